@@ -11,7 +11,13 @@ pub mod guardians_shield;
 pub mod safety_achieved;
 pub mod supporting_cast;
 
-use std::collections::HashMap;
+// The DeFi tool lives beside the guardian; its `main` only runs when the
+// tool is built on its own
+#[path = "../defi_protocol_tool.rs"]
+#[allow(dead_code)]
+pub mod defi_protocol_tool;
+
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +44,8 @@ pub struct ShadowHunter {
     pub attack_type: AttackType,
     pub profit_threshold: u64,
     pub speed_rating: f64,
+    /// How sure the sensor is that this hunter is really there, from 0 to 1
+    pub confidence: f64,
 }
 
 /// The Guardian Protector - Our defensive system
@@ -55,13 +63,24 @@ pub struct SafeSanctuary {
     pub protected_transactions: Vec<Uuid>,
     pub security_level: SecurityLevel,
     pub guardian_count: usize,
+    pub execution_receipt: Option<ExecutionReceipt>,
+    pub protection_journey: Option<ProtectionJourney>,
+}
+
+/// The Proof of Passage - What the chain reports once a transaction executes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionReceipt {
+    pub transaction_id: Uuid,
+    pub security_level: SecurityLevel,
+    pub block_number: Option<u64>,
+    pub transaction_hash: Option<String>,
 }
 
 // =============================================================================
 // PLOT ELEMENTS: The Conflicts and Challenges
 // =============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AttackType {
     SandwichAttack,
     Frontrunning,
@@ -70,7 +89,7 @@ pub enum AttackType {
     LiquidationSniping,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SecurityLevel {
     Vulnerable,
     PartiallyProtected,
@@ -84,6 +103,19 @@ pub struct ProtectionJourney {
     pub current_chapter: ChapterName,
     pub story_beats: Vec<StoryBeat>,
     pub protection_applied: Vec<ProtectionSpell>,
+    pub protection_verdict: Option<ProtectionVerdict>,
+    /// Set when the saga's kill-switch let the transaction through unguarded
+    pub protection_bypassed: bool,
+    /// The related address the transaction dealt with, when it looked like
+    /// its sender trading with itself
+    pub wash_trading_suspected: Option<String>,
+}
+
+/// Whether the protection actually held once the block was produced
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProtectionVerdict {
+    Held,
+    Failed(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,6 +144,287 @@ pub enum ProtectionSpell {
     TimeDelayEnchantment,
 }
 
+impl ProtectionSpell {
+    /// The overhead a spell adds to the transaction, in protection cost units
+    pub fn protection_cost(&self) -> u64 {
+        match self {
+            ProtectionSpell::PrivateMempool => 50,
+            ProtectionSpell::SandwichImmunity => 40,
+            ProtectionSpell::FlashloanShield => 30,
+            ProtectionSpell::FrontrunningBarrier => 20,
+            ProtectionSpell::TimeDelayEnchantment => 10,
+        }
+    }
+    
+    /// How much risk the spell takes off the table, on the same scale for every spell
+    pub fn risk_reduction(&self) -> u64 {
+        match self {
+            ProtectionSpell::PrivateMempool => 50,
+            ProtectionSpell::SandwichImmunity => 35,
+            ProtectionSpell::FrontrunningBarrier => 25,
+            ProtectionSpell::FlashloanShield => 20,
+            ProtectionSpell::TimeDelayEnchantment => 10,
+        }
+    }
+    
+    /// How long the spell takes to be woven once the transaction is public,
+    /// in milliseconds; until then the transaction lies exposed
+    pub fn activation_delay_ms(&self) -> u64 {
+        match self {
+            ProtectionSpell::PrivateMempool => 500,
+            ProtectionSpell::SandwichImmunity => 800,
+            ProtectionSpell::FlashloanShield => 1_000,
+            ProtectionSpell::FrontrunningBarrier => 1_500,
+            ProtectionSpell::TimeDelayEnchantment => 3_000,
+        }
+    }
+}
+
+// =============================================================================
+// THE RULES OF ENGAGEMENT: How Watchful the Guardians Should Be
+// =============================================================================
+
+/// The guardian's standing orders, tuned per network.
+///
+/// Orders can be shared as TOML; any field left out of the file takes its
+/// default.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtectionConfig {
+    pub sandwich: SandwichParams,
+    pub generalized_frontrun: GeneralizedFrontrunParams,
+    /// The most protection overhead the user will pay; `None` applies every
+    /// triggered spell. Fortification after a breach is not bound by it.
+    pub max_protection_cost: Option<u64>,
+    /// The timing of the network the race is run on; `None` skips the race
+    pub latency: Option<LatencyModel>,
+    /// How the saga's threat sensors settle on the hunters they report
+    pub sensor_voting: SensorVoting,
+    /// The emergency kill-switch: threats are still sensed and the journey
+    /// still told, but no guardian is summoned and no spell is woven
+    pub bypass: bool,
+    /// How many protection requests a saga accepts from one address;
+    /// `None` accepts any number
+    pub request_limit: Option<RequestLimit>,
+    /// Who is known to act together, so a sender trading with itself is
+    /// not protected as though it were a victim
+    pub wash_trading: WashTradingParams,
+}
+
+impl ProtectionConfig {
+    /// Reads standing orders from TOML, refusing any whose thresholds fall
+    /// outside the ranges they can meaningfully take
+    pub fn from_toml(orders: &str) -> Result<Self, supporting_cast::PlotTwist> {
+        let config: Self = toml::from_str(orders)
+            .map_err(|error| supporting_cast::PlotTwist::OrdersMisdrawn(error.to_string()))?;
+        config.orders_hold_together()?;
+        Ok(config)
+    }
+
+    /// Writes the standing orders as TOML, to be shared or kept under version
+    /// control. Every map in the orders is kept sorted, so the same orders
+    /// always write the same file.
+    pub fn to_toml(&self) -> Result<String, supporting_cast::PlotTwist> {
+        self.orders_hold_together()?;
+        toml::to_string(self)
+            .map_err(|error| supporting_cast::PlotTwist::OrdersMisdrawn(error.to_string()))
+    }
+
+    /// Checks every threshold lies within its meaningful range
+    pub fn orders_hold_together(&self) -> Result<(), supporting_cast::PlotTwist> {
+        let misdrawn = |reason: String| Err(supporting_cast::PlotTwist::OrdersMisdrawn(reason));
+
+        if !self.sandwich.gas_band.is_finite() || self.sandwich.gas_band < 0.0 {
+            return misdrawn(format!("sandwich gas band {} must be a non-negative fraction", self.sandwich.gas_band));
+        }
+        if let Some(latency) = &self.latency {
+            if latency.block_time_ms == 0 {
+                return misdrawn("a block cannot seal in no time at all".to_string());
+            }
+        }
+        if let Some(limit) = &self.request_limit {
+            if limit.window_secs == 0 {
+                return misdrawn("a request limit needs a window longer than no time at all".to_string());
+            }
+        }
+        if !(0.0..=1.0).contains(&self.wash_trading.protection_weight) {
+            return misdrawn(format!(
+                "wash trading protection weight {} must lie between 0 and 1", self.wash_trading.protection_weight
+            ));
+        }
+        if let SensorVoting::Weighted { weights, threshold } = &self.sensor_voting {
+            if !(0.0..=1.0).contains(threshold) {
+                return misdrawn(format!("sensor voting threshold {} must lie between 0 and 1", threshold));
+            }
+            if let Some((sensor, weight)) = weights.iter().find(|(_, weight)| !weight.is_finite() || **weight < 0.0) {
+                return misdrawn(format!("sensor {} carries a weight of {}", sensor, weight));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How an ensemble of threat sensors turns its reports into one verdict.
+///
+/// Sensors vote per attack type. `Union` trusts any single sensor,
+/// `MajorityVote` needs more than half of them, and `Weighted` needs the
+/// sensors flagging a threat to carry at least `threshold` of the total
+/// weight; a sensor missing from `weights` counts for 1.0.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SensorVoting {
+    #[default]
+    Union,
+    MajorityVote,
+    Weighted {
+        weights: BTreeMap<String, f64>,
+        threshold: f64,
+    },
+}
+
+/// How time flows on a network, in milliseconds.
+///
+/// A transaction is broadcast publicly as a block begins, and the block
+/// seals `block_time_ms` later. A bot hears of it after one
+/// `network_propagation_ms`, reacts, and needs another propagation for its
+/// own transactions to reach the block producer. A bot with a
+/// `speed_rating` of 0.0 takes `slowest_bot_reaction_ms` to react; one
+/// rated 1.0 reacts instantly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LatencyModel {
+    pub network_propagation_ms: u64,
+    pub block_time_ms: u64,
+    pub slowest_bot_reaction_ms: u64,
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self {
+            network_propagation_ms: 200,
+            block_time_ms: 12_000,
+            slowest_bot_reaction_ms: 1_000,
+        }
+    }
+}
+
+/// How a realistic sandwich looks on a given network.
+///
+/// A victim is only worth sandwiching if its gas price sits within
+/// `gas_band` (a fraction, 0.25 = 25%) above the mempool's median bid,
+/// it is among the top `max_depth` pending transactions by gas price,
+/// and the expected extraction exceeds `min_profit`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SandwichParams {
+    pub gas_band: f64,
+    pub max_depth: usize,
+    pub min_profit: u64,
+}
+
+impl Default for SandwichParams {
+    fn default() -> Self {
+        Self {
+            gas_band: 0.5,
+            max_depth: 100,
+            min_profit: 100,
+        }
+    }
+}
+
+/// When a copycat bot would bother replaying a transaction as its own.
+///
+/// Copying only pays if the expected extraction covers outbidding the
+/// victim by one gas unit for `copy_gas_units` of gas, plus `min_profit`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeneralizedFrontrunParams {
+    pub copy_gas_units: u64,
+    pub min_profit: u64,
+}
+
+impl Default for GeneralizedFrontrunParams {
+    fn default() -> Self {
+        Self {
+            copy_gas_units: 21_000,
+            min_profit: 100,
+        }
+    }
+}
+
+/// How many protection requests one address may make in a sliding window,
+/// so a single caller cannot flood a shared guardian.
+///
+/// Every address may make `max_requests` within any `window_secs`, unless
+/// `per_address` names a limit of its own for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestLimit {
+    pub max_requests: u32,
+    pub window_secs: u64,
+    pub per_address: BTreeMap<String, u32>,
+}
+
+impl Default for RequestLimit {
+    fn default() -> Self {
+        Self {
+            max_requests: 60,
+            window_secs: 60,
+            per_address: BTreeMap::new(),
+        }
+    }
+}
+
+impl RequestLimit {
+    /// The most requests `address` may make within one window
+    pub fn allowance_for(&self, address: &str) -> u32 {
+        self.per_address.get(address).copied().unwrap_or(self.max_requests)
+    }
+}
+
+/// Addresses known to act together, and how much protection a transaction
+/// between them still earns.
+///
+/// `related_addresses` maps an address to those it is known to share an
+/// owner with: wallets it funded, the spenders it approved. A sender
+/// dealing with one of its relations, or with an address naming it as
+/// one, is probably trading with itself, and its vulnerability is scaled
+/// by `protection_weight`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WashTradingParams {
+    pub related_addresses: BTreeMap<String, Vec<String>>,
+    pub protection_weight: f64,
+}
+
+impl Default for WashTradingParams {
+    fn default() -> Self {
+        Self {
+            related_addresses: BTreeMap::new(),
+            protection_weight: 0.25,
+        }
+    }
+}
+
+impl WashTradingParams {
+    /// Whether either address names the other among its relations,
+    /// whatever the case of their hex digits
+    pub fn addresses_are_related(&self, one: &str, other: &str) -> bool {
+        let names = |address: &str, relation: &str| self.related_addresses.iter()
+            .filter(|(known, _)| known.eq_ignore_ascii_case(address))
+            .flat_map(|(_, relations)| relations)
+            .any(|related| related.eq_ignore_ascii_case(relation));
+        names(one, other) || names(other, one)
+    }
+}
+
+/// A pending transaction a generalized frontrunner could copy and place first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyableVictim {
+    pub transaction_id: Uuid,
+    pub expected_copy_profit: u64,
+    pub outbid_gas_price: u64,
+}
+
 // =============================================================================
 // ACT I: THREATS EMERGE
 // =============================================================================
@@ -133,10 +446,20 @@ pub mod threats_emerge {
     /// 4. Protection protocols activate
     pub fn transaction_enters_dangerous_waters(
         transaction: InnocentTransaction
+    ) -> Result<(InnocentTransaction, Vec<ShadowHunter>), PlotTwist> {
+        transaction_enters_dangerous_waters_amid(transaction, &[], &SandwichParams::default())
+    }
+    
+    /// The same perilous journey, judged against the other pending
+    /// transactions in the mempool and the network's sandwich parameters
+    pub fn transaction_enters_dangerous_waters_amid(
+        transaction: InnocentTransaction,
+        mempool: &[InnocentTransaction],
+        sandwich_params: &SandwichParams
     ) -> Result<(InnocentTransaction, Vec<ShadowHunter>), PlotTwist> {
         tracing::info!("🌊 Transaction {} begins its perilous journey", transaction.id);
         
-        let detected_threats = shadow_hunters_sense_opportunity(&transaction)?;
+        let detected_threats = shadow_hunters_sense_opportunity(&transaction, mempool, sandwich_params)?;
         let vulnerability_assessment = transaction_reveals_its_vulnerabilities(&transaction);
         
         let updated_transaction = InnocentTransaction {
@@ -149,19 +472,118 @@ pub mod threats_emerge {
         Ok((updated_transaction, detected_threats))
     }
     
+    /// ## The Many Eyes of the Watch
+    /// 
+    /// Anything that can look at a pending transaction and name the hunters
+    /// it would attract - the built-in heuristics, a learned model, or a
+    /// feed from elsewhere.
+    pub trait ThreatSensor: Send + Sync {
+        fn sensor_name(&self) -> &str;
+        fn sense(
+            &self,
+            transaction: &InnocentTransaction,
+            mempool: &[InnocentTransaction]
+        ) -> Result<Vec<ShadowHunter>, PlotTwist>;
+    }
+    
+    /// The hand-written heuristics the guardian has always relied on
+    pub struct HeuristicSensor {
+        pub sandwich_params: SandwichParams,
+    }
+    
+    impl ThreatSensor for HeuristicSensor {
+        fn sensor_name(&self) -> &str {
+            "heuristic"
+        }
+        
+        fn sense(
+            &self,
+            transaction: &InnocentTransaction,
+            mempool: &[InnocentTransaction]
+        ) -> Result<Vec<ShadowHunter>, PlotTwist> {
+            shadow_hunters_sense_opportunity(transaction, mempool, &self.sandwich_params)
+        }
+    }
+    
+    /// The same perilous journey, with the hunters named by an ensemble of
+    /// sensors under the given voting policy
+    pub fn transaction_enters_dangerous_waters_watched_by(
+        transaction: InnocentTransaction,
+        mempool: &[InnocentTransaction],
+        sensors: &[Box<dyn ThreatSensor>],
+        voting: &SensorVoting
+    ) -> Result<(InnocentTransaction, Vec<ShadowHunter>), PlotTwist> {
+        tracing::info!("🌊 Transaction {} begins its perilous journey under {} sensors", transaction.id, sensors.len());
+        
+        let mut reports = Vec::with_capacity(sensors.len());
+        for sensor in sensors {
+            reports.push((sensor.sensor_name().to_string(), sensor.sense(&transaction, mempool)?));
+        }
+        let detected_threats = sensors_cast_their_votes(&reports, voting);
+        let vulnerability_assessment = transaction_reveals_its_vulnerabilities(&transaction);
+        
+        tracing::warn!("⚠️  {} shadow hunters agreed upon by the watch", detected_threats.len());
+        
+        Ok((InnocentTransaction { vulnerability_score: vulnerability_assessment, ..transaction }, detected_threats))
+    }
+    
+    /// Keeps each attack type the policy accepts, reported by the first
+    /// sensor that named it, in the order the types were first named
+    fn sensors_cast_their_votes(
+        reports: &[(String, Vec<ShadowHunter>)],
+        voting: &SensorVoting
+    ) -> Vec<ShadowHunter> {
+        let weight_of = |sensor_name: &str| match voting {
+            SensorVoting::Weighted { weights, .. } => weights.get(sensor_name).copied().unwrap_or(1.0),
+            _ => 1.0,
+        };
+        let total_weight: f64 = reports.iter().map(|(sensor_name, _)| weight_of(sensor_name)).sum();
+        
+        let mut candidates: Vec<(ShadowHunter, usize, f64)> = Vec::new();
+        for (sensor_name, hunters) in reports {
+            let mut attack_types_seen = Vec::new();
+            for hunter in hunters {
+                // A sensor gets one vote per attack type, however many bots it names
+                if attack_types_seen.contains(&hunter.attack_type) {
+                    continue;
+                }
+                attack_types_seen.push(hunter.attack_type.clone());
+                match candidates.iter_mut().find(|(known, _, _)| known.attack_type == hunter.attack_type) {
+                    Some((_, votes, weight)) => {
+                        *votes += 1;
+                        *weight += weight_of(sensor_name);
+                    }
+                    None => candidates.push((hunter.clone(), 1, weight_of(sensor_name))),
+                }
+            }
+        }
+        
+        candidates.into_iter()
+            .filter(|(_, votes, weight)| match voting {
+                SensorVoting::Union => true,
+                SensorVoting::MajorityVote => *votes * 2 > reports.len(),
+                SensorVoting::Weighted { threshold, .. } => total_weight > 0.0 && *weight / total_weight >= *threshold,
+            })
+            .map(|(hunter, _, _)| hunter)
+            .collect()
+    }
+    
     /// Shadow hunters emerge from the darkness when they smell profit
     fn shadow_hunters_sense_opportunity(
-        transaction: &InnocentTransaction
+        transaction: &InnocentTransaction,
+        mempool: &[InnocentTransaction],
+        sandwich_params: &SandwichParams
     ) -> Result<Vec<ShadowHunter>, PlotTwist> {
         let mut hunters = Vec::new();
         
-        // Sandwich attack bots love high-value swaps
-        if transaction.value > 10000 && transaction_looks_like_swap(transaction) {
+        // Sandwich attack bots love high-value swaps they can realistically bracket
+        if transaction_looks_like_swap(transaction) && sandwich_looks_realistic(transaction, mempool, sandwich_params) {
             hunters.push(ShadowHunter {
                 bot_id: "sandwich_serpent_001".to_string(),
                 attack_type: AttackType::SandwichAttack,
                 profit_threshold: transaction.value / 100, // 1% profit target
                 speed_rating: 0.95,
+                confidence: 0.9, // The bracket was simulated against the mempool
             });
         }
         
@@ -172,6 +594,7 @@ pub mod threats_emerge {
                 attack_type: AttackType::Frontrunning,
                 profit_threshold: 1000,
                 speed_rating: 0.98,
+                confidence: 0.85, // Known routers are watched around the clock
             });
         }
         
@@ -182,12 +605,120 @@ pub mod threats_emerge {
                 attack_type: AttackType::FlashloanArbitrage,
                 profit_threshold: 5000,
                 speed_rating: 0.92,
+                confidence: 0.6, // High gas hints at profit but proves nothing
             });
         }
         
         Ok(hunters)
     }
     
+    /// A sandwich only pays when the victim is profitable, near the top of the
+    /// mempool, and not bidding so far above the crowd that outbidding it is costly
+    fn sandwich_looks_realistic(
+        transaction: &InnocentTransaction,
+        mempool: &[InnocentTransaction],
+        sandwich_params: &SandwichParams
+    ) -> bool {
+        let expected_profit = transaction.value as f64 / 100.0;
+        if expected_profit <= sandwich_params.min_profit as f64 {
+            return false;
+        }
+        
+        let mut rival_gas_prices: Vec<u64> = mempool.iter()
+            .filter(|pending| pending.id != transaction.id)
+            .map(|pending| pending.gas_price)
+            .collect();
+        if rival_gas_prices.is_empty() {
+            return true;
+        }
+        
+        let depth = rival_gas_prices.iter()
+            .filter(|&&gas_price| gas_price > transaction.gas_price)
+            .count();
+        if depth >= sandwich_params.max_depth {
+            return false;
+        }
+        
+        rival_gas_prices.sort_unstable();
+        let median_gas_price = rival_gas_prices[rival_gas_prices.len() / 2] as f64;
+        transaction.gas_price as f64 <= median_gas_price * (1.0 + sandwich_params.gas_band)
+    }
+    
+    /// ## Chapter 1½: The Copycats Scan the Whole Mempool
+    /// 
+    /// Generalized frontrunners do not study one victim at a time; they
+    /// replay every pending transaction in simulation and copy any that
+    /// would pay *them* if sent first from their own address.
+    pub fn batch_reveals_copyable_victims(
+        batch: &[InnocentTransaction],
+        frontrun_params: &GeneralizedFrontrunParams
+    ) -> Vec<CopyableVictim> {
+        let copyable_victims: Vec<CopyableVictim> = batch.iter()
+            .filter_map(|transaction| copy_looks_profitable(transaction, frontrun_params))
+            .collect();
+        
+        if !copyable_victims.is_empty() {
+            tracing::warn!("🪞 {} of {} pending transactions could be copied by generalized frontrunners",
+                copyable_victims.len(), batch.len());
+        }
+        
+        copyable_victims
+    }
+    
+    /// A copy pays only if its profit is not bound to the original sender
+    /// and the extraction outweighs the cost of outbidding the victim
+    fn copy_looks_profitable(
+        transaction: &InnocentTransaction,
+        frontrun_params: &GeneralizedFrontrunParams
+    ) -> Option<CopyableVictim> {
+        if calldata_names_its_sender(transaction) {
+            return None;
+        }
+        
+        let expected_copy_profit = transaction.value / 100;
+        let outbid_gas_price = transaction.gas_price.saturating_add(1);
+        let outbid_cost = outbid_gas_price.saturating_mul(frontrun_params.copy_gas_units);
+        
+        if expected_copy_profit <= outbid_cost.saturating_add(frontrun_params.min_profit) {
+            return None;
+        }
+        
+        Some(CopyableVictim {
+            transaction_id: transaction.id,
+            expected_copy_profit: expected_copy_profit - outbid_cost,
+            outbid_gas_price,
+        })
+    }
+    
+    /// Calldata that embeds the sender (a swap's `to`, a permit's owner)
+    /// keeps paying the sender no matter who submits it
+    fn calldata_names_its_sender(transaction: &InnocentTransaction) -> bool {
+        let Ok(sender) = hex::decode(transaction.user_address.trim_start_matches("0x")) else {
+            return false;
+        };
+        !sender.is_empty() && transaction.data.windows(sender.len()).any(|window| window == sender.as_slice())
+    }
+    
+    /// ## The Victim Who Is Its Own Hunter
+    /// 
+    /// The counterparty a transaction shares an owner with, if any: the
+    /// contract it calls, or an address its calldata carries as an ABI
+    /// word, such as a swap's recipient or an approval's spender. A sender
+    /// so entangled is probably wash-trading to manufacture volume.
+    pub fn transaction_looks_self_dealing(
+        transaction: &InnocentTransaction,
+        params: &WashTradingParams
+    ) -> Option<String> {
+        let embedded = transaction.data.get(4..).unwrap_or_default()
+            .chunks_exact(32)
+            .filter(|word| word[..12].iter().all(|byte| *byte == 0) && word[12..].iter().any(|byte| *byte != 0))
+            .map(|word| format!("0x{}", hex::encode(&word[12..])));
+        std::iter::once(transaction.target_contract.clone())
+            .chain(embedded)
+            .filter(|counterparty| !counterparty.eq_ignore_ascii_case(&transaction.user_address))
+            .find(|counterparty| params.addresses_are_related(&transaction.user_address, counterparty))
+    }
+    
     /// The transaction's vulnerabilities are assessed
     fn transaction_reveals_its_vulnerabilities(transaction: &InnocentTransaction) -> f64 {
         let mut vulnerability = 0.0;
@@ -231,6 +762,13 @@ pub mod guardians_shield {
     use super::*;
     use crate::supporting_cast::PlotTwist;
     
+    /// Combined confidence at which an attack is believed enough to earn
+    /// the spell aimed at it
+    const MODERATE_EVIDENCE: f64 = 0.5;
+    
+    /// Combined confidence the heavy spells demand before they are woven
+    const STRONG_EVIDENCE: f64 = 0.8;
+    
     /// ## Chapter 2: The Guardian Temple Awakens
     /// 
     /// When threats are detected, the guardian temple springs into action.
@@ -245,11 +783,60 @@ pub mod guardians_shield {
     pub fn guardian_temple_awakens_to_protect(
         transaction: InnocentTransaction,
         threats: Vec<ShadowHunter>
+    ) -> Result<(GuardianProtector, Vec<ProtectionSpell>), PlotTwist> {
+        guardian_temple_awakens_within_budget(transaction, threats, None)
+    }
+    
+    /// The same ritual, weaving only the most valuable spells whose combined
+    /// cost fits within `max_protection_cost`
+    pub fn guardian_temple_awakens_within_budget(
+        transaction: InnocentTransaction,
+        threats: Vec<ShadowHunter>,
+        max_protection_cost: Option<u64>
+    ) -> Result<(GuardianProtector, Vec<ProtectionSpell>), PlotTwist> {
+        let default_strategy: [Box<dyn SpellStrategy>; 1] = [Box::new(DefaultStrategy)];
+        guardian_temple_awakens_by_strategies(transaction, threats, max_protection_cost, &default_strategy)
+    }
+    
+    /// The same ritual with the spells chosen by `strategies`, consulted in
+    /// order: the first whose choice survives the budget is the one woven.
+    pub fn guardian_temple_awakens_by_strategies(
+        transaction: InnocentTransaction,
+        threats: Vec<ShadowHunter>,
+        max_protection_cost: Option<u64>,
+        strategies: &[Box<dyn SpellStrategy>]
     ) -> Result<(GuardianProtector, Vec<ProtectionSpell>), PlotTwist> {
         tracing::info!("⚔️  Guardian temple activating defenses for transaction {}", transaction.id);
         
         let threat_analysis = analyze_shadow_hunter_capabilities(&threats);
-        let protection_spells = weave_protective_enchantments(&transaction, &threat_analysis)?;
+        let mut last_twist = PlotTwist::GuardianOverwhelmed("No spell strategy was consulted".to_string());
+        let mut chosen_spells = None;
+        for strategy in strategies {
+            let protection_spells = match strategy.choose_spells(&transaction, &threat_analysis) {
+                Ok(protection_spells) => protection_spells,
+                Err(plot_twist) => {
+                    tracing::debug!("📜 Strategy {} chose no spells: {}", strategy.strategy_name(), plot_twist);
+                    last_twist = plot_twist;
+                    continue;
+                }
+            };
+            let protection_spells = match max_protection_cost {
+                Some(budget) => spells_fit_within_budget(protection_spells, budget),
+                None => protection_spells,
+            };
+            if protection_spells.is_empty() {
+                last_twist = PlotTwist::GuardianOverwhelmed(match max_protection_cost {
+                    Some(budget) => format!("No protection spell fits within a budget of {}", budget),
+                    None => format!("Strategy {} chose no spells", strategy.strategy_name()),
+                });
+                continue;
+            }
+            chosen_spells = Some(protection_spells);
+            break;
+        }
+        let Some(protection_spells) = chosen_spells else {
+            return Err(last_twist);
+        };
         
         let guardian = GuardianProtector {
             shield_strength: calculate_required_shield_strength(&threats),
@@ -263,28 +850,108 @@ pub mod guardians_shield {
         Ok((guardian, protection_spells))
     }
     
-    /// The guardian studies the shadow hunters to understand their methods
+    /// ## Chapter 2½: The Temple Fortifies After a Breach
+    /// 
+    /// When a sanctuary proves too weak to hold, the guardian is summoned
+    /// again at full strength, with the private pool opened and the
+    /// mempool-hiding spells woven in whether or not the threats demanded it.
+    pub fn guardian_temple_fortifies(
+        transaction: InnocentTransaction,
+        threats: Vec<ShadowHunter>
+    ) -> Result<(GuardianProtector, Vec<ProtectionSpell>), PlotTwist> {
+        tracing::warn!("🏰 Guardian temple fortifying defenses for transaction {}", transaction.id);
+        
+        let (guardian, mut protection_spells) = match guardian_temple_awakens_to_protect(transaction, threats) {
+            Ok(summoned) => summoned,
+            Err(PlotTwist::GuardianOverwhelmed(_)) => (GuardianProtector {
+                shield_strength: 0.0,
+                private_pool_access: false,
+                flashloan_detection: false,
+                sandwich_immunity: false,
+            }, Vec::new()),
+            Err(plot_twist) => return Err(plot_twist),
+        };
+        
+        if !protection_spells.iter().any(|spell| matches!(spell, ProtectionSpell::PrivateMempool)) {
+            protection_spells.push(ProtectionSpell::PrivateMempool);
+        }
+        if !protection_spells.iter().any(|spell| matches!(spell, ProtectionSpell::FrontrunningBarrier)) {
+            protection_spells.push(ProtectionSpell::FrontrunningBarrier);
+        }
+        
+        let fortified_guardian = GuardianProtector {
+            shield_strength: 1.0,
+            private_pool_access: true,
+            ..guardian
+        };
+        
+        Ok((fortified_guardian, protection_spells))
+    }
+    
+    /// The guardian studies the shadow hunters to understand their methods.
+    /// Only attacks with enough evidence behind them count as present.
     fn analyze_shadow_hunter_capabilities(threats: &[ShadowHunter]) -> ThreatAnalysis {
-        let max_speed = threats.iter()
-            .map(|t| t.speed_rating)
-            .fold(0.0, f64::max);
-            
         let total_profit_target: u64 = threats.iter()
             .map(|t| t.profit_threshold)
             .sum();
             
         let attack_types: std::collections::HashSet<_> = threats.iter()
             .map(|t| &t.attack_type)
+            .filter(|attack_type| {
+                combined_confidence(threats.iter().filter(|t| &t.attack_type == *attack_type)) >= MODERATE_EVIDENCE
+            })
             .collect();
             
         ThreatAnalysis {
-            maximum_speed: max_speed,
+            fast_threat_evidence: combined_confidence(threats.iter().filter(|t| t.speed_rating > 0.9)),
+            swiftest_threat_evidence: combined_confidence(threats.iter().filter(|t| t.speed_rating > 0.95)),
             combined_profit_target: total_profit_target,
             attack_diversity: attack_types.len(),
             most_dangerous_attack: classify_most_dangerous_attack(&attack_types),
         }
     }
     
+    /// How likely at least one of the detections is real, treating each as an
+    /// independent witness: two doubtful sightings outweigh one, but never a
+    /// certain one
+    fn combined_confidence<'a>(detections: impl Iterator<Item = &'a ShadowHunter>) -> f64 {
+        1.0 - detections
+            .map(|t| 1.0 - t.confidence.clamp(0.0, 1.0))
+            .product::<f64>()
+    }
+    
+    /// ## The Spellbook's Many Authors
+    /// 
+    /// Anything that can look at a transaction and what is known of its
+    /// hunters and decide which spells to weave - always-private,
+    /// cost-minimising, maximum-safety, or the guardian's own judgement.
+    pub trait SpellStrategy: Send + Sync {
+        fn strategy_name(&self) -> &str;
+        fn choose_spells(
+            &self,
+            transaction: &InnocentTransaction,
+            threat_analysis: &ThreatAnalysis
+        ) -> Result<Vec<ProtectionSpell>, PlotTwist>;
+    }
+    
+    /// The selection the guardian has always made, spell by spell from the
+    /// threats detected
+    pub struct DefaultStrategy;
+    
+    impl SpellStrategy for DefaultStrategy {
+        fn strategy_name(&self) -> &str {
+            "default"
+        }
+        
+        fn choose_spells(
+            &self,
+            transaction: &InnocentTransaction,
+            threat_analysis: &ThreatAnalysis
+        ) -> Result<Vec<ProtectionSpell>, PlotTwist> {
+            weave_protective_enchantments(transaction, threat_analysis)
+        }
+    }
+    
     /// Protection spells are woven based on the specific threats detected
     fn weave_protective_enchantments(
         transaction: &InnocentTransaction,
@@ -302,8 +969,8 @@ pub mod guardians_shield {
             spells.push(ProtectionSpell::SandwichImmunity);
         }
         
-        // Fast threats need frontrunning barriers
-        if threat_analysis.maximum_speed > 0.9 {
+        // Fast threats need frontrunning barriers, once we are sure of them
+        if threat_analysis.fast_threat_evidence >= STRONG_EVIDENCE {
             spells.push(ProtectionSpell::FrontrunningBarrier);
         }
         
@@ -313,7 +980,7 @@ pub mod guardians_shield {
         }
         
         // Very dangerous situations get time delay enchantments
-        if transaction.vulnerability_score > 0.8 && threat_analysis.maximum_speed > 0.95 {
+        if transaction.vulnerability_score > 0.8 && threat_analysis.swiftest_threat_evidence >= STRONG_EVIDENCE {
             spells.push(ProtectionSpell::TimeDelayEnchantment);
         }
         
@@ -324,18 +991,67 @@ pub mod guardians_shield {
         Ok(spells)
     }
     
+    /// A 0/1 knapsack over the budget: of every choice of candidate spells
+    /// that fits, keep the one removing the most risk (the cheaper one on
+    /// ties). The table only reaches as far as all the candidates cost
+    /// together, so a generous budget costs no more than an exact one.
+    fn spells_fit_within_budget(candidates: Vec<ProtectionSpell>, budget: u64) -> Vec<ProtectionSpell> {
+        let total_cost: u64 = candidates.iter().map(ProtectionSpell::protection_cost).sum();
+        let capacity = budget.min(total_cost) as usize;
+        
+        // most_reduction[spent]: the most risk removed by spells costing exactly `spent`
+        let mut most_reduction: Vec<Option<u64>> = vec![None; capacity + 1];
+        most_reduction[0] = Some(0);
+        let mut taken = vec![vec![false; capacity + 1]; candidates.len()];
+        for (index, spell) in candidates.iter().enumerate() {
+            let cost = spell.protection_cost() as usize;
+            for spent in (cost..=capacity).rev() {
+                let Some(reduction) = most_reduction[spent - cost].map(|reduction| reduction + spell.risk_reduction()) else {
+                    continue;
+                };
+                if most_reduction[spent].is_none_or(|best| reduction > best) {
+                    most_reduction[spent] = Some(reduction);
+                    taken[index][spent] = true;
+                }
+            }
+        }
+        
+        let (mut spent, _) = most_reduction.iter()
+            .enumerate()
+            .filter_map(|(spent, reduction)| reduction.map(|reduction| (spent, reduction)))
+            .max_by_key(|&(spent, reduction)| (reduction, std::cmp::Reverse(spent)))
+            .unwrap_or_default();
+        
+        // Walk the table back from the last candidate to recover the spells
+        let mut chosen = vec![false; candidates.len()];
+        for index in (0..candidates.len()).rev() {
+            if taken[index][spent] {
+                chosen[index] = true;
+                spent -= candidates[index].protection_cost() as usize;
+            }
+        }
+        
+        candidates.into_iter()
+            .zip(chosen)
+            .filter_map(|(spell, chosen)| chosen.then_some(spell))
+            .collect()
+    }
+    
+    /// Each hunter adds to the shield in proportion to how sure we are of it
     fn calculate_required_shield_strength(threats: &[ShadowHunter]) -> f64 {
         let base_strength = 0.5;
-        let threat_multiplier = threats.len() as f64 * 0.2;
+        let threat_multiplier = threats.iter()
+            .map(|t| t.confidence * 0.2)
+            .sum::<f64>();
         let speed_bonus = threats.iter()
-            .map(|t| t.speed_rating * 0.3)
+            .map(|t| t.speed_rating * 0.3 * t.confidence)
             .sum::<f64>();
             
         (base_strength + threat_multiplier + speed_bonus).min(1.0)
     }
     
     fn should_use_private_pool(transaction: &InnocentTransaction, threats: &[ShadowHunter]) -> bool {
-        transaction.vulnerability_score > 0.6 || threats.len() > 2
+        transaction.vulnerability_score > 0.6 || threats.iter().map(|t| t.confidence).sum::<f64>() > 2.0
     }
     
     fn classify_most_dangerous_attack(attack_types: &std::collections::HashSet<&AttackType>) -> AttackType {
@@ -350,12 +1066,16 @@ pub mod guardians_shield {
         }
     }
     
+    /// What the guardian has learned of the hunters, as handed to a spell strategy
     #[derive(Debug, Clone)]
-    struct ThreatAnalysis {
-        maximum_speed: f64,
-        combined_profit_target: u64,
-        attack_diversity: usize,
-        most_dangerous_attack: AttackType,
+    pub struct ThreatAnalysis {
+        /// Combined confidence in the hunters fast enough to frontrun
+        pub fast_threat_evidence: f64,
+        /// Combined confidence in the very fastest hunters
+        pub swiftest_threat_evidence: f64,
+        pub combined_profit_target: u64,
+        pub attack_diversity: usize,
+        pub most_dangerous_attack: AttackType,
     }
 }
 
@@ -381,15 +1101,19 @@ pub mod safety_achieved {
     pub fn transaction_finds_safe_harbor(
         transaction: InnocentTransaction,
         guardian: GuardianProtector,
-        protection_spells: Vec<ProtectionSpell>
+        protection_spells: Vec<ProtectionSpell>,
+        backend: &dyn ExecutionBackend
     ) -> Result<SafeSanctuary, PlotTwist> {
         tracing::info!("🏛️  Transaction {} enters the sacred sanctuary", transaction.id);
         
-        let sanctuary = create_protected_sanctuary(&guardian, &protection_spells)?;
+        let mut sanctuary = create_protected_sanctuary(&guardian, &protection_spells)?;
         let final_protection_story = document_protection_journey(&transaction, &protection_spells);
         
-        // Simulate the transaction execution in safety
-        execute_transaction_in_sanctuary(&transaction, &sanctuary)?;
+        // The transaction executes in safety through the chosen backend
+        let receipt = execute_transaction_in_sanctuary(&transaction, &sanctuary, backend)?;
+        sanctuary.protected_transactions.push(transaction.id);
+        sanctuary.execution_receipt = Some(receipt);
+        sanctuary.protection_journey = Some(final_protection_story);
         
         tracing::info!("✅ Transaction {} completed safely with {} protections", 
                       transaction.id, protection_spells.len());
@@ -408,29 +1132,144 @@ pub mod safety_achieved {
             protected_transactions: vec![], // Will be populated during execution
             security_level,
             guardian_count: calculate_guardian_count(guardian.shield_strength),
+            execution_receipt: None,
+            protection_journey: None,
         })
     }
     
     /// The transaction executes within the protective barriers
     fn execute_transaction_in_sanctuary(
         transaction: &InnocentTransaction,
-        sanctuary: &SafeSanctuary
-    ) -> Result<(), PlotTwist> {
+        sanctuary: &SafeSanctuary,
+        backend: &dyn ExecutionBackend
+    ) -> Result<ExecutionReceipt, PlotTwist> {
         match sanctuary.security_level {
             SecurityLevel::SacredSanctuary | SecurityLevel::FullyShielded => {
                 // Transaction executes with full protection
                 tracing::info!("💎 Transaction executing in maximum security");
-                simulate_safe_execution(transaction)
             },
             SecurityLevel::PartiallyProtected => {
                 // Some risk remains but manageable
                 tracing::warn!("⚠️  Transaction executing with partial protection");
-                simulate_cautious_execution(transaction)
             },
             SecurityLevel::Vulnerable => {
                 return Err(PlotTwist::SanctuaryBreach("Insufficient protection for safe execution".to_string()));
             }
         }
+        
+        backend.execute(transaction, &sanctuary.security_level)
+    }
+    
+    /// ## The Bridge to the Chain
+    /// 
+    /// Whatever actually submits the protected transaction - a real RPC
+    /// endpoint, a private relay, or the simulation used in the tales.
+    pub trait ExecutionBackend: Send + Sync {
+        fn execute(
+            &self,
+            transaction: &InnocentTransaction,
+            security_level: &SecurityLevel
+        ) -> Result<ExecutionReceipt, PlotTwist>;
+    }
+    
+    /// The transactions that landed around ours in the produced block
+    #[derive(Debug, Clone, Default)]
+    pub struct BlockContext {
+        pub block_number: Option<u64>,
+        pub preceding: Vec<InnocentTransaction>,
+        pub following: Vec<InnocentTransaction>,
+    }
+    
+    /// ## The Epilogue: Did the Shield Hold?
+    /// 
+    /// Once the block is produced, a verifier inspects what surrounded the
+    /// transaction and judges whether the promised protection held.
+    pub trait PostExecutionVerifier: Send + Sync {
+        fn surrounding_block(&self, receipt: &ExecutionReceipt) -> Result<BlockContext, PlotTwist>;
+        fn verify(
+            &self,
+            transaction: &InnocentTransaction,
+            receipt: &ExecutionReceipt,
+            context: &BlockContext
+        ) -> ProtectionVerdict;
+    }
+    
+    /// The timeline of one race between the woven protection and a bot
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RaceOutcome {
+        pub bot_id: String,
+        pub bot_ready_ms: u64,
+        pub protection_active_ms: u64,
+        pub block_sealed_ms: u64,
+        pub bot_wins: bool,
+    }
+    
+    /// ## The Race Against the Clock
+    /// 
+    /// The spells are woven together, so the protection holds once the
+    /// slowest is in place. The bot wins only if its transactions reach the
+    /// producer before the block seals while the victim still lies exposed.
+    pub fn protection_races_the_hunter(
+        spells: &[ProtectionSpell],
+        hunter: &ShadowHunter,
+        latency: &LatencyModel
+    ) -> RaceOutcome {
+        let reaction_ms = (latency.slowest_bot_reaction_ms as f64 * (1.0 - hunter.speed_rating.clamp(0.0, 1.0))) as u64;
+        let bot_ready_ms = latency.network_propagation_ms
+            .saturating_mul(2)
+            .saturating_add(reaction_ms);
+        let protection_active_ms = spells.iter()
+            .map(ProtectionSpell::activation_delay_ms)
+            .max()
+            .unwrap_or(u64::MAX);
+        let block_sealed_ms = latency.block_time_ms;
+        
+        RaceOutcome {
+            bot_id: hunter.bot_id.clone(),
+            bot_ready_ms,
+            protection_active_ms,
+            block_sealed_ms,
+            bot_wins: bot_ready_ms < block_sealed_ms && block_sealed_ms < protection_active_ms,
+        }
+    }
+    
+    /// Judges a sandwich by its shape: one stranger touching the same contract
+    /// both just before and just after the protected transaction
+    pub fn sandwich_surrounds(transaction: &InnocentTransaction, context: &BlockContext) -> Option<String> {
+        let front = context.preceding.last()?;
+        let back = context.following.first()?;
+        
+        let same_stranger = front.user_address == back.user_address && front.user_address != transaction.user_address;
+        let same_pool = front.target_contract == transaction.target_contract
+            && back.target_contract == transaction.target_contract;
+        
+        (same_stranger && same_pool).then(|| format!(
+            "{} bracketed the transaction on {}", front.user_address, transaction.target_contract
+        ))
+    }
+    
+    /// The default backend: pretends to execute, taking longer when cautious
+    #[derive(Debug, Clone, Default)]
+    pub struct SimulatedExecution;
+    
+    impl ExecutionBackend for SimulatedExecution {
+        fn execute(
+            &self,
+            transaction: &InnocentTransaction,
+            security_level: &SecurityLevel
+        ) -> Result<ExecutionReceipt, PlotTwist> {
+            match security_level {
+                SecurityLevel::PartiallyProtected => simulate_cautious_execution(transaction)?,
+                _ => simulate_safe_execution(transaction)?,
+            }
+            
+            Ok(ExecutionReceipt {
+                transaction_id: transaction.id,
+                security_level: security_level.clone(),
+                block_number: None,
+                transaction_hash: None,
+            })
+        }
     }
     
     fn determine_sanctuary_security_level(
@@ -488,6 +1327,9 @@ pub mod safety_achieved {
                 }
             ],
             protection_applied: spells.to_vec(),
+            protection_verdict: None,
+            protection_bypassed: false,
+            wash_trading_suspected: None,
         }
     }
 }
@@ -517,6 +1359,15 @@ pub mod supporting_cast {
         
         #[error("An unexpected plot twist occurred: {0}")]
         UnexpectedEnding(String),
+        
+        #[error("The protected ritual went astray: {0}")]
+        RitualAstray(String),
+        
+        #[error("The standing orders were misdrawn: {0}")]
+        OrdersMisdrawn(String),
+        
+        #[error("Too many protection requests from {0}")]
+        TooManyRequests(String),
     }
     
     impl PlotTwist {
@@ -535,9 +1386,12 @@ pub mod supporting_cast {
                 PlotTwist::TransactionLost(msg) => {
                     StoryResolution::LaunchRescueMission(msg)
                 },
-                PlotTwist::UnexpectedEnding(msg) => {
+                PlotTwist::UnexpectedEnding(msg) | PlotTwist::RitualAstray(msg) | PlotTwist::OrdersMisdrawn(msg) => {
                     StoryResolution::ImproviseNewStrategy(msg)
                 },
+                PlotTwist::TooManyRequests(msg) => {
+                    StoryResolution::AwaitYourTurn(msg)
+                },
             }
         }
     }
@@ -549,6 +1403,7 @@ pub mod supporting_cast {
         FortifySanctuary(String),
         LaunchRescueMission(String),
         ImproviseNewStrategy(String),
+        AwaitYourTurn(String),
     }
     
     /// The complete story logger tracks every beat of the protection journey
@@ -569,6 +1424,9 @@ pub mod supporting_cast {
                 current_chapter: ChapterName::ThreatDetection,
                 story_beats: Vec::new(),
                 protection_applied: Vec::new(),
+                protection_verdict: None,
+                protection_bypassed: false,
+                wash_trading_suspected: None,
             };
             self.journey_logs.insert(transaction_id, journey);
         }
@@ -583,41 +1441,541 @@ pub mod supporting_cast {
             self.journey_logs.remove(&transaction_id)
         }
     }
-}
-
-// =============================================================================
-// THE MAIN SAGA: Orchestrating the Complete Story
-// =============================================================================
-
-/// ## The Complete MEV Protection Saga
-/// 
-/// This is the main story orchestrator that takes an innocent transaction
-/// through its complete journey from vulnerability to safety.
-pub async fn complete_mev_protection_saga(
-    transaction: InnocentTransaction
-) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
-    use threats_emerge::*;
-    use guardians_shield::*;
-    use safety_achieved::*;
-    
-    tracing::info!("📖 Beginning the MEV Protection Saga for transaction {}", transaction.id);
-    
-    // Act I: The threats emerge from the shadows
-    let (vulnerable_transaction, shadow_hunters) = 
-        transaction_enters_dangerous_waters(transaction)?;
     
-    // Act II: The guardians rise to protect
-    let (guardian_protector, protection_spells) = 
-        guardian_temple_awakens_to_protect(vulnerable_transaction.clone(), shadow_hunters)?;
+    /// What the guardians have seen around one target contract
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct ContractMetrics {
+        pub transactions_observed: usize,
+        pub threats_by_type: HashMap<AttackType, usize>,
+        pub average_vulnerability_score: f64,
+        pub transactions_protected: usize,
+    }
     
-    // Act III: Safety is achieved in the sacred sanctuary
-    let safe_sanctuary = 
-        transaction_finds_safe_harbor(vulnerable_transaction, guardian_protector, protection_spells)?;
+    #[derive(Debug, Default)]
+    struct ContractTally {
+        transactions_observed: usize,
+        threats_by_type: HashMap<AttackType, usize>,
+        vulnerability_total: f64,
+        transactions_protected: usize,
+    }
     
-    tracing::info!("🎊 MEV Protection Saga concluded successfully!");
+    /// The metrics collector tallies detections and protections per target
+    /// contract, revealing which contracts draw the shadow hunters' gaze
+    #[derive(Debug, Default)]
+    pub struct MetricsCollector {
+        tallies: HashMap<String, ContractTally>,
+    }
     
-    Ok(safe_sanctuary)
-}
+    impl MetricsCollector {
+        pub fn new() -> Self {
+            Self::default()
+        }
+        
+        pub fn record_detection(&mut self, transaction: &InnocentTransaction, hunters: &[ShadowHunter]) {
+            let tally = self.tallies.entry(transaction.target_contract.clone()).or_default();
+            tally.transactions_observed += 1;
+            tally.vulnerability_total += transaction.vulnerability_score;
+            for hunter in hunters {
+                *tally.threats_by_type.entry(hunter.attack_type.clone()).or_insert(0) += 1;
+            }
+        }
+        
+        pub fn record_protection(&mut self, transaction: &InnocentTransaction) {
+            self.tallies.entry(transaction.target_contract.clone()).or_default().transactions_protected += 1;
+        }
+        
+        pub fn metrics_by_contract(&self) -> HashMap<String, ContractMetrics> {
+            self.tallies.iter()
+                .map(|(contract, tally)| {
+                    let average_vulnerability_score = if tally.transactions_observed == 0 {
+                        0.0
+                    } else {
+                        tally.vulnerability_total / tally.transactions_observed as f64
+                    };
+                    (contract.clone(), ContractMetrics {
+                        transactions_observed: tally.transactions_observed,
+                        threats_by_type: tally.threats_by_type.clone(),
+                        average_vulnerability_score,
+                        transactions_protected: tally.transactions_protected,
+                    })
+                })
+                .collect()
+        }
+    }
+    
+    /// The request ledger remembers when each address last asked for
+    /// protection, so a saga can turn away whoever asks too often
+    #[derive(Debug, Default)]
+    pub struct RequestLedger {
+        requests_by_address: HashMap<String, std::collections::VecDeque<std::time::Instant>>,
+    }
+    
+    impl RequestLedger {
+        /// Records a request from `address` at `now` if it is within the
+        /// limit, forgetting requests that have slid out of the window.
+        /// Addresses with nothing left in the window are forgotten outright,
+        /// so a stream of one-off senders cannot grow the ledger forever.
+        pub fn request_is_heard(
+            &mut self,
+            address: &str,
+            limit: &RequestLimit,
+            now: std::time::Instant
+        ) -> Result<(), PlotTwist> {
+            let window = std::time::Duration::from_secs(limit.window_secs);
+            self.requests_by_address
+                .retain(|_, requests| requests.back().is_some_and(|asked_at| now.duration_since(*asked_at) < window));
+            let requests = self.requests_by_address.entry(address.to_string()).or_default();
+            while requests.front().is_some_and(|asked_at| now.duration_since(*asked_at) >= window) {
+                requests.pop_front();
+            }
+            if requests.len() >= limit.allowance_for(address) as usize {
+                return Err(PlotTwist::TooManyRequests(format!(
+                    "{} asked {} times within {} seconds", address, requests.len(), limit.window_secs
+                )));
+            }
+            requests.push_back(now);
+            Ok(())
+        }
+        
+        /// How many addresses still have a request within the window
+        pub fn addresses_remembered(&self) -> usize {
+            self.requests_by_address.len()
+        }
+    }
+}
+
+// =============================================================================
+// THE MAIN SAGA: Orchestrating the Complete Story
+// =============================================================================
+
+/// ## The Complete MEV Protection Saga
+/// 
+/// This is the main story orchestrator that takes an innocent transaction
+/// through its complete journey from vulnerability to safety.
+pub async fn complete_mev_protection_saga(
+    transaction: InnocentTransaction
+) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
+    complete_mev_protection_saga_with_config(transaction, &ProtectionConfig::default()).await
+}
+
+/// ## The Protection Saga Under Custom Orders
+/// 
+/// The same journey, with the guardians following a network-specific
+/// `ProtectionConfig` instead of the default standing orders. Each call
+/// tells a saga of its own, but all of them count requests in one shared
+/// ledger, so the request limit in the orders holds across calls.
+pub async fn complete_mev_protection_saga_with_config(
+    transaction: InnocentTransaction,
+    config: &ProtectionConfig
+) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
+    ProtectionSaga::saga_begins(config.clone())
+        .with_request_ledger(shared_request_ledger())
+        .saga_unfolds(transaction)
+        .await
+}
+
+/// The ledger every free-standing saga counts its requests in
+fn shared_request_ledger() -> std::sync::Arc<std::sync::Mutex<supporting_cast::RequestLedger>> {
+    static SHARED_LEDGER: std::sync::LazyLock<std::sync::Arc<std::sync::Mutex<supporting_cast::RequestLedger>>> =
+        std::sync::LazyLock::new(Default::default);
+    SHARED_LEDGER.clone()
+}
+
+/// ## The Saga and Its Cast
+/// 
+/// Holds the standing orders and the pluggable players the protection
+/// journey relies on, so each can be swapped without rewriting the acts.
+/// A shared guardian service keeps one saga for all its callers, so the
+/// request limit in its orders is counted across them.
+pub struct ProtectionSaga {
+    config: ProtectionConfig,
+    execution_backend: Box<dyn safety_achieved::ExecutionBackend>,
+    post_execution_verifier: Option<Box<dyn safety_achieved::PostExecutionVerifier>>,
+    threat_sensors: Vec<Box<dyn threats_emerge::ThreatSensor>>,
+    spell_strategies: Vec<Box<dyn guardians_shield::SpellStrategy>>,
+    metrics: std::sync::Mutex<supporting_cast::MetricsCollector>,
+    request_ledger: std::sync::Arc<std::sync::Mutex<supporting_cast::RequestLedger>>,
+    mempool: std::sync::Mutex<Vec<InnocentTransaction>>,
+}
+
+impl ProtectionSaga {
+    pub fn saga_begins(config: ProtectionConfig) -> Self {
+        Self {
+            config,
+            execution_backend: Box::new(safety_achieved::SimulatedExecution),
+            post_execution_verifier: None,
+            threat_sensors: Vec::new(),
+            spell_strategies: vec![Box::new(guardians_shield::DefaultStrategy)],
+            metrics: std::sync::Mutex::new(supporting_cast::MetricsCollector::new()),
+            request_ledger: Default::default(),
+            mempool: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+    
+    pub fn with_execution_backend(mut self, backend: Box<dyn safety_achieved::ExecutionBackend>) -> Self {
+        self.execution_backend = backend;
+        self
+    }
+    
+    pub fn with_post_execution_verifier(mut self, verifier: Box<dyn safety_achieved::PostExecutionVerifier>) -> Self {
+        self.post_execution_verifier = Some(verifier);
+        self
+    }
+    
+    /// Counts requests in `ledger` instead of a ledger of the saga's own,
+    /// so every saga holding it shares one request limit
+    pub fn with_request_ledger(mut self, ledger: std::sync::Arc<std::sync::Mutex<supporting_cast::RequestLedger>>) -> Self {
+        self.request_ledger = ledger;
+        self
+    }
+    
+    /// Adds a sensor to the watch. With none added, the built-in heuristics
+    /// watch alone; once any is added, only the added sensors vote.
+    pub fn with_threat_sensor(mut self, sensor: Box<dyn threats_emerge::ThreatSensor>) -> Self {
+        self.threat_sensors.push(sensor);
+        self
+    }
+    
+    /// Replaces how spells are chosen with `strategies`, consulted in order
+    /// until one chooses spells that fit the budget
+    pub fn with_spell_strategies(mut self, strategies: Vec<Box<dyn guardians_shield::SpellStrategy>>) -> Self {
+        self.spell_strategies = strategies;
+        self
+    }
+    
+    /// Races the woven spells against every hunter on the configured
+    /// network; empty when no latency model is configured
+    pub fn protection_races_the_hunters(
+        &self,
+        spells: &[ProtectionSpell],
+        hunters: &[ShadowHunter]
+    ) -> Vec<safety_achieved::RaceOutcome> {
+        let Some(latency) = &self.config.latency else {
+            return Vec::new();
+        };
+        hunters.iter()
+            .map(|hunter| safety_achieved::protection_races_the_hunter(spells, hunter, latency))
+            .collect()
+    }
+    
+    /// Tells the saga of a transaction now pending in the mempool, so each
+    /// victim is judged against the crowd it waits among. A transaction
+    /// heard again replaces what was heard of it before.
+    pub fn mempool_hears(&self, transaction: InnocentTransaction) {
+        let mut mempool = self.mempool.lock().unwrap();
+        mempool.retain(|pending| pending.id != transaction.id);
+        mempool.push(transaction);
+    }
+    
+    /// Forgets a pending transaction once it is mined or dropped
+    pub fn mempool_forgets(&self, transaction_id: &Uuid) {
+        self.mempool.lock().unwrap().retain(|pending| pending.id != *transaction_id);
+    }
+    
+    /// Detections and protections so far, broken down by target contract
+    pub fn metrics_by_contract(&self) -> HashMap<String, supporting_cast::ContractMetrics> {
+        self.metrics.lock().unwrap().metrics_by_contract()
+    }
+    
+    pub async fn saga_unfolds(
+        &self,
+        transaction: InnocentTransaction
+    ) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
+        use threats_emerge::*;
+        use guardians_shield::*;
+        
+        // A shared guardian hears each address only so often
+        if let Some(limit) = &self.config.request_limit {
+            self.request_ledger.lock().unwrap()
+                .request_is_heard(&transaction.user_address, limit, std::time::Instant::now())?;
+        }
+        
+        tracing::info!("📖 Beginning the MEV Protection Saga for transaction {}", transaction.id);
+        
+        // Act I: The threats emerge from the shadows
+        let mempool = self.mempool.lock().unwrap().clone();
+        let (mut vulnerable_transaction, shadow_hunters) = if self.threat_sensors.is_empty() {
+            transaction_enters_dangerous_waters_amid(transaction, &mempool, &self.config.sandwich)?
+        } else {
+            transaction_enters_dangerous_waters_watched_by(
+                transaction,
+                &mempool,
+                &self.threat_sensors,
+                &self.config.sensor_voting
+            )?
+        };
+        
+        // A sender trading with itself earns only a share of the protection
+        let self_dealing = transaction_looks_self_dealing(&vulnerable_transaction, &self.config.wash_trading);
+        if let Some(counterparty) = &self_dealing {
+            tracing::warn!("🪞 Transaction {} deals with {}, a relation of its sender: probable wash trading",
+                           vulnerable_transaction.id, counterparty);
+            vulnerable_transaction.vulnerability_score *= self.config.wash_trading.protection_weight;
+        }
+        self.metrics.lock().unwrap().record_detection(&vulnerable_transaction, &shadow_hunters);
+        
+        if self.config.bypass {
+            let mut unguarded = self.transaction_passes_unguarded(&vulnerable_transaction, &shadow_hunters)?;
+            self.protection_is_verified(&vulnerable_transaction, &mut unguarded)?;
+            Self::journey_notes_self_dealing(&mut unguarded, self_dealing);
+            return Ok(unguarded);
+        }
+        
+        // Act II: The guardians rise to protect
+        let (guardian_protector, protection_spells) = 
+            guardian_temple_awakens_by_strategies(
+                vulnerable_transaction.clone(),
+                shadow_hunters.clone(),
+                self.config.max_protection_cost,
+                &self.spell_strategies
+            )?;
+        
+        // Act III: Safety is achieved in the sacred sanctuary
+        let mut safe_sanctuary = self.sanctuary_holds_or_fortifies(
+            vulnerable_transaction.clone(),
+            shadow_hunters,
+            guardian_protector,
+            protection_spells
+        )?;
+        
+        self.metrics.lock().unwrap().record_protection(&vulnerable_transaction);
+        
+        // Epilogue: the verifier checks that the protection truly held
+        self.protection_is_verified(&vulnerable_transaction, &mut safe_sanctuary)?;
+        Self::journey_notes_self_dealing(&mut safe_sanctuary, self_dealing);
+        
+        tracing::info!("🎊 MEV Protection Saga concluded successfully!");
+        
+        Ok(safe_sanctuary)
+    }
+    
+    fn journey_notes_self_dealing(sanctuary: &mut SafeSanctuary, counterparty: Option<String>) {
+        if let Some(journey) = sanctuary.protection_journey.as_mut() {
+            journey.wash_trading_suspected = counterparty;
+        }
+    }
+    
+    /// ## The Gates Left Open
+    /// 
+    /// Under the kill-switch the transaction goes straight to the backend
+    /// as `Vulnerable`, and the journey records the hunters that were sensed
+    /// and that protection was bypassed.
+    fn transaction_passes_unguarded(
+        &self,
+        transaction: &InnocentTransaction,
+        shadow_hunters: &[ShadowHunter]
+    ) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
+        tracing::warn!("🚨 Protection bypassed for transaction {} with {} hunters sensed",
+                       transaction.id, shadow_hunters.len());
+        
+        let receipt = self.execution_backend.execute(transaction, &SecurityLevel::Vulnerable)?;
+        Ok(SafeSanctuary {
+            protected_transactions: vec![],
+            security_level: SecurityLevel::Vulnerable,
+            guardian_count: 0,
+            execution_receipt: Some(receipt),
+            protection_journey: Some(ProtectionJourney {
+                transaction_id: transaction.id,
+                current_chapter: ChapterName::SafePassage,
+                story_beats: vec![StoryBeat {
+                    timestamp: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs(),
+                    event: "Protection bypassed by the kill-switch".to_string(),
+                    threat_level: transaction.vulnerability_score,
+                    protection_response: format!("Passed through unguarded past {} shadow hunters", shadow_hunters.len()),
+                }],
+                protection_applied: vec![],
+                protection_verdict: None,
+                protection_bypassed: true,
+                wash_trading_suspected: None,
+            }),
+        })
+    }
+    
+    /// Records the verifier's verdict in the sanctuary's journey, so failed
+    /// protections can be counted when calibrating the guardians
+    fn protection_is_verified(
+        &self,
+        transaction: &InnocentTransaction,
+        sanctuary: &mut SafeSanctuary
+    ) -> Result<(), supporting_cast::PlotTwist> {
+        let (Some(verifier), Some(receipt)) = (&self.post_execution_verifier, &sanctuary.execution_receipt) else {
+            return Ok(());
+        };
+        
+        let context = verifier.surrounding_block(receipt)?;
+        let verdict = verifier.verify(transaction, receipt, &context);
+        if let ProtectionVerdict::Failed(reason) = &verdict {
+            tracing::warn!("💔 Protection for transaction {} did not hold: {}", transaction.id, reason);
+        }
+        
+        if let Some(journey) = sanctuary.protection_journey.as_mut() {
+            journey.story_beats.push(StoryBeat {
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs(),
+                event: "Post-execution verification completed".to_string(),
+                threat_level: if verdict == ProtectionVerdict::Held { 0.0 } else { 1.0 },
+                protection_response: format!("{:?}", verdict),
+            });
+            journey.protection_verdict = Some(verdict);
+        }
+        
+        Ok(())
+    }
+    
+    /// Seeks safe harbor with the summoned guardian; if the sanctuary is
+    /// breached, the temple fortifies and execution is attempted once more.
+    pub fn sanctuary_holds_or_fortifies(
+        &self,
+        transaction: InnocentTransaction,
+        shadow_hunters: Vec<ShadowHunter>,
+        guardian_protector: GuardianProtector,
+        protection_spells: Vec<ProtectionSpell>
+    ) -> Result<SafeSanctuary, supporting_cast::PlotTwist> {
+        use guardians_shield::*;
+        use safety_achieved::*;
+        use supporting_cast::PlotTwist;
+        
+        match transaction_finds_safe_harbor(
+            transaction.clone(),
+            guardian_protector,
+            protection_spells,
+            self.execution_backend.as_ref()
+        ) {
+            Err(PlotTwist::SanctuaryBreach(breach)) => {
+                // A breach resolves by fortifying the sanctuary, so fortify and try once more
+                tracing::warn!("🧱 Sanctuary breached ({}), fortifying and retrying once", breach);
+                
+                let (fortified_guardian, fortified_spells) =
+                    guardian_temple_fortifies(transaction.clone(), shadow_hunters)?;
+                transaction_finds_safe_harbor(
+                    transaction,
+                    fortified_guardian,
+                    fortified_spells,
+                    self.execution_backend.as_ref()
+                )
+            }
+            outcome => outcome,
+        }
+    }
+}
+
+// =============================================================================
+// THE BRIDGE: Protected Rituals for the DeFi Orchestrator
+// =============================================================================
+
+pub mod ritual_bridge {
+    use super::*;
+    use crate::defi_protocol_tool::{AssetQuest, DeFiStoryOrchestrator, ProtocolSpirit, SacredRitual};
+    use crate::supporting_cast::PlotTwist;
+    
+    /// The gas price, in gwei, the orchestrator's rituals bid
+    const RITUAL_GAS_PRICE_GWEI: u64 = 20;
+    
+    /// A ritual performed under the guardian's protection, with the
+    /// sanctuary that shielded it; `None` when no hunter took an interest
+    /// in the ritual, so it needed no shielding
+    #[derive(Debug, Clone)]
+    pub struct ProtectedQuest {
+        pub quest: AssetQuest,
+        pub sanctuary: Option<SafeSanctuary>,
+    }
+    
+    /// ## The Ritual Puts On Ordinary Clothes
+    /// 
+    /// How a ritual looks to the mempool: a call from the orchestrator's
+    /// guardian to the spirit's contract, carrying the function selector
+    /// the ritual would send and the amount it moves.
+    pub fn ritual_becomes_transaction(
+        guardian_address: &str,
+        spirit_id: &ProtocolSpirit,
+        ritual: &SacredRitual
+    ) -> InnocentTransaction {
+        let selector: [u8; 4] = match ritual {
+            SacredRitual::AssetTransmutation { .. } => [0x38, 0xed, 0x17, 0x39], // swapExactTokensForTokens
+            SacredRitual::PowerOffering { .. } => [0xe8, 0xed, 0xa9, 0xdf],      // deposit
+            SacredRitual::PowerBorrowing { .. } => [0xa4, 0x15, 0xbc, 0xad],     // borrow
+            SacredRitual::DebtSettlement { .. } => [0x57, 0x3a, 0xde, 0x81],     // repay
+            SacredRitual::PowerReclamation { .. } => [0x69, 0x32, 0x8d, 0xec],   // withdraw
+        };
+        let (_, power_amount) = ritual.ritual_moves();
+        let mut data = selector.to_vec();
+        data.extend_from_slice(&power_amount.to_be_bytes());
+        
+        InnocentTransaction {
+            id: Uuid::new_v4(),
+            user_address: guardian_address.to_string(),
+            target_contract: spirit_contract(spirit_id).to_string(),
+            value: u64::try_from(power_amount).unwrap_or(u64::MAX),
+            gas_price: RITUAL_GAS_PRICE_GWEI,
+            data,
+            vulnerability_score: 0.0,
+        }
+    }
+    
+    /// ## A Ritual Under Guard
+    /// 
+    /// Runs the ritual's transaction through the complete protection saga
+    /// and performs the ritual only once protection has held. A saga that
+    /// fails, or whose verdict is a failure, leaves the guardian untouched.
+    /// A ritual no hunter would prey on is safe as it stands, and is
+    /// performed at once without a saga.
+    pub async fn protected_ritual_unfolds(
+        orchestrator: &mut DeFiStoryOrchestrator,
+        spirit_id: ProtocolSpirit,
+        ritual: SacredRitual
+    ) -> Result<ProtectedQuest, PlotTwist> {
+        protected_ritual_unfolds_with_config(orchestrator, spirit_id, ritual, &ProtectionConfig::default()).await
+    }
+    
+    /// The same ritual under custom orders, its saga counted against the
+    /// request limit of every other free-standing saga
+    pub async fn protected_ritual_unfolds_with_config(
+        orchestrator: &mut DeFiStoryOrchestrator,
+        spirit_id: ProtocolSpirit,
+        ritual: SacredRitual,
+        config: &ProtectionConfig
+    ) -> Result<ProtectedQuest, PlotTwist> {
+        let transaction = ritual_becomes_transaction(
+            &orchestrator.wallet_guardian.mystical_address,
+            &spirit_id,
+            &ritual
+        );
+        let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters(transaction.clone())?;
+        if hunters.is_empty() {
+            let quest = orchestrator.approve_and_execute(spirit_id, ritual).await
+                .map_err(|plot_twist| PlotTwist::RitualAstray(plot_twist.to_string()))?;
+            return Ok(ProtectedQuest { quest, sanctuary: None });
+        }
+        
+        let sanctuary = complete_mev_protection_saga_with_config(transaction, config).await?;
+        
+        let verdict = sanctuary.protection_journey.as_ref()
+            .and_then(|journey| journey.protection_verdict.clone());
+        if let Some(ProtectionVerdict::Failed(reason)) = verdict {
+            return Err(PlotTwist::SanctuaryBreach(reason));
+        }
+        
+        let quest = orchestrator.approve_and_execute(spirit_id, ritual).await
+            .map_err(|plot_twist| PlotTwist::RitualAstray(plot_twist.to_string()))?;
+        Ok(ProtectedQuest { quest, sanctuary: Some(sanctuary) })
+    }
+    
+    /// The mainnet contract each spirit is reached through
+    fn spirit_contract(spirit_id: &ProtocolSpirit) -> &'static str {
+        match spirit_id {
+            ProtocolSpirit::UniswapTheExchanger => "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D", // Uniswap V2 Router
+            ProtocolSpirit::AaveTheGiver => "0x7d2768dE32b0b80b7a3454c06BdAc94A69DDc7A9",        // Aave V2 Lending Pool
+            ProtocolSpirit::CompoundTheGrower => "0x3d9819210A31b4961b30EF54bE2aeD79B9c9Cd3B",   // Compound Comptroller
+            ProtocolSpirit::MakerTheCreator => "0x5ef30b9986345249bc32d8928B7ee64DE9435E39",     // Maker CDP Manager
+            ProtocolSpirit::CurveTheBender => "0xbEbc44782C7dB0a1A60Cb6fe97d0b483032FF1C7",      // Curve 3pool
+        }
+    }
+}
 
 // =============================================================================
 // EXAMPLE USAGE
@@ -665,6 +2023,780 @@ mod tales {
         // Even low-risk transactions should get some protection
         assert!(result.is_ok());
     }
+    
+    #[tokio::test]
+    async fn the_watchers_tally_each_contract_apart() {
+        let saga = ProtectionSaga::saga_begins(ProtectionConfig::default());
+        let uniswap = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+        let uniswap_v3 = "0xE592427A0AEce92De3Edee1F18E0157C05861564";
+        // Every swap goes to a watched router, so each draws hunters and is protected
+        let transactions = vec![
+            InnocentTransaction { target_contract: uniswap.to_string(), ..pending_swap(50_000, 100) },
+            InnocentTransaction { target_contract: uniswap.to_string(), ..pending_swap(20_000, 30) },
+            InnocentTransaction { target_contract: uniswap_v3.to_string(), ..pending_swap(50_000, 100) },
+        ];
+        
+        let mut expected: HashMap<String, (usize, HashMap<AttackType, usize>, f64)> = HashMap::new();
+        for transaction in &transactions {
+            let (assessed, hunters) = threats_emerge::transaction_enters_dangerous_waters(transaction.clone()).unwrap();
+            let entry = expected.entry(assessed.target_contract.clone()).or_default();
+            entry.0 += 1;
+            entry.2 += assessed.vulnerability_score;
+            for hunter in hunters {
+                *entry.1.entry(hunter.attack_type).or_insert(0) += 1;
+            }
+            saga.saga_unfolds(transaction.clone()).await.unwrap();
+        }
+        
+        let metrics = saga.metrics_by_contract();
+        assert_eq!(metrics.len(), 2);
+        for (contract, (observed, threats, vulnerability_total)) in expected {
+            let contract_metrics = &metrics[&contract];
+            assert_eq!(contract_metrics.transactions_observed, observed);
+            assert_eq!(contract_metrics.transactions_protected, observed);
+            assert_eq!(contract_metrics.threats_by_type, threats);
+            assert!((contract_metrics.average_vulnerability_score - vulnerability_total / observed as f64).abs() < 1e-9);
+        }
+        assert_eq!(metrics[uniswap].threats_by_type[&AttackType::Frontrunning], 2);
+        assert_eq!(metrics[uniswap_v3].threats_by_type[&AttackType::Frontrunning], 1);
+    }
+    
+    #[test]
+    fn slow_blocks_give_a_weak_spell_time_to_set() {
+        let swift_bot = ShadowHunter {
+            bot_id: "swift_serpent".to_string(),
+            attack_type: AttackType::SandwichAttack,
+            profit_threshold: 500,
+            speed_rating: 0.98,
+            confidence: 1.0,
+        };
+        let weak_protection = vec![ProtectionSpell::TimeDelayEnchantment];
+        let config_on = |block_time_ms| ProtectionConfig {
+            latency: Some(LatencyModel { block_time_ms, ..LatencyModel::default() }),
+            ..ProtectionConfig::default()
+        };
+        
+        let fast_chain = ProtectionSaga::saga_begins(config_on(2_000));
+        let [fast_race] = &fast_chain.protection_races_the_hunters(&weak_protection, std::slice::from_ref(&swift_bot))[..] else {
+            panic!("one hunter, one race");
+        };
+        assert!(fast_race.bot_wins);
+        assert!(fast_race.bot_ready_ms < fast_race.block_sealed_ms);
+        assert!(fast_race.block_sealed_ms < fast_race.protection_active_ms);
+        
+        let slow_chain = ProtectionSaga::saga_begins(config_on(12_000));
+        let [slow_race] = &slow_chain.protection_races_the_hunters(&weak_protection, std::slice::from_ref(&swift_bot))[..] else {
+            panic!("one hunter, one race");
+        };
+        assert!(!slow_race.bot_wins);
+        
+        let unmodelled = ProtectionSaga::saga_begins(ProtectionConfig::default());
+        assert!(unmodelled.protection_races_the_hunters(&weak_protection, &[swift_bot]).is_empty());
+    }
+    
+    /// Always reports the same hunters, whatever it is shown
+    struct FixedSensor {
+        name: &'static str,
+        sees: Vec<AttackType>,
+    }
+    
+    impl threats_emerge::ThreatSensor for FixedSensor {
+        fn sensor_name(&self) -> &str {
+            self.name
+        }
+        
+        fn sense(
+            &self,
+            _transaction: &InnocentTransaction,
+            _mempool: &[InnocentTransaction]
+        ) -> Result<Vec<ShadowHunter>, supporting_cast::PlotTwist> {
+            Ok(self.sees.iter().map(|attack_type| ShadowHunter {
+                bot_id: format!("{}_{:?}", self.name, attack_type),
+                attack_type: attack_type.clone(),
+                profit_threshold: 1_000,
+                speed_rating: 0.9,
+                confidence: 1.0,
+            }).collect())
+        }
+    }
+    
+    #[test]
+    fn the_watch_votes_on_what_its_sensors_disagree_about() {
+        let sensors: Vec<Box<dyn threats_emerge::ThreatSensor>> = vec![
+            Box::new(FixedSensor { name: "heuristic", sees: vec![AttackType::SandwichAttack, AttackType::Frontrunning] }),
+            Box::new(FixedSensor { name: "learned", sees: vec![AttackType::Frontrunning] }),
+        ];
+        let flagged_under = |voting: SensorVoting| {
+            let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters_watched_by(
+                pending_swap(50_000, 100), &[], &sensors, &voting
+            ).unwrap();
+            hunters.into_iter().map(|hunter| hunter.attack_type).collect::<Vec<_>>()
+        };
+        
+        assert_eq!(flagged_under(SensorVoting::Union), vec![AttackType::SandwichAttack, AttackType::Frontrunning]);
+        assert_eq!(flagged_under(SensorVoting::MajorityVote), vec![AttackType::Frontrunning]);
+        
+        let trust_the_heuristic = SensorVoting::Weighted {
+            weights: BTreeMap::from([("heuristic".to_string(), 3.0)]),
+            threshold: 0.7,
+        };
+        assert_eq!(flagged_under(trust_the_heuristic), vec![AttackType::SandwichAttack, AttackType::Frontrunning]);
+    }
+    
+    fn pending_swap(value: u64, gas_price: u64) -> InnocentTransaction {
+        InnocentTransaction {
+            id: uuid::Uuid::new_v4(),
+            user_address: "0x1234567890123456789012345678901234567890".to_string(),
+            target_contract: "0x0000000000000000000000000000000000000002".to_string(),
+            value,
+            gas_price,
+            data: vec![0x38, 0xed, 0x17, 0x39, 0x00, 0x00, 0x00, 0x64],
+            vulnerability_score: 0.0,
+        }
+    }
+    
+    #[test]
+    fn the_copycats_single_out_the_copyable_one() {
+        let sender = "0x1234567890123456789012345678901234567890";
+        let sender_bytes = [0x12, 0x34, 0x56, 0x78, 0x90].repeat(4);
+        
+        // A lucrative arbitrage call whose profit goes to whoever sends it
+        let open_arbitrage = InnocentTransaction {
+            id: uuid::Uuid::new_v4(),
+            user_address: sender.to_string(),
+            target_contract: "0x0000000000000000000000000000000000000003".to_string(),
+            value: 10_000_000_000,
+            gas_price: 50,
+            data: vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x01],
+            vulnerability_score: 0.0,
+        };
+        
+        // Just as lucrative, but the swap pays out to its sender
+        let mut bound_swap_data = vec![0x38, 0xed, 0x17, 0x39];
+        bound_swap_data.extend_from_slice(&sender_bytes);
+        let bound_swap = InnocentTransaction {
+            data: bound_swap_data,
+            id: uuid::Uuid::new_v4(),
+            ..open_arbitrage.clone()
+        };
+        
+        // Free to copy, but not worth outbidding for
+        let small_call = InnocentTransaction {
+            id: uuid::Uuid::new_v4(),
+            value: 50_000,
+            ..open_arbitrage.clone()
+        };
+        
+        let batch = vec![bound_swap, open_arbitrage.clone(), small_call];
+        let flagged = threats_emerge::batch_reveals_copyable_victims(&batch, &GeneralizedFrontrunParams::default());
+        
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].transaction_id, open_arbitrage.id);
+        assert_eq!(flagged[0].outbid_gas_price, 51);
+    }
+    
+    struct RecordingBackend {
+        seen_levels: std::sync::Arc<std::sync::Mutex<Vec<SecurityLevel>>>,
+    }
+    
+    impl safety_achieved::ExecutionBackend for RecordingBackend {
+        fn execute(
+            &self,
+            transaction: &InnocentTransaction,
+            security_level: &SecurityLevel
+        ) -> Result<ExecutionReceipt, supporting_cast::PlotTwist> {
+            self.seen_levels.lock().unwrap().push(security_level.clone());
+            Ok(ExecutionReceipt {
+                transaction_id: transaction.id,
+                security_level: security_level.clone(),
+                block_number: Some(42),
+                transaction_hash: Some("0xfeed".to_string()),
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn the_saga_hands_execution_to_its_backend() {
+        let seen_levels = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let transaction = pending_swap(50_000, 100);
+        let transaction_id = transaction.id;
+        
+        let sanctuary = ProtectionSaga::saga_begins(ProtectionConfig::default())
+            .with_execution_backend(Box::new(RecordingBackend { seen_levels: seen_levels.clone() }))
+            .saga_unfolds(transaction)
+            .await
+            .unwrap();
+        
+        assert_eq!(*seen_levels.lock().unwrap(), vec![sanctuary.security_level.clone()]);
+        let receipt = sanctuary.execution_receipt.unwrap();
+        assert_eq!(receipt.transaction_id, transaction_id);
+        assert_eq!(receipt.block_number, Some(42));
+        assert_eq!(receipt.transaction_hash.as_deref(), Some("0xfeed"));
+        assert_eq!(sanctuary.protected_transactions, vec![transaction_id]);
+    }
+    
+    #[tokio::test]
+    async fn the_kill_switch_lets_a_hunted_swap_through_unguarded() {
+        let seen_levels = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let transaction = InnocentTransaction {
+            vulnerability_score: 0.9,
+            ..pending_swap(500_000, 100)
+        };
+        let transaction_id = transaction.id;
+        
+        let saga = ProtectionSaga::saga_begins(ProtectionConfig { bypass: true, ..ProtectionConfig::default() })
+            .with_threat_sensor(Box::new(FixedSensor {
+                name: "watch",
+                sees: vec![AttackType::SandwichAttack, AttackType::Frontrunning],
+            }))
+            .with_execution_backend(Box::new(RecordingBackend { seen_levels: seen_levels.clone() }));
+        let sanctuary = saga.saga_unfolds(transaction).await.unwrap();
+        
+        assert_eq!(*seen_levels.lock().unwrap(), vec![SecurityLevel::Vulnerable]);
+        assert_eq!(sanctuary.execution_receipt.unwrap().transaction_id, transaction_id);
+        assert_eq!(sanctuary.guardian_count, 0);
+        let journey = sanctuary.protection_journey.unwrap();
+        assert!(journey.protection_bypassed);
+        assert!(journey.protection_applied.is_empty());
+        assert!(journey.story_beats[0].protection_response.contains("2 shadow hunters"));
+        
+        // The watch still counted what it saw, but nothing was protected
+        let metrics = &saga.metrics_by_contract()["0x0000000000000000000000000000000000000002"];
+        assert_eq!(metrics.transactions_observed, 1);
+        assert_eq!(metrics.threats_by_type[&AttackType::SandwichAttack], 1);
+        assert_eq!(metrics.transactions_protected, 0);
+    }
+    
+    /// Always finds the same attacker wrapped around the protected swap
+    struct SandwichWitness;
+    
+    impl safety_achieved::PostExecutionVerifier for SandwichWitness {
+        fn surrounding_block(&self, receipt: &ExecutionReceipt) -> Result<safety_achieved::BlockContext, supporting_cast::PlotTwist> {
+            let attacker_leg = InnocentTransaction {
+                user_address: "0xbadbadbadbadbadbadbadbadbadbadbadbadbad0".to_string(),
+                ..pending_swap(1_000_000, 500)
+            };
+            Ok(safety_achieved::BlockContext {
+                block_number: receipt.block_number,
+                preceding: vec![attacker_leg.clone()],
+                following: vec![attacker_leg],
+            })
+        }
+        
+        fn verify(
+            &self,
+            transaction: &InnocentTransaction,
+            _receipt: &ExecutionReceipt,
+            context: &safety_achieved::BlockContext
+        ) -> ProtectionVerdict {
+            match safety_achieved::sandwich_surrounds(transaction, context) {
+                Some(sandwich) => ProtectionVerdict::Failed(sandwich),
+                None => ProtectionVerdict::Held,
+            }
+        }
+    }
+    
+    #[tokio::test]
+    async fn the_epilogue_reveals_a_sandwich_after_all() {
+        let saga = ProtectionSaga::saga_begins(ProtectionConfig::default())
+            .with_execution_backend(Box::new(RecordingBackend { seen_levels: Default::default() }))
+            .with_post_execution_verifier(Box::new(SandwichWitness));
+        
+        let sanctuary = saga.saga_unfolds(pending_swap(50_000, 100)).await.unwrap();
+        let journey = sanctuary.protection_journey.unwrap();
+        
+        assert!(matches!(journey.protection_verdict, Some(ProtectionVerdict::Failed(_))));
+        assert_eq!(journey.story_beats.last().unwrap().threat_level, 1.0);
+    }
+    
+    #[test]
+    fn a_tight_budget_keeps_only_the_mightiest_spell() {
+        let hunter = |bot_id: &str, attack_type: AttackType, speed_rating: f64| ShadowHunter {
+            bot_id: bot_id.to_string(),
+            attack_type,
+            profit_threshold: 1000,
+            speed_rating,
+            confidence: 1.0,
+        };
+        let threats = vec![
+            hunter("sandwich", AttackType::SandwichAttack, 0.95),
+            hunter("frontrun", AttackType::Frontrunning, 0.98),
+            hunter("flashloan", AttackType::FlashloanArbitrage, 0.92),
+        ];
+        let transaction = InnocentTransaction {
+            vulnerability_score: 0.9,
+            ..pending_swap(50_000, 100)
+        };
+        
+        let (_, tight_spells) = guardians_shield::guardian_temple_awakens_within_budget(
+            transaction.clone(), threats.clone(), Some(50)
+        ).unwrap();
+        assert_eq!(tight_spells.len(), 1);
+        assert!(matches!(tight_spells[0], ProtectionSpell::PrivateMempool));
+        
+        let (_, generous_spells) = guardians_shield::guardian_temple_awakens_within_budget(
+            transaction.clone(), threats.clone(), Some(1_000)
+        ).unwrap();
+        let (_, unbounded_spells) = guardians_shield::guardian_temple_awakens_to_protect(transaction, threats).unwrap();
+        assert_eq!(generous_spells.len(), 5);
+        assert_eq!(unbounded_spells.len(), 5);
+    }
+    
+    /// Hides every transaction in the private pool, whatever the risk
+    struct AlwaysPrivate;
+    
+    impl guardians_shield::SpellStrategy for AlwaysPrivate {
+        fn strategy_name(&self) -> &str {
+            "always_private"
+        }
+        
+        fn choose_spells(
+            &self,
+            _transaction: &InnocentTransaction,
+            _threat_analysis: &guardians_shield::ThreatAnalysis
+        ) -> Result<Vec<ProtectionSpell>, supporting_cast::PlotTwist> {
+            Ok(vec![ProtectionSpell::PrivateMempool])
+        }
+    }
+    
+    #[test]
+    fn a_custom_strategy_hides_even_a_harmless_swap() {
+        let transaction = InnocentTransaction {
+            vulnerability_score: 0.1,
+            ..pending_swap(50_000, 100)
+        };
+        
+        // The guardian's own judgement finds nothing worth weaving
+        let judged = guardians_shield::guardian_temple_awakens_to_protect(transaction.clone(), Vec::new());
+        assert!(matches!(judged, Err(supporting_cast::PlotTwist::GuardianOverwhelmed(_))));
+        
+        // Consulted first, the default defers to the strategy after it
+        let strategies: Vec<Box<dyn guardians_shield::SpellStrategy>> =
+            vec![Box::new(guardians_shield::DefaultStrategy), Box::new(AlwaysPrivate)];
+        let (_, spells) = guardians_shield::guardian_temple_awakens_by_strategies(
+            transaction, Vec::new(), None, &strategies
+        ).unwrap();
+        assert!(matches!(spells[..], [ProtectionSpell::PrivateMempool]));
+    }
+    
+    /// Offers every spell the guardian knows, many times over
+    struct EveryoneAtOnce;
+    
+    impl guardians_shield::SpellStrategy for EveryoneAtOnce {
+        fn strategy_name(&self) -> &str {
+            "everyone_at_once"
+        }
+        
+        fn choose_spells(
+            &self,
+            _transaction: &InnocentTransaction,
+            _threat_analysis: &guardians_shield::ThreatAnalysis
+        ) -> Result<Vec<ProtectionSpell>, supporting_cast::PlotTwist> {
+            let spells = [
+                ProtectionSpell::PrivateMempool,
+                ProtectionSpell::SandwichImmunity,
+                ProtectionSpell::FlashloanShield,
+                ProtectionSpell::FrontrunningBarrier,
+                ProtectionSpell::TimeDelayEnchantment,
+            ];
+            Ok(spells.into_iter().cycle().take(70).collect())
+        }
+    }
+    
+    #[test]
+    fn a_budget_is_kept_however_many_spells_are_offered() {
+        let strategies: Vec<Box<dyn guardians_shield::SpellStrategy>> = vec![Box::new(EveryoneAtOnce)];
+        let (_, spells) = guardians_shield::guardian_temple_awakens_by_strategies(
+            pending_swap(50_000, 100), Vec::new(), Some(95), &strategies
+        ).unwrap();
+        
+        let cost: u64 = spells.iter().map(ProtectionSpell::protection_cost).sum();
+        let reduction: u64 = spells.iter().map(ProtectionSpell::risk_reduction).sum();
+        assert!(cost <= 95);
+        assert_eq!(reduction, 110);
+        
+        // A budget beyond every spell together weaves them all
+        let (_, spells) = guardians_shield::guardian_temple_awakens_by_strategies(
+            pending_swap(50_000, 100), Vec::new(), Some(u64::MAX), &strategies
+        ).unwrap();
+        assert_eq!(spells.len(), 70);
+    }
+    
+    #[test]
+    fn doubtful_sightings_earn_a_lighter_shield_than_a_certain_one() {
+        let sighting = |bot_id: &str, speed_rating: f64, confidence: f64| ShadowHunter {
+            bot_id: bot_id.to_string(),
+            attack_type: AttackType::SandwichAttack,
+            profit_threshold: 1000,
+            speed_rating,
+            confidence,
+        };
+        let transaction = InnocentTransaction {
+            vulnerability_score: 0.3,
+            ..pending_swap(50_000, 100)
+        };
+        
+        // One doubtful sighting alone moves no spell
+        let lone_rumour = guardians_shield::guardian_temple_awakens_to_protect(
+            transaction.clone(), vec![sighting("rumour", 0.95, 0.3)]
+        );
+        assert!(matches!(lone_rumour, Err(supporting_cast::PlotTwist::GuardianOverwhelmed(_))));
+        
+        // Several together earn the sandwich's own spell, but not the heavy barrier
+        let rumours = vec![
+            sighting("rumour_a", 0.95, 0.3),
+            sighting("rumour_b", 0.95, 0.3),
+            sighting("rumour_c", 0.95, 0.3),
+        ];
+        let (moderate_guardian, moderate_spells) = guardians_shield::guardian_temple_awakens_to_protect(
+            transaction.clone(), rumours
+        ).unwrap();
+        assert!(matches!(moderate_spells[..], [ProtectionSpell::SandwichImmunity]));
+        
+        // One certain sighting brings the barrier too
+        let (strong_guardian, strong_spells) = guardians_shield::guardian_temple_awakens_to_protect(
+            transaction, vec![sighting("certain", 0.98, 0.95)]
+        ).unwrap();
+        assert!(strong_spells.iter().any(|spell| matches!(spell, ProtectionSpell::SandwichImmunity)));
+        assert!(strong_spells.iter().any(|spell| matches!(spell, ProtectionSpell::FrontrunningBarrier)));
+        assert!(strong_guardian.shield_strength > moderate_guardian.shield_strength);
+    }
+    
+    #[test]
+    fn a_breached_sanctuary_fortifies_and_holds() {
+        let seen_levels = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let saga = ProtectionSaga::saga_begins(ProtectionConfig::default())
+            .with_execution_backend(Box::new(RecordingBackend { seen_levels: seen_levels.clone() }));
+        
+        // A guardian far too feeble to hold the sanctuary on the first attempt
+        let feeble_guardian = GuardianProtector {
+            shield_strength: 0.1,
+            private_pool_access: false,
+            flashloan_detection: false,
+            sandwich_immunity: false,
+        };
+        let transaction = pending_swap(50_000, 20);
+        let transaction_id = transaction.id;
+        
+        let sanctuary = saga.sanctuary_holds_or_fortifies(
+            transaction,
+            Vec::new(),
+            feeble_guardian,
+            vec![ProtectionSpell::TimeDelayEnchantment]
+        ).unwrap();
+        
+        assert_eq!(sanctuary.security_level, SecurityLevel::SacredSanctuary);
+        assert_eq!(sanctuary.protected_transactions, vec![transaction_id]);
+        assert_eq!(*seen_levels.lock().unwrap(), vec![SecurityLevel::SacredSanctuary]);
+    }
+    
+    #[test]
+    fn the_same_swap_judged_by_different_networks() {
+        let victim = pending_swap(20_000, 45);
+        let mut mempool: Vec<InnocentTransaction> = (0..5).map(|_| pending_swap(5_000, 48)).collect();
+        mempool.extend((0..5).map(|_| pending_swap(5_000, 30)));
+        
+        let aggressive = SandwichParams { gas_band: 1.0, max_depth: 10, min_profit: 50 };
+        let conservative = SandwichParams { gas_band: 0.1, max_depth: 3, min_profit: 500 };
+        
+        let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters_amid(
+            victim.clone(), &mempool, &aggressive
+        ).unwrap();
+        assert!(hunters.iter().any(|h| h.attack_type == AttackType::SandwichAttack));
+        
+        let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters_amid(
+            victim, &mempool, &conservative
+        ).unwrap();
+        assert!(!hunters.iter().any(|h| h.attack_type == AttackType::SandwichAttack));
+    }
+    
+    #[tokio::test]
+    async fn a_victim_buried_deep_in_the_mempool_is_not_worth_a_sandwich() {
+        let orders = ProtectionConfig {
+            sandwich: SandwichParams { gas_band: 1.0, max_depth: 3, min_profit: 50 },
+            ..ProtectionConfig::default()
+        };
+        let sandwiches = |saga: &ProtectionSaga| saga.metrics_by_contract()["0x0000000000000000000000000000000000000002"]
+            .threats_by_type.get(&AttackType::SandwichAttack).copied().unwrap_or(0);
+        
+        // Alone in the mempool, the swap is the obvious victim
+        let lonely = ProtectionSaga::saga_begins(orders.clone());
+        lonely.saga_unfolds(pending_swap(20_000, 45)).await.unwrap();
+        assert_eq!(sandwiches(&lonely), 1);
+        
+        // Behind five higher bids it sits too deep to bracket, and no spell is called for
+        let crowded = ProtectionSaga::saga_begins(orders);
+        let rivals: Vec<InnocentTransaction> = (0..5).map(|_| pending_swap(5_000, 48)).collect();
+        for rival in &rivals {
+            crowded.mempool_hears(rival.clone());
+        }
+        let unthreatened = crowded.saga_unfolds(pending_swap(20_000, 45)).await;
+        assert!(matches!(unthreatened, Err(supporting_cast::PlotTwist::GuardianOverwhelmed(_))));
+        assert_eq!(sandwiches(&crowded), 0);
+        
+        // Once they are mined, the next such swap stands exposed again
+        for rival in &rivals {
+            crowded.mempool_forgets(&rival.id);
+        }
+        crowded.saga_unfolds(pending_swap(20_000, 45)).await.unwrap();
+        assert_eq!(sandwiches(&crowded), 1);
+    }
+    
+    #[test]
+    fn standing_orders_travel_as_toml_and_return_unchanged() {
+        let orders = ProtectionConfig {
+            sandwich: SandwichParams { gas_band: 0.25, max_depth: 20, min_profit: 300 },
+            max_protection_cost: Some(60),
+            latency: Some(LatencyModel { block_time_ms: 2_000, ..LatencyModel::default() }),
+            sensor_voting: SensorVoting::Weighted {
+                weights: BTreeMap::from([("heuristic".to_string(), 2.0)]),
+                threshold: 0.6,
+            },
+            ..ProtectionConfig::default()
+        };
+        
+        let shared = orders.to_toml().unwrap();
+        assert_eq!(ProtectionConfig::from_toml(&shared).unwrap(), orders);
+        
+        // Whatever the file leaves out falls back to the defaults
+        let sparse = ProtectionConfig::from_toml("bypass = true").unwrap();
+        assert_eq!(sparse, ProtectionConfig { bypass: true, ..ProtectionConfig::default() });
+        
+        let reckless = shared.replace("threshold = 0.6", "threshold = 1.5");
+        assert!(matches!(
+            ProtectionConfig::from_toml(&reckless),
+            Err(supporting_cast::PlotTwist::OrdersMisdrawn(_))
+        ));
+    }
+    
+    #[test]
+    fn the_same_orders_always_write_the_same_toml() {
+        let addresses = ["0xc0ffee", "0xbeef", "0xa11ce", "0xdecaf", "0x0b0b"];
+        let orders_named_in = |order: &[&str]| ProtectionConfig {
+            sensor_voting: SensorVoting::Weighted {
+                weights: order.iter().map(|sensor| (sensor.to_string(), 1.5)).collect(),
+                threshold: 0.5,
+            },
+            request_limit: Some(RequestLimit {
+                per_address: order.iter().map(|address| (address.to_string(), 2)).collect(),
+                ..RequestLimit::default()
+            }),
+            wash_trading: WashTradingParams {
+                related_addresses: order.iter().map(|address| (address.to_string(), vec!["0x5e1f".to_string()])).collect(),
+                ..WashTradingParams::default()
+            },
+            ..ProtectionConfig::default()
+        };
+        
+        let reversed: Vec<&str> = addresses.iter().rev().copied().collect();
+        let written = orders_named_in(&addresses).to_toml().unwrap();
+        assert_eq!(orders_named_in(&reversed).to_toml().unwrap(), written);
+        assert!(written.find("0x0b0b").unwrap() < written.find("0xc0ffee").unwrap());
+    }
+    
+    #[tokio::test]
+    async fn a_flooding_address_is_turned_away_while_its_neighbour_is_heard() {
+        let alice = "0x00000000000000000000000000000000000a11ce".to_string();
+        let bob = "0x0000000000000000000000000000000000000b0b".to_string();
+        let patient = "0x00000000000000000000000000000000000000aa".to_string();
+        let saga = ProtectionSaga::saga_begins(ProtectionConfig {
+            request_limit: Some(RequestLimit {
+                max_requests: 3,
+                window_secs: 3_600,
+                per_address: BTreeMap::from([(patient.clone(), 1)]),
+            }),
+            ..ProtectionConfig::default()
+        });
+        // A large swap to a watched router always draws hunters, and so protection
+        let swap_from = |user_address: &String| InnocentTransaction {
+            user_address: user_address.clone(),
+            target_contract: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string(),
+            ..pending_swap(50_000, 100)
+        };
+        
+        for _ in 0..3 {
+            saga.saga_unfolds(swap_from(&alice)).await.unwrap();
+        }
+        let excess = saga.saga_unfolds(swap_from(&alice)).await;
+        assert!(matches!(excess, Err(supporting_cast::PlotTwist::TooManyRequests(_))));
+        
+        // Alice's flood costs bob nothing
+        saga.saga_unfolds(swap_from(&bob)).await.unwrap();
+        
+        // An address with a limit of its own is held to that instead
+        saga.saga_unfolds(swap_from(&patient)).await.unwrap();
+        assert!(matches!(
+            saga.saga_unfolds(swap_from(&patient)).await,
+            Err(supporting_cast::PlotTwist::TooManyRequests(_))
+        ));
+    }
+    
+    #[test]
+    fn the_ledger_forgets_addresses_gone_quiet() {
+        let limit = RequestLimit { max_requests: 3, window_secs: 60, per_address: BTreeMap::new() };
+        let mut ledger = supporting_cast::RequestLedger::default();
+        let opened = std::time::Instant::now();
+        for sender in ["0xa", "0xb", "0xc"] {
+            ledger.request_is_heard(sender, &limit, opened).unwrap();
+        }
+        assert_eq!(ledger.addresses_remembered(), 3);
+        
+        // A minute on, only the newcomer is still within the window
+        ledger.request_is_heard("0xd", &limit, opened + std::time::Duration::from_secs(60)).unwrap();
+        assert_eq!(ledger.addresses_remembered(), 1);
+    }
+    
+    #[tokio::test]
+    async fn a_sender_paying_its_own_wallet_is_a_probable_wash_trade() {
+        let trader = "0x00000000000000000000000000000000000a11ce";
+        let sock_puppet = "0x0000000000000000000000000000000000000B0B";
+        let mut data = vec![0x38, 0xed, 0x17, 0x39];
+        data.extend([0u8; 12]);
+        data.extend(hex::decode(sock_puppet.trim_start_matches("0x")).unwrap());
+        let swap = InnocentTransaction {
+            user_address: trader.to_string(),
+            data,
+            ..pending_swap(500_000, 100)
+        };
+        let saga_knowing = |related_addresses: BTreeMap<String, Vec<String>>| ProtectionSaga::saga_begins(ProtectionConfig {
+            wash_trading: WashTradingParams { related_addresses, ..WashTradingParams::default() },
+            ..ProtectionConfig::default()
+        }).with_threat_sensor(Box::new(FixedSensor { name: "watch", sees: vec![AttackType::SandwichAttack] }));
+        
+        let strangers = saga_knowing(BTreeMap::new()).saga_unfolds(swap.clone()).await.unwrap();
+        let honest_journey = strangers.protection_journey.unwrap();
+        assert_eq!(honest_journey.wash_trading_suspected, None);
+        
+        // The puppet was funded by the trader, and the swap pays it
+        let related = BTreeMap::from([(trader.to_string(), vec![sock_puppet.to_lowercase()])]);
+        let params = WashTradingParams { related_addresses: related.clone(), ..WashTradingParams::default() };
+        assert_eq!(
+            threats_emerge::transaction_looks_self_dealing(&swap, &params).as_deref(),
+            Some(sock_puppet.to_lowercase().as_str())
+        );
+        let wash = saga_knowing(related).saga_unfolds(swap).await.unwrap();
+        let wash_journey = wash.protection_journey.unwrap();
+        assert_eq!(wash_journey.wash_trading_suspected, Some(sock_puppet.to_lowercase()));
+        assert!(honest_journey.protection_applied.iter().any(|spell| matches!(spell, ProtectionSpell::PrivateMempool)));
+        assert!(!wash_journey.protection_applied.iter().any(|spell| matches!(spell, ProtectionSpell::PrivateMempool)));
+        assert!(wash_journey.protection_applied.len() < honest_journey.protection_applied.len());
+    }
+    
+    #[tokio::test]
+    async fn a_large_transmutation_swaps_only_under_the_guardians_watch() {
+        use defi_protocol_tool::{DeFiStoryOrchestrator, ProtocolSpirit, QuestOutcome, SacredRitual};
+        
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+        let large_swap = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: weth, power_amount: 400_000_000, min_received: 0,
+        };
+        
+        // The mempool sees a swap against the Uniswap router, which hunters notice
+        let disguised = ritual_bridge::ritual_becomes_transaction(
+            &orchestrator.wallet_guardian.mystical_address, &ProtocolSpirit::UniswapTheExchanger, &large_swap
+        );
+        let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters(disguised).unwrap();
+        assert!(hunters.iter().any(|h| h.attack_type == AttackType::SandwichAttack));
+        
+        let protected = ritual_bridge::protected_ritual_unfolds(
+            &mut orchestrator, ProtocolSpirit::UniswapTheExchanger, large_swap
+        ).await.unwrap();
+        
+        assert!(matches!(protected.quest.quest_outcome, QuestOutcome::LegendComplete));
+        let sanctuary = protected.sanctuary.expect("a hunted swap is shielded");
+        assert!(matches!(sanctuary.security_level, SecurityLevel::FullyShielded | SecurityLevel::SacredSanctuary));
+        assert_eq!(sanctuary.protected_transactions.len(), 1);
+        let guardian = &orchestrator.wallet_guardian;
+        assert_eq!(guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 600_000_000);
+        assert!(guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power > 0);
+    }
+    
+    #[tokio::test]
+    async fn the_request_limit_holds_across_free_standing_sagas() {
+        use defi_protocol_tool::{DeFiStoryOrchestrator, ProtocolSpirit, SacredRitual};
+        
+        let orders = ProtectionConfig {
+            request_limit: Some(RequestLimit { max_requests: 2, window_secs: 3_600, ..RequestLimit::default() }),
+            ..ProtectionConfig::default()
+        };
+        let flooder = InnocentTransaction {
+            user_address: "0x00000000000000000000000000000000f100d3e5".to_string(),
+            target_contract: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string(),
+            ..pending_swap(50_000, 100)
+        };
+        for _ in 0..2 {
+            complete_mev_protection_saga_with_config(flooder.clone(), &orders).await.unwrap();
+        }
+        let excess = complete_mev_protection_saga_with_config(flooder, &orders).await;
+        assert!(matches!(excess, Err(supporting_cast::PlotTwist::TooManyRequests(_))));
+        
+        // A guardian sending rituals through the bridge is counted the same way
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x000000000000000000000000000000000bad6a7e".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+        let large_swap = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: weth, power_amount: 300_000_000, min_received: 0,
+        };
+        for _ in 0..2 {
+            ritual_bridge::protected_ritual_unfolds_with_config(
+                &mut orchestrator, ProtocolSpirit::UniswapTheExchanger, large_swap.clone(), &orders
+            ).await.unwrap();
+        }
+        let excess = ritual_bridge::protected_ritual_unfolds_with_config(
+            &mut orchestrator, ProtocolSpirit::UniswapTheExchanger, large_swap, &orders
+        ).await;
+        assert!(matches!(excess, Err(supporting_cast::PlotTwist::TooManyRequests(_))));
+        
+        // The excess ritual never touched the guardian's assets
+        let guardian = &orchestrator.wallet_guardian;
+        assert_eq!(guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 400_000_000);
+    }
+    
+    #[tokio::test]
+    async fn a_ritual_no_hunter_wants_is_performed_without_a_saga() {
+        use defi_protocol_tool::{DeFiStoryOrchestrator, ProtocolSpirit, QuestOutcome, SacredRitual};
+        
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 1_000
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let small_supply = SacredRitual::PowerOffering { asset: dai, power_amount: 400 };
+        
+        // A modest deposit to the lending pool, at an unhurried gas price, tempts nobody
+        let disguised = ritual_bridge::ritual_becomes_transaction(
+            &orchestrator.wallet_guardian.mystical_address, &ProtocolSpirit::AaveTheGiver, &small_supply
+        );
+        let (_, hunters) = threats_emerge::transaction_enters_dangerous_waters(disguised).unwrap();
+        assert!(hunters.is_empty());
+        
+        let protected = ritual_bridge::protected_ritual_unfolds(
+            &mut orchestrator, ProtocolSpirit::AaveTheGiver, small_supply
+        ).await.unwrap();
+        assert!(matches!(protected.quest.quest_outcome, QuestOutcome::LegendComplete));
+        assert!(protected.sanctuary.is_none());
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 600);
+    }
 }
 
 // Example main function for running the saga
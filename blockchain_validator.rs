@@ -3,19 +3,72 @@
 // A Tale of Trust, Consensus, and Digital Truth
 // Where transactions tell their stories and blocks preserve history
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::{SystemTime, UNIX_EPOCH, Duration, Instant};
 use std::thread;
 use std::net::{TcpListener, TcpStream};
 use std::io::{Read, Write};
 
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
-use ed25519_dalek::{Keypair, PublicKey, Signature, Signer, Verifier};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
 use rand::rngs::OsRng;
-use sled::{Db, IVec};
-use tokio::sync::mpsc;
+use sled::{Db, IVec, Tree};
+use tokio::sync::{broadcast, mpsc};
+
+/// The most transaction stories the miner will weave into one chapter
+const MAX_TALES_PER_CHAPTER: usize = 1000;
+
+/// How many heights below the newest peer tip report are remembered
+const PEER_TIP_MEMORY: u64 = 100;
+
+/// The newest story version this node understands, and the one it writes
+const CURRENT_STORY_VERSION: u32 = 2;
+
+/// How many heights below the newest validator signature are remembered
+const EQUIVOCATION_MEMORY: u64 = 1000;
+
+/// Every this many penalties, a validator's stake is slashed
+const SLASHING_PENALTY_THRESHOLD: u64 = 3;
+
+/// The share of its stake, in percent, a validator loses to each slash
+const SLASHED_STAKE_PERCENT: u64 = 10;
+
+/// The first story version allowed to lock outputs behind a script hash
+const SCRIPT_HASH_MIN_VERSION: u32 = 2;
+
+/// The largest message a peer may frame; a longer length prefix ends the connection
+const MAX_NETWORK_FRAME_BYTES: u32 = 32 * 1024 * 1024;
+
+/// How long a listening node rests when no peer has anything to say
+const PEER_HEEDING_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The most chapters one `ChainSyncResponse` carries
+const SYNC_BATCH_CHAPTERS: u64 = 500;
+
+/// How many chapters the median time past is taken over
+const MEDIAN_TIME_SPAN: u64 = 11;
+
+/// Within a retarget interval, no chapter counts as arriving more than this
+/// many target block times after the one before it
+const RETARGET_GAP_LIMIT: u64 = 4;
+
+/// How many chain events a slow subscriber may fall behind before it lags
+const CHAIN_EVENT_CAPACITY: usize = 1024;
+
+/// How many mempool changes are remembered for peers asking what changed
+const MEMPOOL_JOURNAL_MEMORY: usize = 10_000;
+
+/// Where the sync database keeps how far the last sync came
+const SYNC_STATUS_KEY: &str = "sync_status";
+
+/// A fee policy's share is counted in basis points of this whole
+const FEE_BASIS_POINTS_WHOLE: u16 = 10_000;
+
+/// Fee rates are told per this many serialized bytes
+const FEE_RATE_BYTES: u128 = 1_000;
 
 /// ## The Grand Narrative: A Blockchain's Life Story
 /// 
@@ -25,11 +78,21 @@ use tokio::sync::mpsc;
 pub struct BlockchainChronicler {
     chain_repository: ChainRepository,
     mempool_of_pending_tales: Arc<Mutex<Vec<TransactionStory>>>,
+    mempool_audit_trail: Arc<Mutex<VecDeque<MempoolAuditEntry>>>,
+    mempool_journal: Arc<Mutex<MempoolJournal>>,
+    mempool_policy_hooks: Vec<Box<dyn MempoolPolicyHook>>,
+    priority_boosts: Arc<Mutex<HashMap<String, u64>>>,
+    own_unconfirmed_stories: Arc<Mutex<HashMap<String, RebroadcastWatch>>>,
     validator_council: ValidatorCouncil,
     network_storytellers: NetworkOfStoryTellers,
     utxo_ledger: UTXOLedger,
     configuration: ChronicleConfiguration,
     mining_heart: Option<MiningHeart>,
+    chain_events: broadcast::Sender<ChainEvent>,
+    pending_sync_branch: Vec<BlockChapter>,
+    /// The chain and unspent outputs at the tip last frozen, shared by every
+    /// snapshot taken while that tip stands
+    snapshot_base: Arc<Mutex<Option<SnapshotBase>>>,
 }
 
 /// ## Chapter Structure: Each Block Tells Its Tale
@@ -47,6 +110,39 @@ pub struct BlockChapter {
     chapter_essence: String,
     proof_of_storytelling: ProofOfWork,
     chapter_size_bytes: usize,
+    /// The root over the unspent outputs as they stand once the chapter is
+    /// applied, for light clients to prove state against; `None` when the
+    /// miner commits none
+    utxo_state_root: Option<String>,
+}
+
+/// ## A Chapter Remembered Without Its Tales
+/// 
+/// Everything about a chapter but its stories: enough to check that the
+/// chain still links up and was honestly mined, after the body is pruned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub chapter_number: u64,
+    pub timestamp_of_creation: u64,
+    pub previous_chapter_essence: String,
+    pub merkle_tree_of_truth: String,
+    pub chapter_essence: String,
+    pub proof_of_storytelling: ProofOfWork,
+    pub utxo_state_root: Option<String>,
+}
+
+impl BlockChapter {
+    pub fn header(&self) -> BlockHeader {
+        BlockHeader {
+            chapter_number: self.chapter_number,
+            timestamp_of_creation: self.timestamp_of_creation,
+            previous_chapter_essence: self.previous_chapter_essence.clone(),
+            merkle_tree_of_truth: self.merkle_tree_of_truth.clone(),
+            chapter_essence: self.chapter_essence.clone(),
+            proof_of_storytelling: self.proof_of_storytelling.clone(),
+            utxo_state_root: self.utxo_state_root.clone(),
+        }
+    }
 }
 
 /// ## Individual Transaction Stories
@@ -55,6 +151,7 @@ pub struct BlockChapter {
 /// the digital realm, seeking validation and permanence.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TransactionStory {
+    version: u32,
     story_id: String,
     inputs_consumed: Vec<UTXOReference>,
     outputs_created: Vec<UTXOOutput>,
@@ -63,13 +160,33 @@ pub struct TransactionStory {
     transaction_nonce: u64,
     digital_signature: Vec<u8>,
     public_key_of_narrator: Vec<u8>,
+    fee_sponsorship: Option<FeeSponsorship>,
+    /// What each input offers its locking script beyond the narrator's own
+    /// signature, in the order of `inputs_consumed`; may be empty
+    input_witnesses: Vec<InputWitness>,
+    /// Set only on a chapter's coinbase, the story that pays its miner:
+    /// the height it pays for. A coinbase consumes nothing.
+    coinbase_height: Option<u64>,
+}
+
+/// ## A Patron Pays the Storyteller's Fee
+/// 
+/// A third party covers `story_fee` from its own inputs, so the narrator's
+/// inputs need only cover the outputs. Any leftover returns to the sponsor
+/// as an extra output indexed after the story's own outputs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeeSponsorship {
+    sponsor_public_key: Vec<u8>,
+    sponsor_inputs: Vec<UTXOReference>,
+    sponsor_change: Option<UTXOOutput>,
+    sponsor_signature: Vec<u8>,
 }
 
 /// ## UTXO: Unspent Tale Outputs
 /// 
 /// These represent the unspent outputs that can be used
 /// as inputs for future transaction stories.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct UTXOReference {
     previous_story_id: String,
     output_index: u32,
@@ -89,6 +206,8 @@ pub struct ScriptOfTruth {
     public_keys: Vec<Vec<u8>>,
 }
 
+/// `PayToScriptHash` commits to a script by its hash, held as the sole
+/// entry of `public_keys`; the spender reveals the script itself.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ScriptType {
     PayToPublicKey,
@@ -96,18 +215,241 @@ pub enum ScriptType {
     PayToScriptHash,
 }
 
+/// ## The Key Offered to a Lock
+/// 
+/// Extra signatures over the story's signable message, and for a script
+/// hash the script that was committed to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InputWitness {
+    signatures: Vec<Vec<u8>>,
+    revealed_script: Option<ScriptOfTruth>,
+}
+
+/// ## The Script Interpreter
+/// 
+/// Judges whether the signatures offered over one message satisfy a
+/// locking script.
+pub struct ScriptInterpreter {
+    message: Vec<u8>,
+    offered_signatures: Vec<Vec<u8>>,
+}
+
 /// ## The Proof of Work: Storytelling Effort
 /// 
 /// The computational effort required to earn the right
 /// to tell the next chapter in the blockchain's saga.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProofOfWork {
-    difficulty_target: u64,
+    difficulty_bits: CompactTarget,
     nonce_of_discovery: u64,
     storyteller_reward: u64,
     hash_rate_estimate: f64,
 }
 
+/// ## The Line a Chapter's Essence Must Fall Beneath
+/// 
+/// A 256-bit unsigned target, held as 32 big-endian bytes so that byte
+/// order and numeric order agree; a chapter's hash, read the same way,
+/// must not exceed it. A larger target is an easier one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct DifficultyTarget([u8; 32]);
+
+/// ## A Target Folded Small
+/// 
+/// The compact "bits" form a chapter carries its target in, as Bitcoin's
+/// nBits: the top byte counts the target's significant bytes and the low
+/// three bytes hold its leading digits. Bit 23 would mark a negative
+/// target, which no chapter may carry. Folding rounds a target down, so
+/// the chain always works against the target its bits unfold to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CompactTarget(u32);
+
+/// The easiest target the chronicle starts from: four leading zero bits
+const GENESIS_DIFFICULTY_TARGET: DifficultyTarget = DifficultyTarget::with_leading_zero_bits(4);
+
+/// When every chronicle's first chapter claims to have been written
+const GENESIS_TIMESTAMP: u64 = 1_700_000_000;
+
+/// The nonce the genesis chapter is hashed with; it is decreed, not mined
+const GENESIS_NONCE: u64 = 0;
+
+impl DifficultyTarget {
+    pub const MAX: DifficultyTarget = DifficultyTarget([0xFF; 32]);
+
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Every bit set except the first `bits`, as in `0x0FFF...` for four
+    pub const fn with_leading_zero_bits(bits: u32) -> Self {
+        let mut bytes = [0xFF; 32];
+        let mut index = 0;
+        while index < 32 {
+            let bits_before = index as u32 * 8;
+            if bits >= bits_before + 8 {
+                bytes[index] = 0;
+            } else if bits > bits_before {
+                bytes[index] = 0xFF >> (bits - bits_before);
+            }
+            index += 1;
+        }
+        Self(bytes)
+    }
+
+    /// Reads a 64-character hex hash as a big-endian 256-bit number
+    pub fn from_hex(hash: &str) -> Result<Self, ChronicleError> {
+        let bytes = hex::decode(hash)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        let bytes: [u8; 32] = bytes.try_into()
+            .map_err(|bytes: Vec<u8>| ChronicleError::SerializationError(format!(
+                "A 256-bit value needs 32 bytes, got {}", bytes.len()
+            )))?;
+        Ok(Self(bytes))
+    }
+
+    /// The target a difficulty stands for, `MAX / difficulty`, so that
+    /// difficulty one is the easiest target of all. Zero is read as one.
+    pub fn from_difficulty(difficulty: u64) -> Self {
+        Self::MAX.scaled(1, difficulty)
+    }
+
+    /// How many times harder than `MAX` this target is, rounded down and
+    /// saturating at `u64::MAX`
+    pub fn difficulty(&self) -> u64 {
+        // Long division of MAX (every bit set) by the target, one quotient bit at a time
+        let mut remainder = [0u8; 32];
+        let mut quotient = 0u64;
+        for _ in 0..256 {
+            let mut carry = 1u16;
+            for byte in remainder.iter_mut().rev() {
+                let doubled = ((*byte as u16) << 1) | carry;
+                *byte = doubled as u8;
+                carry = doubled >> 8;
+            }
+            if quotient >> 63 != 0 {
+                return u64::MAX;
+            }
+            quotient <<= 1;
+
+            if carry != 0 || remainder >= self.0 {
+                let mut borrow = 0i16;
+                for (byte, &divisor_byte) in remainder.iter_mut().zip(self.0.iter()).rev() {
+                    let difference = *byte as i16 - divisor_byte as i16 - borrow;
+                    *byte = difference.rem_euclid(256) as u8;
+                    borrow = (difference < 0) as i16;
+                }
+                quotient |= 1;
+            }
+        }
+        quotient
+    }
+
+    /// Folds the target into its compact bits, dropping every digit after
+    /// the leading three
+    pub fn to_compact(&self) -> CompactTarget {
+        let Some(first_significant) = self.0.iter().position(|&byte| byte != 0) else {
+            return CompactTarget(0);
+        };
+        let mut size = (32 - first_significant) as u32;
+        let mut mantissa = self.0[first_significant..].iter().take(3)
+            .fold(0u32, |mantissa, &byte| (mantissa << 8) | byte as u32);
+        if size < 3 {
+            mantissa <<= 8 * (3 - size);
+        }
+        // A mantissa with its top bit set would read as negative, so it gives up a digit
+        if mantissa & 0x0080_0000 != 0 {
+            mantissa >>= 8;
+            size += 1;
+        }
+        CompactTarget((size << 24) | mantissa)
+    }
+
+    /// `self * numerator / denominator`, saturating at `MAX`
+    pub fn scaled(&self, numerator: u64, denominator: u64) -> Self {
+        let denominator = denominator.max(1) as u128;
+
+        // Multiply into five little-endian 64-bit limbs
+        let mut limbs = [0u64; 5];
+        let mut carry = 0u128;
+        for (limb_index, chunk) in self.0.rchunks(8).enumerate() {
+            let limb = u64::from_be_bytes(chunk.try_into().unwrap()) as u128;
+            let product = limb * numerator as u128 + carry;
+            limbs[limb_index] = product as u64;
+            carry = product >> 64;
+        }
+        limbs[4] = carry as u64;
+
+        // Then divide from the most significant limb down
+        let mut remainder = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let dividend = (remainder << 64) | *limb as u128;
+            *limb = (dividend / denominator) as u64;
+            remainder = dividend % denominator;
+        }
+
+        if limbs[4] != 0 {
+            return Self::MAX;
+        }
+        let mut bytes = [0u8; 32];
+        for (limb_index, chunk) in bytes.rchunks_mut(8).enumerate() {
+            chunk.copy_from_slice(&limbs[limb_index].to_be_bytes());
+        }
+        Self(bytes)
+    }
+}
+
+impl CompactTarget {
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Unfolds the bits into the full 256-bit target, refusing bits that
+    /// claim a negative target or one too large for 256 bits
+    pub fn to_target(&self) -> Result<DifficultyTarget, ChronicleError> {
+        let size = (self.0 >> 24) as usize;
+        let mantissa = self.0 & 0x007F_FFFF;
+        if self.0 & 0x0080_0000 != 0 && mantissa != 0 {
+            return Err(ChronicleError::ProofOfWorkFailed(format!("Bits {} claim a negative target", self)));
+        }
+
+        let mut bytes = [0u8; 32];
+        for (offset, &byte) in mantissa.to_be_bytes()[1..].iter().enumerate() {
+            // Digits shifted past the right end are simply dropped
+            let Some(place_from_right) = size.checked_sub(offset + 1) else {
+                continue;
+            };
+            if place_from_right >= 32 {
+                if byte != 0 {
+                    return Err(ChronicleError::ProofOfWorkFailed(format!("Bits {} overflow 256 bits", self)));
+                }
+                continue;
+            }
+            bytes[31 - place_from_right] = byte;
+        }
+        Ok(DifficultyTarget(bytes))
+    }
+}
+
+impl std::fmt::Display for CompactTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:08x}", self.0)
+    }
+}
+
+impl std::fmt::Display for DifficultyTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
 /// ## Persistent Chain Repository
 /// 
 /// The eternal keeper of all blockchain stories,
@@ -116,29 +458,53 @@ pub struct ChainRepository {
     block_db: Db,
     tx_db: Db,
     utxo_db: Db,
+    sync_db: Db,
+    nonce_db: Db,
+    mempool_db: Db,
+    header_tree: Tree,
+    /// The height of every stored header, keyed by its chapter essence
+    essence_tree: Tree,
     chain_tip: Arc<RwLock<Option<BlockChapter>>>,
     block_index: Arc<RwLock<HashMap<String, u64>>>,
+    /// The height each peer syncing from us will ask for next, while it is
+    /// still mid-sync; chapters from the lowest of them may not be pruned
+    sync_served_from: Arc<RwLock<HashMap<String, u64>>>,
 }
 
 /// ## UTXO Ledger: Keeper of Unspent Stories
 /// 
 /// Tracks all unspent transaction outputs that can be
 /// used as inputs for new transaction stories.
+#[derive(Clone)]
 pub struct UTXOLedger {
     unspent_outputs: Arc<RwLock<HashMap<String, UTXOOutput>>>,
     spent_outputs: Arc<RwLock<HashSet<String>>>,
     db: Db,
+    /// For every output spent on the chain: the story that spent it and the
+    /// chapter it did so in
+    spend_provenance: Tree,
+    /// For every output created on the chain: the chapter that created it
+    output_birthplace: Tree,
 }
 
 /// ## Network of Story Tellers
 /// 
 /// Manages connections with other blockchain nodes,
 /// sharing stories and synchronizing the eternal chain.
+#[derive(Clone)]
 pub struct NetworkOfStoryTellers {
     peer_connections: Arc<Mutex<Vec<PeerConnection>>>,
     message_broadcaster: Arc<Mutex<mpsc::UnboundedSender<NetworkMessage>>>,
+    transport: Arc<RwLock<Arc<dyn Transport>>>,
+    /// Each message heard from a peer, with the address it came from
+    incoming_sender: mpsc::UnboundedSender<(String, NetworkMessage)>,
+    incoming_messages: Arc<Mutex<mpsc::UnboundedReceiver<(String, NetworkMessage)>>>,
     sync_status: Arc<RwLock<SyncStatus>>,
     known_peers: Arc<RwLock<HashSet<String>>>,
+    peer_tip_reports: Arc<RwLock<HashMap<u64, HashMap<String, String>>>>,
+    /// How far each peer's clock runs ahead of ours, in seconds, as it
+    /// last reported
+    peer_clock_offsets: Arc<RwLock<HashMap<String, i64>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -149,6 +515,69 @@ pub struct PeerConnection {
     sync_height: u64,
 }
 
+/// ## Transport: The Roads Between Story Tellers
+///
+/// Carries what this node announces to its peers. The network does not
+/// care whether the road is a TCP stream or a simulated link in a test.
+pub trait Transport: Send + Sync {
+    /// Carries the message to every peer
+    fn carry(&self, message: &NetworkMessage) -> Result<(), ChronicleError>;
+    /// Carries the message to the one peer at `peer_address`, as when
+    /// answering what that peer asked
+    fn carry_to(&self, peer_address: &str, message: &NetworkMessage) -> Result<(), ChronicleError>;
+}
+
+/// The default road: every connected peer's TCP stream receives the
+/// message as one length-prefixed bincode frame. A peer whose stream
+/// fails mid-write is taken to have left and is dropped.
+pub struct TcpTransport {
+    peer_connections: Arc<Mutex<Vec<PeerConnection>>>,
+}
+
+/// ## Sim Network: Story Tellers Joined by Imagined Roads
+///
+/// An in-memory network whose clock only moves when a message is
+/// delivered. Each link carries its own latency and chance of loss, and
+/// losses are drawn from a seeded generator, so a scenario plays out the
+/// same way every time it runs.
+#[derive(Clone)]
+pub struct SimNetwork {
+    state: Arc<Mutex<SimState>>,
+}
+
+/// How a one-way link between two sim nodes behaves
+#[derive(Debug, Clone, Copy)]
+pub struct SimLink {
+    pub latency: Duration,
+    pub loss_probability: f64,
+}
+
+/// A message arriving at a sim node, at the simulated time it lands
+#[derive(Debug, Clone)]
+pub struct SimDelivery {
+    pub arrives_at: Duration,
+    pub from_node: usize,
+    pub to_node: usize,
+    pub message: NetworkMessage,
+}
+
+struct SimState {
+    now: Duration,
+    node_count: usize,
+    links: HashMap<(usize, usize), SimLink>,
+    in_flight: BinaryHeap<Reverse<(Duration, u64, usize)>>,
+    /// Each message in flight with the node that sent it
+    messages: HashMap<u64, (usize, NetworkMessage)>,
+    next_sequence: u64,
+    rng_state: u64,
+}
+
+/// One node's handle onto a `SimNetwork`
+pub struct SimTransport {
+    node: usize,
+    network: SimNetwork,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NetworkMessage {
     NewTransactionStory(TransactionStory),
@@ -157,9 +586,47 @@ pub enum NetworkMessage {
     ChainSyncResponse(Vec<BlockChapter>),
     PeerDiscovery(Vec<String>),
     Heartbeat(u64),
+    /// Sent first by the side that opens a connection: the height and tip
+    /// essence of our chain, so the peer can both sync and watch for a split,
+    /// and the time our clock shows, so it can follow the network's time.
+    /// The accepting side answers with a `Heartbeat` only if it is ahead.
+    Handshake {
+        chain_height: u64,
+        tip_essence: String,
+        clock_time: u64,
+    },
+    /// Tells the peers a story or chapter (named by its id or essence) was
+    /// refused, and why, so the one who sent it can find out what went wrong
+    Reject {
+        target_id: String,
+        reason_code: RejectCode,
+        description: String,
+    },
 }
 
-#[derive(Debug, Clone)]
+/// Why a story or chapter from a peer was refused, in the coarse terms
+/// every node agrees on; the description carries the details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectCode {
+    /// It could not be decoded at all
+    Malformed,
+    /// It breaks the rules of the chain
+    Invalid,
+    /// It uses a version no longer understood
+    Obsolete,
+    /// It was already known, or tried to replace what is already known
+    Duplicate,
+    /// It is valid but refused by this node's policy
+    NonStandard,
+    /// It pays too little, in total or per byte
+    InsufficientFee,
+    /// It would reorganize the chain deeper than this node allows
+    Checkpoint,
+}
+
+/// How far a sync has come. It is kept on disk, so a node interrupted
+/// mid-sync asks only for the chapters it has not yet validated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncStatus {
     is_syncing: bool,
     current_height: u64,
@@ -172,10 +639,14 @@ pub struct SyncStatus {
 /// The computational heart that seeks valid hashes
 /// through persistent effort and storytelling passion.
 pub struct MiningHeart {
+    /// Cleared while mining is paused; the quest waits until it is set again
     is_beating: Arc<Mutex<bool>>,
-    current_difficulty: Arc<RwLock<u64>>,
+    /// Set once mining is stopped for good; the quest ends its loop on seeing it
+    is_stilled: Arc<Mutex<bool>>,
+    current_difficulty: Arc<RwLock<DifficultyTarget>>,
     hash_rate: Arc<RwLock<f64>>,
     mining_reward_address: Vec<u8>,
+    mining_quest: Option<tokio::task::JoinHandle<()>>,
     thread_handles: Vec<thread::JoinHandle<()>>,
 }
 
@@ -188,6 +659,23 @@ pub struct ValidatorCouncil {
     consensus_threshold: f64,
     current_storyteller: Option<String>,
     reputation_system: ReputationSystem,
+    equivocation_watchtower: EquivocationWatchtower,
+}
+
+/// ## The Watchtower Over the Validators
+/// 
+/// Remembers which chapter each validator signed at each height, so a
+/// validator signing two different chapters at one height is caught.
+#[derive(Debug, Default)]
+pub struct EquivocationWatchtower {
+    signed_claims: HashMap<u64, HashMap<String, SignedChapterClaim>>,
+    slashed_at: HashSet<(String, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedChapterClaim {
+    pub chapter_essence: String,
+    pub signature: Vec<u8>,
 }
 
 #[derive(Debug, Clone)]
@@ -218,6 +706,28 @@ pub enum PenaltyType {
     DoubleSpending,
     MalformedTransaction,
     NetworkMisbehavior,
+    Equivocation,
+}
+
+/// What a penalty left a validator with
+#[derive(Debug, Clone, PartialEq)]
+pub struct PenaltyOutcome {
+    pub reputation_score: f64,
+    /// Whether the penalty brought the validator's offences to a slashing threshold
+    pub slash_due: bool,
+}
+
+impl PenaltyType {
+    /// How much reputation the offence costs, out of a full score of 1.0
+    fn reputation_cost(&self) -> f64 {
+        match self {
+            PenaltyType::InvalidSignature => 0.1,
+            PenaltyType::DoubleSpending => 0.5,
+            PenaltyType::MalformedTransaction => 0.05,
+            PenaltyType::NetworkMisbehavior => 0.1,
+            PenaltyType::Equivocation => 1.0,
+        }
+    }
 }
 
 /// ## Chronicle Configuration
@@ -229,12 +739,102 @@ pub struct ChronicleConfiguration {
     pub target_block_time: Duration,
     pub difficulty_adjustment_interval: u64,
     pub max_block_size: usize,
+    /// The flat floor every story's fee must reach, however small the story
     pub min_transaction_fee: u64,
+    /// The lowest fee per thousand serialized bytes a story may pay to enter
+    /// the mempool, so a larger story must pay proportionally more than the flat floor
+    pub min_fee_rate: u64,
+    /// How many stories the mempool holds before the lowest fee rates are evicted
+    pub max_mempool_stories: usize,
     pub base_mining_reward: u64,
     pub reward_halving_interval: u64,
+    /// Who shares each coinbase, as `(address, weight)`, and so in what
+    /// proportion, as a mining pool would; empty pays the miner alone
+    pub reward_beneficiaries: Vec<(Vec<u8>, u64)>,
+    /// What becomes of the fees a chapter collects: all to its miner, or a
+    /// share burned or sent to a treasury
+    pub fee_policy: FeePolicy,
     pub max_peers: usize,
     pub network_port: u16,
     pub data_directory: String,
+    pub max_mempool_audit_entries: usize,
+    pub max_future_drift_secs: u64,
+    /// The furthest the network-adjusted clock may stray from our own,
+    /// however far the peers' median reported offset lies
+    pub max_peer_clock_offset_secs: u64,
+    pub strict_story_versions: bool,
+    pub rebroadcast_interval: Duration,
+    pub rebroadcast_backoff: f64,
+    pub max_rebroadcast_interval: Duration,
+    pub rebroadcast_expiry: Duration,
+    pub node_mode: NodeMode,
+    /// An observer validates, relays and answers queries but never mines;
+    /// its mining heart is never awakened
+    pub observer_mode: bool,
+    /// Whether the chapters this node mines commit to the state root of
+    /// the unspent outputs they leave behind
+    pub commit_state_roots: bool,
+}
+
+/// ## How Much of the Past a Node Keeps
+/// 
+/// An archival node keeps and serves every chapter. A pruned node keeps
+/// only its most recent `keep_blocks` chapters, and answers questions about
+/// older ones with `ChronicleError::ChapterPruned`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NodeMode {
+    #[default]
+    Archival,
+    Pruned { keep_blocks: u64 },
+}
+
+/// ## Where the Fees Go
+/// 
+/// A chapter's fees all pay its miner, or a share of them is burned,
+/// never minted so the total supply shrinks by it, or sent to a treasury
+/// in an output of the coinbase. The share is given in basis points and
+/// reckoned in whole numbers, rounded down, so every node computes the
+/// same split. The block reward itself always pays the miner.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FeePolicy {
+    #[default]
+    AllToMiner,
+    BurnBasisPoints(u16),
+    TreasurySplit(Vec<u8>, u16),
+}
+
+impl FeePolicy {
+    /// The basis points of the fees taken from the miner, if any
+    fn diverted_basis_points(&self) -> u16 {
+        match self {
+            FeePolicy::AllToMiner => 0,
+            FeePolicy::BurnBasisPoints(basis_points) | FeePolicy::TreasurySplit(_, basis_points) => *basis_points,
+        }
+    }
+
+    /// How much of `collected_fees` leaves the miner's pay
+    fn diverted_share(&self, collected_fees: u64) -> u64 {
+        let share = u128::from(collected_fees) * u128::from(self.diverted_basis_points())
+            / u128::from(FEE_BASIS_POINTS_WHOLE);
+        u64::try_from(share).map_or(collected_fees, |share| share.min(collected_fees))
+    }
+
+    /// The treasury and what it is owed from `collected_fees`
+    fn treasury_share(&self, collected_fees: u64) -> Option<(&[u8], u64)> {
+        match self {
+            FeePolicy::TreasurySplit(treasury, _) => Some((treasury.as_slice(), self.diverted_share(collected_fees))),
+            _ => None,
+        }
+    }
+}
+
+/// One of our own stories, re-announced to peers until it confirms or expires
+#[derive(Debug, Clone)]
+struct RebroadcastWatch {
+    story: TransactionStory,
+    first_announced: Instant,
+    next_announcement: Instant,
+    rebroadcasts: u32,
 }
 
 impl BlockchainChronicler {
@@ -245,18 +845,52 @@ impl BlockchainChronicler {
     pub async fn new_chronicle_begins(config: ChronicleConfiguration) -> Result<Self, ChronicleError> {
         println!("📖 A new blockchain chronicle begins...");
         
+        let mut chronicle = Self::chronicle_gathers_its_cast(config).await?;
+
+        // Start the mining heart, unless the node only watches
+        if chronicle.configuration.observer_mode {
+            println!("👁️  Observer mode: this node validates and relays but never mines");
+        } else {
+            chronicle.awaken_the_mining_heart().await?;
+        }
+        
+        // Begin network synchronization
+        chronicle.begin_network_synchronization().await?;
+
+        // Keep our own stories alive in peers' mempools
+        chronicle.begin_rebroadcasting_own_stories();
+
+        println!("✨ Chronicle initialization complete!");
+        Ok(chronicle)
+    }
+
+    /// Assembles the chronicle's storage, network and genesis chapter
+    /// without yet awakening the mining heart or the network.
+    async fn chronicle_gathers_its_cast(config: ChronicleConfiguration) -> Result<Self, ChronicleError> {
+        config.validate()?;
         let chain_repository = ChainRepository::new(&config.data_directory).await?;
-        let utxo_ledger = UTXOLedger::new(&config.data_directory).await?;
+        let utxo_ledger = UTXOLedger::new(chain_repository.utxo_db.clone()).await?;
         let network = NetworkOfStoryTellers::new(config.network_port).await?;
+        if let Some(persisted_status) = chain_repository.load_sync_status()? {
+            *network.sync_status.write().unwrap() = persisted_status;
+        }
         
         let mut chronicle = Self {
             chain_repository,
             mempool_of_pending_tales: Arc::new(Mutex::new(Vec::new())),
+            mempool_audit_trail: Arc::new(Mutex::new(VecDeque::new())),
+            mempool_journal: Arc::new(Mutex::new(MempoolJournal::default())),
+            mempool_policy_hooks: Vec::new(),
+            priority_boosts: Arc::new(Mutex::new(HashMap::new())),
+            own_unconfirmed_stories: Arc::new(Mutex::new(HashMap::new())),
             validator_council: ValidatorCouncil::new(),
             network_storytellers: network,
             utxo_ledger,
-            configuration: config.clone(),
+            configuration: config,
             mining_heart: None,
+            chain_events: broadcast::channel(CHAIN_EVENT_CAPACITY).0,
+            pending_sync_branch: Vec::new(),
+            snapshot_base: Arc::new(Mutex::new(None)),
         };
 
         // Create genesis block if this is a new chain
@@ -264,16 +898,36 @@ impl BlockchainChronicler {
             chronicle.craft_and_commit_genesis_chapter().await?;
         }
 
-        // Start the mining heart
-        chronicle.awaken_the_mining_heart().await?;
-        
-        // Begin network synchronization
-        chronicle.begin_network_synchronization().await?;
+        chronicle.pending_tales_return().await?;
 
-        println!("✨ Chronicle initialization complete!");
         Ok(chronicle)
     }
 
+    /// Brings back the stories that were pending when the node last stopped.
+    /// Each goes through admission again against the ledger as it stands
+    /// now, so a story whose inputs were spent while the node was down, or
+    /// that is otherwise no longer valid, is forgotten instead.
+    async fn pending_tales_return(&mut self) -> Result<(), ChronicleError> {
+        let mut forgotten = Vec::new();
+        for story in self.chain_repository.load_pending_stories()? {
+            match self.story_earns_its_place_in_mempool(&story).await {
+                Ok(()) => self.mempool_of_pending_tales.lock().unwrap().push(story),
+                Err(plot_twist) => {
+                    self.record_mempool_verdict(&story.story_id, MempoolVerdict::Rejected(format!("{:?}", plot_twist)));
+                    forgotten.push(story.story_id);
+                }
+            }
+        }
+        self.chain_repository.record_pending_stories(&[], &forgotten)?;
+        self.journal_mempool_changes();
+
+        let returned = self.mempool_of_pending_tales.lock().unwrap().len();
+        if returned > 0 || !forgotten.is_empty() {
+            println!("📥 {} pending stories returned, {} forgotten", returned, forgotten.len());
+        }
+        Ok(())
+    }
+
     /// ## Act I: Transaction Stories Arrive
     /// 
     /// New transaction stories arrive at our validator,
@@ -281,22 +935,35 @@ impl BlockchainChronicler {
     pub async fn transaction_story_arrives(&mut self, story: TransactionStory) -> Result<(), ChronicleError> {
         println!("📜 New transaction story arrives: {}", story.story_id);
 
-        // The story must prove its authenticity
-        self.story_proves_its_authenticity(&story).await?;
-        
-        // The story must not be a duplicate
-        self.story_proves_its_uniqueness(&story).await?;
+        if let Err(plot_twist) = self.story_earns_its_place_in_mempool(&story).await {
+            self.record_mempool_verdict(&story.story_id, MempoolVerdict::Rejected(format!("{:?}", plot_twist)));
+            self.validator_answers_for_its_story(&story, &plot_twist).await;
+            return Err(plot_twist);
+        }
         
         // Add to mempool
-        {
+        let evicted_stories = {
             let mut mempool = self.mempool_of_pending_tales.lock().unwrap();
             mempool.push(story.clone());
             
-            // Keep mempool size reasonable
-            if mempool.len() > 10000 {
-                mempool.sort_by(|a, b| b.story_fee.cmp(&a.story_fee));
-                mempool.truncate(10000);
+            // Keep mempool size reasonable, evicting the lowest fee rates, so
+            // a large story paying a large fee cannot squeeze out small ones paying more per byte
+            let capacity = self.configuration.max_mempool_stories;
+            if mempool.len() > capacity {
+                mempool.sort_by_key(|pending| std::cmp::Reverse(story_fee_rate(pending)));
+                mempool.split_off(capacity)
+            } else {
+                Vec::new()
             }
+        };
+
+        self.record_mempool_verdict(&story.story_id, MempoolVerdict::Admitted);
+        for evicted in &evicted_stories {
+            self.record_mempool_verdict(&evicted.story_id, MempoolVerdict::Evicted("Mempool capacity exceeded".to_string()));
+        }
+        self.journal_mempool_changes();
+        if !evicted_stories.iter().any(|evicted| evicted.story_id == story.story_id) {
+            self.announce(ChainEvent::TransactionAccepted(story.clone()));
         }
 
         // Broadcast to network
@@ -306,98 +973,823 @@ impl BlockchainChronicler {
         Ok(())
     }
 
-    /// ## Act II: Stories Undergo Rigorous Validation
+    /// ## Listening to the Chronicle
+    ///
+    /// A receiver of every chapter added, story accepted and reorganization
+    /// from now on. Each event is sent only once the change it tells of is
+    /// stored; a subscriber falling too far behind misses the oldest events.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ChainEvent> {
+        self.chain_events.subscribe()
+    }
+
+    /// Tells every subscriber; having none is no failure
+    fn announce(&self, event: ChainEvent) {
+        let _ = self.chain_events.send(event);
+    }
+
+    /// ## Our Own Stories Are Not Forgotten
     /// 
-    /// Each story must prove its worth through multiple trials
-    /// before joining the permanent chronicle of the blockchain.
-    async fn story_proves_its_authenticity(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
-        // Verify digital signature
-        if !self.signature_tells_the_truth(story).await? {
-            return Err(ChronicleError::StoryBearsFalseWitness(
-                "Digital signature verification failed".to_string()
-            ));
-        }
+    /// Admits a story told by this node and keeps watch over it: until it
+    /// confirms or expires, the rebroadcast task re-announces it to peers
+    /// whose mempools may have dropped it.
+    pub async fn submit_own_story(&mut self, story: TransactionStory) -> Result<(), ChronicleError> {
+        self.transaction_story_arrives(story.clone()).await?;
+        self.watch_for_rebroadcast(story);
+        Ok(())
+    }
 
-        // Verify inputs exist and are unspent
-        let total_input_value = self.verify_and_calculate_input_value(story).await?;
-        let total_output_value = story.outputs_created.iter().map(|o| o.value_locked).sum::<u64>();
-        
-        // Verify sufficient funds and reasonable fee
-        if total_input_value < total_output_value + story.story_fee {
-            return Err(ChronicleError::NarratorLacksResources(
-                "Insufficient input value to cover outputs and fees".to_string()
-            ));
+    fn watch_for_rebroadcast(&self, story: TransactionStory) {
+        let now = Instant::now();
+        self.own_unconfirmed_stories.lock().unwrap().insert(story.story_id.clone(), RebroadcastWatch {
+            story,
+            first_announced: now,
+            next_announcement: now + self.configuration.rebroadcast_interval,
+            rebroadcasts: 0,
+        });
+    }
+
+    /// Hands over the messages this node sends its peers, whether announced
+    /// to all of them or told to one, so they can be carried by whatever
+    /// transport links it to the network.
+    pub fn take_outgoing_messages(&self) -> mpsc::UnboundedReceiver<NetworkMessage> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.network_storytellers.message_broadcaster.lock().unwrap() = tx;
+        rx
+    }
+
+    /// Carries this node's announcements over `transport` from now on,
+    /// in place of the TCP streams it was gathered with.
+    pub fn carry_messages_over(&self, transport: Arc<dyn Transport>) {
+        *self.network_storytellers.transport.write().unwrap() = transport;
+    }
+
+    /// Hears a message from the peer at `peer_address`. A chapter we have
+    /// not seen is verified, joined to the chain and passed on; a story is
+    /// admitted to the mempool, which passes it on in turn. Either one
+    /// refused is answered with a `Reject`. Heartbeats and sync messages
+    /// drive the chain sync described below; what a peer asks of us is
+    /// answered to that peer alone.
+    pub async fn network_message_arrives(&mut self, peer_address: &str, message: NetworkMessage) -> Result<(), ChronicleError> {
+        match message {
+            NetworkMessage::NewBlockChapter(block) => {
+                if self.chain_repository.knows_chapter(&block.chapter_essence) {
+                    return Ok(());
+                }
+                let chapter_essence = block.chapter_essence.clone();
+                self.receive_block_chapter(block.clone()).await
+                    .map_err(|plot_twist| self.refuse_before_peer(peer_address, chapter_essence, plot_twist))?;
+                self.network_storytellers.announce_block_chapter(block).await
+            }
+            NetworkMessage::NewTransactionStory(story) => {
+                let story_id = story.story_id.clone();
+                self.transaction_story_arrives(story).await
+                    .map_err(|plot_twist| self.refuse_before_peer(peer_address, story_id, plot_twist))
+            }
+            NetworkMessage::Heartbeat(peer_height) => self.peer_height_heard(peer_address, peer_height).await,
+            NetworkMessage::Handshake { chain_height, tip_essence, clock_time } => {
+                self.peer_reports_tip(peer_address, chain_height, &tip_essence);
+                self.peer_reports_clock(peer_address, clock_time);
+                self.peer_height_heard(peer_address, chain_height).await
+            }
+            NetworkMessage::RequestChainSync(from_height) => self.answer_chain_sync(peer_address, from_height),
+            NetworkMessage::ChainSyncResponse(chapters) => self.chain_sync_batch_arrives(chapters).await,
+            // A rejection is only ever heard, never answered, so two nodes cannot argue forever
+            NetworkMessage::Reject { target_id, reason_code, description } => {
+                println!("🚫 A peer refused {} ({:?}): {}", target_id, reason_code, description);
+                Ok(())
+            }
+            _ => Ok(()),
         }
+    }
 
-        // Verify fee is reasonable
-        if story.story_fee < self.configuration.min_transaction_fee {
-            return Err(ChronicleError::InsufficientFee(story.story_fee));
+    /// Tells the peer at `peer_address` why what it sent was refused, if it
+    /// was its fault, and hands the plot twist back to be returned as usual.
+    /// The other peers sent nothing wrong and hear nothing.
+    fn refuse_before_peer(&self, peer_address: &str, target_id: String, plot_twist: ChronicleError) -> ChronicleError {
+        if let Some(reason_code) = plot_twist.reject_code() {
+            let rejection = NetworkMessage::Reject { target_id, reason_code, description: format!("{:?}", plot_twist) };
+            if let Err(network_failure) = self.network_storytellers.tell_peer(peer_address, rejection) {
+                eprintln!("Could not send a rejection: {:?}", network_failure);
+            }
         }
+        plot_twist
+    }
 
-        // Verify transaction nonce to prevent replay attacks
-        self.verify_transaction_nonce(story).await?;
+    /// Opens a TCP connection to a peer listening at `address`; from then
+    /// on it receives our announcements and we hear its own. Our handshake
+    /// goes out first, so whichever side is behind knows to sync.
+    pub async fn connect_to_peer(&self, address: &str) -> Result<(), ChronicleError> {
+        let stream = TcpStream::connect(address)
+            .map_err(|e| ChronicleError::NetworkError(format!("{}: {}", address, e)))?;
+        let peer_address = self.network_storytellers.welcome_peer(stream)?;
+        let handshake = self.handshake().await?;
+        self.network_storytellers.tell_peer(&peer_address, handshake)
+    }
 
-        println!("✅ Story passes all authenticity trials");
-        Ok(())
+    /// The handshake naming our chain's height and tip
+    async fn handshake(&self) -> Result<NetworkMessage, ChronicleError> {
+        let tip = self.chain_repository.get_chain_tip().await?;
+        Ok(NetworkMessage::Handshake {
+            chain_height: tip.as_ref().map_or(0, |tip| tip.chapter_number),
+            tip_essence: tip.map(|tip| tip.chapter_essence).unwrap_or_default(),
+            clock_time: current_timestamp(),
+        })
     }
 
-    async fn story_proves_its_uniqueness(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
-        // Check if transaction already exists in chain
-        if self.chain_repository.transaction_exists(&story.story_id).await? {
-            return Err(ChronicleError::DuplicateStory(story.story_id.clone()));
+    /// ## Catching Up With a Taller Chain
+    /// 
+    /// A peer that is ahead of us starts a sync towards its height; one that
+    /// is behind hears our height in return, so it can start its own. Peers
+    /// level with us need nothing.
+    async fn peer_height_heard(&self, peer_address: &str, peer_height: u64) -> Result<(), ChronicleError> {
+        let our_height = self.chain_height().await?;
+        if peer_height > our_height {
+            self.record_sync_progress(our_height, peer_height)?;
+            self.network_storytellers.begin_chain_synchronization(our_height + 1).await
+        } else if peer_height < our_height {
+            self.network_storytellers.tell_peer(peer_address, NetworkMessage::Heartbeat(our_height))
+        } else {
+            Ok(())
         }
+    }
 
-        // Check if already in mempool
-        let mempool = self.mempool_of_pending_tales.lock().unwrap();
-        if mempool.iter().any(|t| t.story_id == story.story_id) {
-            return Err(ChronicleError::DuplicateStory(story.story_id.clone()));
+    /// Sends the peer at `peer_address` the next batch of at most
+    /// `SYNC_BATCH_CHAPTERS` chapters from `from_height` on. A request beyond
+    /// our tip is answered with an empty batch, which tells the requester
+    /// there is nothing more to be had.
+    fn answer_chain_sync(&self, peer_address: &str, from_height: u64) -> Result<(), ChronicleError> {
+        let chapters = self.chain_repository.chapters_from(from_height, SYNC_BATCH_CHAPTERS)?;
+        println!("📚 Answering sync from chapter {} with {} chapters", from_height, chapters.len());
+        self.chain_repository.note_sync_served(peer_address, from_height, chapters.len() as u64);
+        self.network_storytellers.tell_peer(peer_address, NetworkMessage::ChainSyncResponse(chapters))
+    }
+
+    /// Joins each chapter of a batch to the chain, recording progress as it
+    /// goes, and asks for the next batch while the target is out of reach.
+    /// An empty batch, or one taking us to the target, ends the sync. Once a
+    /// chapter stops following our tip, the rest of the batch is a rival
+    /// branch and is weighed against our chain instead.
+    async fn chain_sync_batch_arrives(&mut self, chapters: Vec<BlockChapter>) -> Result<(), ChronicleError> {
+        let target_height = self.network_storytellers.sync_status.read().unwrap().target_height;
+        let batch_was_empty = chapters.is_empty();
+        let mut chapters = chapters.into_iter();
+        while let Some(chapter) = chapters.next() {
+            if !self.chain_repository.knows_chapter(&chapter.chapter_essence) {
+                let follows_our_tip = self.chain_repository.get_chain_tip().await?
+                    .is_some_and(|tip| tip.chapter_essence == chapter.previous_chapter_essence);
+                if !follows_our_tip {
+                    let branch = std::iter::once(chapter).chain(chapters).collect();
+                    return self.rival_branch_arrives(branch, target_height).await;
+                }
+                self.receive_block_chapter(chapter).await?;
+            }
+            let our_height = self.chain_height().await?;
+            self.record_sync_progress(our_height, target_height.max(our_height))?;
         }
+        self.pending_sync_branch.clear();
 
-        Ok(())
+        let our_height = self.chain_height().await?;
+        if batch_was_empty || our_height >= target_height {
+            self.record_sync_progress(our_height, our_height)?;
+            println!("✅ Chain synchronized at chapter {}", our_height);
+            return Ok(());
+        }
+        self.network_storytellers.begin_chain_synchronization(our_height + 1).await
     }
 
-    async fn signature_tells_the_truth(&self, story: &TransactionStory) -> Result<bool, ChronicleError> {
-        let public_key = PublicKey::from_bytes(&story.public_key_of_narrator)
-            .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
-        
-        let signature = Signature::from_bytes(&story.digital_signature)
-            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+    /// ## When the Batch Tells Another Tale
+    /// 
+    /// Gathers a rival branch across sync batches until it outweighs our
+    /// chain, then reorganizes onto it. A branch whose first parent we do
+    /// not hold forks further back, so the sync steps back a batch to find
+    /// where the two chains part. A branch still too light to win asks for
+    /// its next batch while the target lies ahead.
+    async fn rival_branch_arrives(&mut self, branch: Vec<BlockChapter>, target_height: u64) -> Result<(), ChronicleError> {
+        let first = &branch[0];
+        let continues_pending = self.pending_sync_branch.last()
+            .is_some_and(|last| last.chapter_essence == first.previous_chapter_essence);
+        if !continues_pending {
+            self.pending_sync_branch.clear();
+            let joins_our_chain = self.chain_repository.chapter_number_of(&first.previous_chapter_essence)?
+                .is_some_and(|height| height + 1 == first.chapter_number);
+            if !joins_our_chain {
+                if first.chapter_number <= 1 {
+                    return Err(ChronicleError::ReorganizationRejected(
+                        "The branch begins from another genesis chapter".to_string(),
+                    ));
+                }
+                let from_height = first.chapter_number.saturating_sub(SYNC_BATCH_CHAPTERS).max(1);
+                println!("🔍 Chapter {} forks from a chapter we never told; stepping back to {}",
+                    first.chapter_number, from_height);
+                return self.network_storytellers.begin_chain_synchronization(from_height).await;
+            }
+        }
+        self.pending_sync_branch.extend(branch);
 
-        // Create message to verify (transaction without signature)
-        let message = self.create_signable_message(story);
-        
-        Ok(public_key.verify(&message, &signature).is_ok())
+        let branch_height = self.pending_sync_branch.last().map_or(0, |chapter| chapter.chapter_number);
+        match self.reorganize_to(self.pending_sync_branch.clone()).await {
+            Ok(_) => self.pending_sync_branch.clear(),
+            Err(ChronicleError::ReorganizationRejected(_)) if branch_height < target_height => {
+                return self.network_storytellers.begin_chain_synchronization(branch_height + 1).await;
+            }
+            Err(plot_twist) => {
+                self.pending_sync_branch.clear();
+                return Err(plot_twist);
+            }
+        }
+
+        let our_height = self.chain_height().await?;
+        self.record_sync_progress(our_height, target_height.max(our_height))?;
+        if our_height >= target_height {
+            println!("✅ Chain synchronized at chapter {}", our_height);
+            return Ok(());
+        }
+        self.network_storytellers.begin_chain_synchronization(our_height + 1).await
     }
 
-    async fn verify_and_calculate_input_value(&self, story: &TransactionStory) -> Result<u64, ChronicleError> {
+    /// The number of the chapter at our tip; genesis is zero
+    async fn chain_height(&self) -> Result<u64, ChronicleError> {
+        Ok(self.chain_repository.get_chain_tip().await?
+            .map(|tip| tip.chapter_number)
+            .unwrap_or(0))
+    }
+
+    /// ## Listening for as Long as the Node Runs
+    /// 
+    /// Heeds the peers' messages as they arrive, resting a moment whenever
+    /// none are waiting. A node started with `new_chronicle_begins` runs
+    /// this once it is ready; it returns only when the task is dropped.
+    pub async fn keep_heeding_peers(&mut self) {
+        loop {
+            if self.heed_incoming_messages().await == 0 {
+                tokio::time::sleep(PEER_HEEDING_INTERVAL).await;
+            }
+        }
+    }
+
+    /// Hears every message peers have sent since last asked, returning how
+    /// many were heard. A message that fails its trials is dropped; one
+    /// peer's bad story must not silence the others.
+    pub async fn heed_incoming_messages(&mut self) -> usize {
+        let mut heard = 0;
+        loop {
+            let next = self.network_storytellers.incoming_messages.lock().unwrap().try_recv();
+            let Ok((peer_address, message)) = next else {
+                return heard;
+            };
+            heard += 1;
+            if let Err(plot_twist) = self.network_message_arrives(&peer_address, message).await {
+                eprintln!("Peer message refused: {:?}", plot_twist);
+            }
+        }
+    }
+
+    /// Re-announces our own unconfirmed stories as they fall due, waiting
+    /// longer after each announcement, and stops watching a story once it is
+    /// in the chain, has left our mempool, or has outlived its expiry.
+    fn begin_rebroadcasting_own_stories(&self) -> tokio::task::JoinHandle<()> {
+        let watched = self.own_unconfirmed_stories.clone();
+        let mempool = self.mempool_of_pending_tales.clone();
+        let chain_repo = self.chain_repository.clone();
+        let network = self.network_storytellers.clone();
+        let config = self.configuration.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let next_wake = {
+                    let watched = watched.lock().unwrap();
+                    watched.values()
+                        .map(|watch| watch.next_announcement)
+                        .min()
+                        .unwrap_or_else(|| Instant::now() + config.rebroadcast_interval)
+                };
+                tokio::time::sleep_until(tokio::time::Instant::from_std(next_wake)).await;
+
+                let candidates: Vec<RebroadcastWatch> = watched.lock().unwrap().values().cloned().collect();
+                let now = Instant::now();
+                for watch in candidates {
+                    let story_id = &watch.story.story_id;
+                    let confirmed = chain_repo.transaction_exists(story_id).await.unwrap_or(false);
+                    let still_pending = mempool.lock().unwrap().iter().any(|pending| &pending.story_id == story_id);
+                    let expired = now.duration_since(watch.first_announced) >= config.rebroadcast_expiry;
+
+                    if confirmed || !still_pending || expired {
+                        watched.lock().unwrap().remove(story_id);
+                        continue;
+                    }
+                    if watch.next_announcement > now {
+                        continue;
+                    }
+
+                    if let Err(e) = network.broadcast_transaction_story(watch.story.clone()).await {
+                        eprintln!("Rebroadcast error: {:?}", e);
+                    }
+                    if let Some(entry) = watched.lock().unwrap().get_mut(story_id) {
+                        entry.rebroadcasts += 1;
+                        entry.next_announcement = now + Self::rebroadcast_delay(&config, entry.rebroadcasts);
+                    }
+                }
+            }
+        })
+    }
+
+    /// The wait before the next announcement grows by the backoff factor
+    /// with every rebroadcast, up to the configured ceiling
+    fn rebroadcast_delay(config: &ChronicleConfiguration, rebroadcasts: u32) -> Duration {
+        let factor = config.rebroadcast_backoff.max(1.0).powi(rebroadcasts as i32);
+        config.rebroadcast_interval
+            .mul_f64(factor)
+            .min(config.max_rebroadcast_interval)
+    }
+
+    /// Runs every admission trial and, when the story spends inputs already
+    /// claimed by pending tales, replaces them if it pays a strictly higher fee.
+    async fn story_earns_its_place_in_mempool(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
+        // A coinbase travels only inside the chapter that mints it
+        if story.is_coinbase() {
+            return Err(ChronicleError::StoryBearsFalseWitness(format!(
+                "Coinbase {} cannot enter the mempool", story.story_id
+            )));
+        }
+
+        // The story must prove its authenticity
+        self.story_proves_its_authenticity(story).await?;
+        
+        // The story must not be a duplicate
+        self.story_proves_its_uniqueness(story).await?;
+
+        // The story must satisfy every policy the operator has registered
+        for hook in &self.mempool_policy_hooks {
+            hook.evaluate(story).map_err(|reason| ChronicleError::PolicyRejected {
+                policy: hook.name().to_string(),
+                reason,
+            })?;
+        }
+
+        // Conflicting pending tales are replaced only by a better-paying story
+        let replaced_stories = {
+            let mut mempool = self.mempool_of_pending_tales.lock().unwrap();
+            let conflicting_fees: Vec<u64> = mempool.iter()
+                .filter(|pending| stories_share_inputs(pending, story))
+                .map(|pending| pending.story_fee)
+                .collect();
+            let conflicting_total: u64 = conflicting_fees.iter().sum();
+
+            if !conflicting_fees.is_empty() && story.story_fee <= conflicting_total {
+                return Err(ChronicleError::ReplacementRejected(format!(
+                    "Replacement fee {} does not exceed conflicting fees {}",
+                    story.story_fee, conflicting_total
+                )));
+            }
+
+            let (replaced, kept): (Vec<_>, Vec<_>) = mempool.drain(..)
+                .partition(|pending| stories_share_inputs(pending, story));
+            *mempool = kept;
+            replaced
+        };
+
+        for replaced in &replaced_stories {
+            self.record_mempool_verdict(&replaced.story_id, MempoolVerdict::Replaced {
+                replaced_by: story.story_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// A refused story told by a council member is held against it: a false
+    /// signature as `InvalidSignature`, and spending an output the chain has
+    /// already seen spent as `DoubleSpending`. Other refusals cost nothing.
+    async fn validator_answers_for_its_story(&mut self, story: &TransactionStory, plot_twist: &ChronicleError) {
+        let Some(validator_id) = self.validator_council.validator_with_key(&story.public_key_of_narrator) else {
+            return;
+        };
+
+        let offence = if !matches!(self.signature_tells_the_truth(story).await, Ok(true)) {
+            PenaltyType::InvalidSignature
+        } else {
+            match plot_twist {
+                ChronicleError::UTXONotFound(utxo_key)
+                    if matches!(self.utxo_ledger.get_spending_tx(utxo_key), Ok(Some(_))) => PenaltyType::DoubleSpending,
+                _ => return,
+            }
+        };
+        if let Some(outcome) = self.validator_council.penalize_validator(&validator_id, offence) {
+            println!("⚖️  Validator {} penalized, reputation now {:.2}{}", validator_id, outcome.reputation_score,
+                     if outcome.slash_due { ", stake slashed" } else { "" });
+        }
+    }
+
+    /// Gates features by story version: versions start at 1, versions newer
+    /// than this node understands are refused under strict policy, and
+    /// script-hash outputs need at least `SCRIPT_HASH_MIN_VERSION`.
+    fn story_version_permits_its_features(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
+        if story.version == 0 {
+            return Err(ChronicleError::UnsupportedStoryVersion(
+                "Story versions begin at 1".to_string()
+            ));
+        }
+        if story.version > CURRENT_STORY_VERSION && self.configuration.strict_story_versions {
+            return Err(ChronicleError::UnsupportedStoryVersion(format!(
+                "Version {} is newer than the known version {}", story.version, CURRENT_STORY_VERSION
+            )));
+        }
+
+        let sponsor_change = story.fee_sponsorship.iter().flat_map(|sponsorship| sponsorship.sponsor_change.iter());
+        let uses_script_hash = story.outputs_created.iter().chain(sponsor_change)
+            .any(|output| output.locking_script.script_type == ScriptType::PayToScriptHash);
+        if uses_script_hash && story.version < SCRIPT_HASH_MIN_VERSION {
+            return Err(ChronicleError::UnsupportedStoryVersion(format!(
+                "Script-hash outputs require version {}, story is version {}",
+                SCRIPT_HASH_MIN_VERSION, story.version
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// ## The Operator Vouches for a Story
+    /// 
+    /// A node-local hint: boosted stories are packed ahead of all others
+    /// regardless of fee, highest boost first. A boost of zero clears it.
+    pub fn set_priority_boost(&self, story_id: &str, priority_boost: u64) {
+        let mut boosts = self.priority_boosts.lock().unwrap();
+        if priority_boost == 0 {
+            boosts.remove(story_id);
+        } else {
+            boosts.insert(story_id.to_string(), priority_boost);
+        }
+    }
+
+    /// ## The Operator's Own Rules at the Gate
+    /// 
+    /// Registers a custom admission policy, consulted in registration order
+    /// after the built-in authenticity and uniqueness trials.
+    pub fn register_mempool_policy(&mut self, hook: Box<dyn MempoolPolicyHook>) {
+        self.mempool_policy_hooks.push(hook);
+    }
+
+    fn record_mempool_verdict(&self, story_id: &str, verdict: MempoolVerdict) {
+        let mut audit_trail = self.mempool_audit_trail.lock().unwrap();
+        audit_trail.push_back(MempoolAuditEntry {
+            story_id: story_id.to_string(),
+            recorded_at: current_timestamp(),
+            verdict,
+        });
+
+        while audit_trail.len() > self.configuration.max_mempool_audit_entries {
+            audit_trail.pop_front();
+        }
+    }
+
+    /// ## Act II: Stories Undergo Rigorous Validation
+    /// 
+    /// Each story must prove its worth through multiple trials
+    /// before joining the permanent chronicle of the blockchain.
+    async fn story_proves_its_authenticity(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
+        if let Some(first_failure) = self.validate_verbose(story).await.failures.into_iter().next() {
+            return Err(first_failure);
+        }
+
+        println!("✅ Story passes all authenticity trials");
+        Ok(())
+    }
+
+    /// ## Every Trial, Every Verdict
+    /// 
+    /// Puts the story through all authenticity trials without stopping
+    /// at the first failure, so a narrator can fix everything at once.
+    pub async fn validate_verbose(&self, story: &TransactionStory) -> ValidationResult {
+        self.validate_story(story, true).await
+    }
+
+    /// `validate_verbose`, leaving out the narrator's signature when the
+    /// caller has already verified it, as a chapter's batch does
+    async fn validate_story(&self, story: &TransactionStory, check_narrator_signature: bool) -> ValidationResult {
+        let mut failures = Vec::new();
+
+        // A coinbase has no inputs to check and no narrator to sign for them;
+        // what it may pay is judged against its chapter
+        if story.is_coinbase() {
+            if !story.inputs_consumed.is_empty() || story.fee_sponsorship.is_some() {
+                failures.push(ChronicleError::StoryBearsFalseWitness(format!(
+                    "Coinbase {} may neither consume outputs nor be sponsored", story.story_id
+                )));
+            }
+            return ValidationResult { failures };
+        }
+
+        // Verify digital signature
+        if check_narrator_signature {
+            match self.signature_tells_the_truth(story).await {
+                Ok(true) => {}
+                Ok(false) => failures.push(ChronicleError::StoryBearsFalseWitness(
+                    "Digital signature verification failed".to_string()
+                )),
+                Err(plot_twist) => failures.push(plot_twist),
+            }
+        }
+
+        // Verify inputs exist and are unspent, and cover outputs plus fee,
+        // unless a sponsor has taken the fee upon itself
+        let fee_owed_by_narrator = if story.fee_sponsorship.is_some() { 0 } else { story.story_fee };
+        let narrator_interpreter = ScriptInterpreter::new(
+            self.create_signable_message(story),
+            vec![story.digital_signature.clone()],
+        );
+        match self.verify_and_calculate_input_value(&story.inputs_consumed, &story.input_witnesses, &narrator_interpreter).await {
+            Ok((total_input_value, script_failure)) => {
+                failures.extend(script_failure);
+                let total_output_value = story.outputs_created.iter().map(|o| o.value_locked).sum::<u64>();
+                if total_input_value < total_output_value + fee_owed_by_narrator {
+                    failures.push(ChronicleError::NarratorLacksResources(
+                        "Insufficient input value to cover outputs and fees".to_string()
+                    ));
+                }
+            }
+            Err(plot_twist) => failures.push(plot_twist),
+        }
+
+        if let Some(sponsorship) = &story.fee_sponsorship {
+            if let Err(plot_twist) = self.sponsorship_covers_the_fee(story, sponsorship).await {
+                failures.push(plot_twist);
+            }
+        }
+
+        // Verify fee is reasonable, both in total and for the story's size
+        if story.story_fee < self.configuration.min_transaction_fee {
+            failures.push(ChronicleError::InsufficientFee(story.story_fee));
+        } else if story_fee_rate(story) < self.configuration.min_fee_rate {
+            failures.push(ChronicleError::FeeRateTooLow(story_fee_rate(story)));
+        }
+
+        // Verify transaction nonce to prevent replay attacks
+        if let Err(plot_twist) = self.verify_transaction_nonce(story).await {
+            failures.push(plot_twist);
+        }
+
+        // The story's version must be known and permit every feature it uses
+        if let Err(plot_twist) = self.story_version_permits_its_features(story) {
+            failures.push(plot_twist);
+        }
+
+        // A story cannot claim to be told too far in the future
+        let latest_plausible = self.network_adjusted_time().saturating_add(self.configuration.max_future_drift_secs);
+        if story.timestamp_of_telling > latest_plausible {
+            failures.push(ChronicleError::InvalidTimestamp(format!(
+                "Story {} is dated {}, beyond the allowed drift limit {}",
+                story.story_id, story.timestamp_of_telling, latest_plausible
+            )));
+        }
+
+        ValidationResult { failures }
+    }
+
+    async fn story_proves_its_uniqueness(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
+        // Check if transaction already exists in chain
+        if self.chain_repository.transaction_exists(&story.story_id).await? {
+            return Err(ChronicleError::DuplicateStory(story.story_id.clone()));
+        }
+
+        // Check if already in mempool
+        let mempool = self.mempool_of_pending_tales.lock().unwrap();
+        if mempool.iter().any(|t| t.story_id == story.story_id) {
+            return Err(ChronicleError::DuplicateStory(story.story_id.clone()));
+        }
+
+        Ok(())
+    }
+
+    async fn signature_tells_the_truth(&self, story: &TransactionStory) -> Result<bool, ChronicleError> {
+        let public_key = PublicKey::from_bytes(&story.public_key_of_narrator)
+            .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
+        
+        let signature = Signature::from_bytes(&story.digital_signature)
+            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+
+        // Create message to verify (transaction without signature)
+        let message = self.create_signable_message(story);
+        
+        Ok(public_key.verify(&message, &signature).is_ok())
+    }
+
+    /// ## Every Narrator Vouches at Once
+    /// 
+    /// Verifies the narrator signature of every story in the chapter as one
+    /// ed25519 batch, far cheaper than one signature at a time. Only when
+    /// the batch fails are the signatures checked singly, to name the story
+    /// at fault; a story the single check accepts is not held against the
+    /// chapter.
+    pub fn verify_block_signatures(&self, block: &BlockChapter) -> Result<(), ChronicleError> {
+        let mut narrators = Vec::new();
+        for story in block.transaction_tales.iter().filter(|story| !story.is_coinbase()) {
+            let public_key = PublicKey::from_bytes(&story.public_key_of_narrator)
+                .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
+            let signature = Signature::from_bytes(&story.digital_signature)
+                .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+            narrators.push((story, self.create_signable_message(story), signature, public_key));
+        }
+        if narrators.is_empty() {
+            return Ok(());
+        }
+
+        let messages: Vec<&[u8]> = narrators.iter().map(|(_, message, _, _)| message.as_slice()).collect();
+        let signatures: Vec<Signature> = narrators.iter().map(|(_, _, signature, _)| *signature).collect();
+        let public_keys: Vec<PublicKey> = narrators.iter().map(|(_, _, _, public_key)| *public_key).collect();
+        if ed25519_dalek::verify_batch(&messages, &signatures, &public_keys).is_ok() {
+            return Ok(());
+        }
+
+        match narrators.iter().find(|(_, message, signature, public_key)| public_key.verify(message, signature).is_err()) {
+            Some((story, ..)) => Err(ChronicleError::StoryBearsFalseWitness(format!(
+                "Digital signature verification failed for story {} in chapter {}",
+                story.story_id, block.chapter_number
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// The sponsor must truly have signed, spend only its own unspent
+    /// outputs, and bring enough to pay the fee plus its change
+    async fn sponsorship_covers_the_fee(
+        &self,
+        story: &TransactionStory,
+        sponsorship: &FeeSponsorship,
+    ) -> Result<(), ChronicleError> {
+        let sponsor_key = PublicKey::from_bytes(&sponsorship.sponsor_public_key)
+            .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
+        let sponsor_signature = Signature::from_bytes(&sponsorship.sponsor_signature)
+            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+        sponsor_key.verify(&self.create_sponsor_message(story, sponsorship), &sponsor_signature)
+            .map_err(|_| ChronicleError::StoryBearsFalseWitness(
+                "Sponsor signature verification failed".to_string()
+            ))?;
+
+        if sponsorship.sponsor_inputs.iter().any(|input| story.inputs_consumed.contains(input)) {
+            return Err(ChronicleError::StoryBearsFalseWitness(
+                "Sponsor inputs overlap the narrator's inputs".to_string()
+            ));
+        }
+
+        let sponsor_interpreter = ScriptInterpreter::new(
+            self.create_sponsor_message(story, sponsorship),
+            vec![sponsorship.sponsor_signature.clone()],
+        );
+        let (sponsor_value, script_failure) = self.verify_and_calculate_input_value(
+            &sponsorship.sponsor_inputs,
+            &[],
+            &sponsor_interpreter,
+        ).await?;
+        if let Some(plot_twist) = script_failure {
+            return Err(plot_twist);
+        }
+        let sponsor_change = sponsorship.sponsor_change.as_ref().map(|change| change.value_locked).unwrap_or(0);
+        let sponsor_owes = story.story_fee.checked_add(sponsor_change).ok_or(ChronicleError::ValueOverflow)?;
+        if sponsor_value < sponsor_owes {
+            return Err(ChronicleError::InsufficientFunds {
+                required: sponsor_owes,
+                available: sponsor_value,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Sums what the inputs hold, setting apart the first locking script
+    /// the spender fails to satisfy so the sum is still told
+    async fn verify_and_calculate_input_value(
+        &self,
+        inputs: &[UTXOReference],
+        witnesses: &[InputWitness],
+        interpreter: &ScriptInterpreter,
+    ) -> Result<(u64, Option<ChronicleError>), ChronicleError> {
         let mut total_value = 0u64;
+        let mut script_failure = None;
         
-        for input in &story.inputs_consumed {
+        for (input_index, input) in inputs.iter().enumerate() {
             let utxo_key = format!("{}:{}", input.previous_story_id, input.output_index);
             
             // Check if UTXO exists and is unspent
             let utxo = self.utxo_ledger.find_unspent_output(&utxo_key).await?
                 .ok_or_else(|| ChronicleError::UTXONotFound(utxo_key.clone()))?;
             
-            // Verify spending authorization (simplified - in reality would check scripts)
-            self.verify_spending_authorization(&utxo, &story.public_key_of_narrator)?;
+            // The spender must satisfy the output's locking script
+            if let Err(plot_twist) = interpreter.unlocks(&utxo.locking_script, witnesses.get(input_index)) {
+                script_failure.get_or_insert(plot_twist);
+            }
             
             total_value = total_value.checked_add(utxo.value_locked)
                 .ok_or(ChronicleError::ValueOverflow)?;
         }
         
-        Ok(total_value)
+        Ok((total_value, script_failure))
     }
 
+    /// A story's nonce must exceed every nonce its narrator has had committed,
+    /// so a story already told cannot be replayed
     async fn verify_transaction_nonce(&self, story: &TransactionStory) -> Result<(), ChronicleError> {
-        // In a real implementation, you'd track nonces per address
-        // This is a simplified check
-        if story.transaction_nonce == 0 {
+        let highest_committed = self.chain_repository.highest_committed_nonce(&story.public_key_of_narrator)?;
+        if story.transaction_nonce <= highest_committed {
             return Err(ChronicleError::InvalidNonce(story.transaction_nonce));
         }
         Ok(())
     }
 
+    /// The nonce a narrator's next story should carry: one past both its
+    /// committed stories and those still waiting in the mempool
+    fn next_nonce_for(&self, narrator: &[u8]) -> Result<u64, ChronicleError> {
+        let highest_committed = self.chain_repository.highest_committed_nonce(narrator)?;
+        let highest_pending = self.mempool_of_pending_tales.lock().unwrap().iter()
+            .filter(|pending| pending.public_key_of_narrator == narrator)
+            .map(|pending| pending.transaction_nonce)
+            .max()
+            .unwrap_or(0);
+        highest_committed.max(highest_pending).checked_add(1).ok_or(ChronicleError::ValueOverflow)
+    }
+
+    /// ## A Chapter Arrives From a Peer
+    /// 
+    /// Trusts nothing the peer claims: the chapter must follow our tip,
+    /// carry the target we would have set, hash to its stated essence
+    /// within that target, weave to its stated merkle root, and tell only
+    /// stories that pass every trial a mempool story must. Only then is it
+    /// applied to the ledger and written to the chain.
+    pub async fn receive_block_chapter(&mut self, block: BlockChapter) -> Result<(), ChronicleError> {
+        println!("📦 Chapter {} arrives from a peer: {}", block.chapter_number, block.chapter_essence);
+
+        self.chapter_earns_its_place(&block).await?;
+
+        // The ledger refuses stories that spend the same output twice
+        Self::commit_chapter(&self.utxo_ledger, &self.chain_repository, &block).await?;
+        Self::prune_to_node_mode(&self.chain_repository, &self.configuration, block.chapter_number)?;
+
+        let confirmed: HashSet<&str> = block.transaction_tales.iter().map(|story| story.story_id.as_str()).collect();
+        self.mempool_of_pending_tales.lock().unwrap()
+            .retain(|pending| !confirmed.contains(pending.story_id.as_str()));
+        self.journal_mempool_changes();
+
+        println!("✅ Chapter {} joins the chronicle", block.chapter_number);
+        self.announce(ChainEvent::BlockAdded(block));
+        Ok(())
+    }
+
+    /// The trials every chapter must pass before it joins our chain, whether
+    /// it arrives alone from a peer or as part of a competing branch: it
+    /// must follow our tip, carry honest proof of work at the target the
+    /// chain requires, keep to its dates, pay its miner no more than earned,
+    /// and tell only stories that validate against the ledger as it stands.
+    async fn chapter_earns_its_place(&self, block: &BlockChapter) -> Result<(), ChronicleError> {
+        let tip = self.chain_repository.get_chain_tip().await?
+            .ok_or_else(|| ChronicleError::ChronicleCorrupted("No chain tip found".to_string()))?;
+        if block.previous_chapter_essence != tip.chapter_essence || block.chapter_number != tip.chapter_number + 1 {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Chapter {} follows {} but our tip is chapter {} ({})",
+                block.chapter_number, block.previous_chapter_essence, tip.chapter_number, tip.chapter_essence
+            )));
+        }
+
+        let expected_bits = Self::calculate_current_difficulty(&tip, &self.chain_repository, &self.configuration)?;
+        let proof = &block.proof_of_storytelling;
+        if proof.difficulty_bits != expected_bits {
+            return Err(ChronicleError::ProofOfWorkFailed(format!(
+                "Chapter {} claims bits {} but the chain requires {}",
+                block.chapter_number, proof.difficulty_bits, expected_bits
+            )));
+        }
+        if Self::calculate_block_hash(block, proof.nonce_of_discovery) != block.chapter_essence {
+            return Err(ChronicleError::ProofOfWorkFailed(format!(
+                "Chapter {} does not hash to its essence", block.chapter_number
+            )));
+        }
+        if !Self::hash_meets_difficulty(&block.chapter_essence, &proof.difficulty_bits.to_target()?) {
+            return Err(ChronicleError::ProofOfWorkFailed(format!(
+                "Chapter {} does not meet its difficulty target", block.chapter_number
+            )));
+        }
+
+        if Self::weave_merkle_tree_of_truth(&block.transaction_tales) != block.merkle_tree_of_truth {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Chapter {} does not weave to its merkle root", block.chapter_number
+            )));
+        }
+        Self::chapter_keeps_its_chronology(block)?;
+        Self::chapter_is_dated_plausibly(block, &self.chain_repository, &self.configuration, self.network_adjusted_time())?;
+        Self::chapter_closes_its_interval_in_time(block, &self.chain_repository, &self.configuration)?;
+        Self::chapter_pays_its_miner_fairly(block, &self.configuration)?;
+
+        self.verify_block_signatures(block)?;
+        for story in &block.transaction_tales {
+            if self.chain_repository.transaction_exists(&story.story_id).await? {
+                return Err(ChronicleError::DuplicateStory(story.story_id.clone()));
+            }
+            if let Some(first_failure) = self.validate_story(story, false).await.failures.into_iter().next() {
+                return Err(first_failure);
+            }
+        }
+        self.chapter_replays_to_its_state_root(block).await?;
+        Ok(())
+    }
+
     /// ## Act III: The Mining Saga
     /// 
     /// Through computational effort, validators earn the right
@@ -419,63 +1811,229 @@ impl BlockchainChronicler {
 
     async fn begin_the_eternal_mining_quest(&mut self) -> Result<(), ChronicleError> {
         let mempool = self.mempool_of_pending_tales.clone();
+        let priority_boosts = self.priority_boosts.clone();
         let chain_repo = self.chain_repository.clone();
+        let utxo_ledger = self.utxo_ledger.clone();
         let config = self.configuration.clone();
+        let chain_events = self.chain_events.clone();
+        let heart = self.mining_heart.as_mut()
+            .ok_or_else(|| ChronicleError::ChronicleCorrupted("The mining heart has not awakened".to_string()))?;
+        let reward_address = heart.mining_reward_address.clone();
+        let is_beating = heart.is_beating.clone();
+        let is_stilled = heart.is_stilled.clone();
         
-        tokio::spawn(async move {
+        heart.mining_quest = Some(tokio::spawn(async move {
             loop {
                 // Wait for transactions to accumulate
                 tokio::time::sleep(Duration::from_secs(1)).await;
                 
+                if *is_stilled.lock().unwrap() {
+                    break;
+                }
+                // A paused heart leaves the mempool untouched until it beats again
+                if !*is_beating.lock().unwrap() {
+                    continue;
+                }
+                
                 let transactions = {
                     let mut pool = mempool.lock().unwrap();
                     if pool.is_empty() {
                         continue;
                     }
                     
-                    // Select transactions for next block
-                    pool.sort_by(|a, b| b.story_fee.cmp(&a.story_fee));
-                    let selected = pool.drain(..std::cmp::min(1000, pool.len())).collect::<Vec<_>>();
+                    let mut boosts = priority_boosts.lock().unwrap();
+                    let selected = Self::select_tales_for_chapter(&mut pool, &boosts, current_timestamp(), config.max_block_size);
+                    for story in &selected {
+                        boosts.remove(&story.story_id);
+                    }
                     selected
                 };
                 
-                if let Err(e) = Self::mine_new_chapter(transactions, &chain_repo, &config).await {
-                    eprintln!("Mining error: {:?}", e);
+                if transactions.is_empty() {
+                    continue;
+                }
+                
+                let included: Vec<String> = transactions.iter().map(|story| story.story_id.clone()).collect();
+                match Self::mine_new_chapter(transactions, &reward_address, &chain_repo, &utxo_ledger, &config).await {
+                    Err(e) => eprintln!("Mining error: {:?}", e),
+                    Ok(chapter) => {
+                        if let Err(e) = chain_repo.record_pending_stories(&[], &included) {
+                            eprintln!("Mempool persistence error: {:?}", e);
+                        }
+                        let _ = chain_events.send(ChainEvent::BlockAdded(chapter));
+                    }
                 }
             }
-        });
+            println!("🛑 The mining quest has ended");
+        }));
         
         Ok(())
     }
 
+    /// Suspends mining at the start of the quest's next round; a chapter
+    /// already being mined is finished first. Does nothing without a heart.
+    pub fn pause_mining(&self) {
+        if let Some(heart) = &self.mining_heart {
+            *heart.is_beating.lock().unwrap() = false;
+            println!("⏸️  Mining paused");
+        }
+    }
+
+    /// Lets a paused mining heart beat again
+    pub fn resume_mining(&self) {
+        if let Some(heart) = &self.mining_heart {
+            *heart.is_beating.lock().unwrap() = true;
+            println!("▶️  Mining resumed");
+        }
+    }
+
+    /// Ends the mining quest for good and waits, at most a round or so, for
+    /// everything the heart spawned to finish. Stopping a stopped heart
+    /// does nothing.
+    pub async fn stop_mining(&mut self) {
+        let Some(heart) = self.mining_heart.as_mut() else {
+            return;
+        };
+        *heart.is_stilled.lock().unwrap() = true;
+        *heart.is_beating.lock().unwrap() = false;
+
+        // The quest sees the flag when it next wakes, so a chapter being
+        // mined is finished rather than cut off halfway through storing it
+        if let Some(quest) = heart.mining_quest.take() {
+            let _ = quest.await;
+        }
+        for handle in heart.thread_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    /// Picks the stories for the next chapter in `mining_order`, leaving
+    /// stories dated ahead of the clock (and any beyond the chapter's
+    /// capacity) in the mempool for a later chapter. A story spending an
+    /// output already claimed by a story picked before it could never be
+    /// valid once the chapter lands, so it is dropped altogether.
+    fn select_tales_for_chapter(
+        pool: &mut Vec<TransactionStory>,
+        priority_boosts: &HashMap<String, u64>,
+        now: u64,
+        max_block_size: usize,
+    ) -> Vec<TransactionStory> {
+        let (mut ready, waiting): (Vec<_>, Vec<_>) = pool.drain(..)
+            .partition(|story| story.timestamp_of_telling <= now);
+        *pool = waiting;
+
+        ready.sort_by(|a, b| Self::mining_order(priority_boosts, a, b));
+
+        let mut claimed: HashSet<UTXOReference> = HashSet::new();
+        ready.retain(|story| {
+            let spends: Vec<&UTXOReference> = story_spends(story).collect();
+            if spends.iter().any(|input| claimed.contains(*input)) {
+                println!("🚫 Story {} spends an output already claimed in this chapter", story.story_id);
+                return false;
+            }
+            claimed.extend(spends.into_iter().cloned());
+            true
+        });
+
+        let selected_count = Self::stories_filling_a_chapter(&ready, max_block_size);
+        pool.extend(ready.split_off(selected_count));
+        ready
+    }
+
+    /// The order the miner packs stories in: operator-boosted stories first,
+    /// then by fee rate, the richer story first where the rates tie
+    fn mining_order(priority_boosts: &HashMap<String, u64>, a: &TransactionStory, b: &TransactionStory) -> std::cmp::Ordering {
+        let boost_of = |story: &TransactionStory| priority_boosts.get(&story.story_id).copied().unwrap_or(0);
+        boost_of(b).cmp(&boost_of(a))
+            .then(story_fee_rate(b).cmp(&story_fee_rate(a)))
+            .then(b.story_fee.cmp(&a.story_fee))
+    }
+
+    /// How many of `stories`, taken in order, fit in one chapter: no more
+    /// than `MAX_TALES_PER_CHAPTER`, and no more than `max_block_size` bytes
+    /// of them. A story too large for any chapter is packed alone rather
+    /// than left waiting forever.
+    fn stories_filling_a_chapter(stories: &[TransactionStory], max_block_size: usize) -> usize {
+        let mut bytes = 0u64;
+        let fitting = stories.iter()
+            .take(MAX_TALES_PER_CHAPTER)
+            .take_while(|story| {
+                bytes = bytes.saturating_add(story_size_bytes(story));
+                bytes <= max_block_size as u64
+            })
+            .count();
+        fitting.max(stories.len().min(1))
+    }
+
     async fn mine_new_chapter(
         transactions: Vec<TransactionStory>,
+        reward_address: &[u8],
         chain_repo: &ChainRepository,
+        utxo_ledger: &UTXOLedger,
         config: &ChronicleConfiguration,
-    ) -> Result<(), ChronicleError> {
+    ) -> Result<BlockChapter, ChronicleError> {
         println!("⛏️  Beginning to mine new chapter with {} transactions", transactions.len());
         
         let previous_block = chain_repo.get_chain_tip().await?
             .ok_or(ChronicleError::ChainCorrupted("No chain tip found".to_string()))?;
+
+        // The miner's pay leads the chapter: the height's reward plus the
+        // fees the fee policy leaves it
+        let height = previous_block.chapter_number + 1;
+        let collected_fees = transactions.iter()
+            .try_fold(0u64, |total, story| total.checked_add(story.story_fee))
+            .ok_or(ChronicleError::ValueOverflow)?;
+        let miner_pay = Self::mining_reward_at_height(height, config)?
+            .checked_add(collected_fees - config.fee_policy.diverted_share(collected_fees))
+            .ok_or(ChronicleError::ValueOverflow)?;
+        let mut coinbase = if config.reward_beneficiaries.is_empty() {
+            Self::coinbase_story(height, reward_address, miner_pay)
+        } else {
+            Self::pooled_coinbase_story(height, &config.reward_beneficiaries, miner_pay)
+        };
+        if let Some((treasury, share)) = config.fee_policy.treasury_share(collected_fees).filter(|(_, share)| *share > 0) {
+            coinbase.outputs_created.push(UTXOOutput {
+                recipient_address: treasury.to_vec(),
+                value_locked: share,
+                locking_script: ScriptOfTruth {
+                    script_type: ScriptType::PayToPublicKey,
+                    required_signatures: 1,
+                    public_keys: vec![treasury.to_vec()],
+                },
+            });
+        }
+        // Once the reward has halved away, a chapter paying no fees mints nothing at all
+        let mut transaction_tales = Vec::new();
+        if !coinbase.outputs_created.is_empty() {
+            transaction_tales.push(coinbase);
+        }
+        transaction_tales.extend(transactions);
         
+        // Even a miner whose clock lags dates its chapter after the median time past
         let mut block = BlockChapter {
-            chapter_number: previous_block.chapter_number + 1,
-            timestamp_of_creation: current_timestamp(),
+            chapter_number: height,
+            timestamp_of_creation: current_timestamp().max(chain_repo.median_time_past()? + 1),
             previous_chapter_essence: previous_block.chapter_essence.clone(),
-            transaction_tales: transactions,
+            transaction_tales,
             merkle_tree_of_truth: String::new(),
             chapter_essence: String::new(),
             proof_of_storytelling: ProofOfWork {
-                difficulty_target: Self::calculate_current_difficulty(&previous_block, config),
+                difficulty_bits: Self::calculate_current_difficulty(&previous_block, chain_repo, config)?,
                 nonce_of_discovery: 0,
-                storyteller_reward: config.base_mining_reward,
+                storyteller_reward: Self::mining_reward_at_height(height, config)?,
                 hash_rate_estimate: 0.0,
             },
             chapter_size_bytes: 0,
+            utxo_state_root: None,
         };
 
+        Self::chapter_keeps_its_chronology(&block)?;
+
         // Calculate merkle root
         block.merkle_tree_of_truth = Self::weave_merkle_tree_of_truth(&block.transaction_tales);
+        if config.commit_state_roots {
+            block.utxo_state_root = Some(utxo_ledger.state_root_after(&block).await?);
+        }
         
         // Mine the block
         let (hash, nonce, hash_rate) = Self::perform_proof_of_work(&block).await?;
@@ -485,15 +2043,190 @@ impl BlockchainChronicler {
         block.proof_of_storytelling.hash_rate_estimate = hash_rate;
         block.chapter_size_bytes = bincode::serialize(&block).unwrap().len();
         
-        // Commit to chain
-        chain_repo.add_block_chapter(block.clone()).await?;
+        // Settle the ledger first: a chapter whose stories cannot all be
+        // applied is never written to the chain
+        Self::commit_chapter(utxo_ledger, chain_repo, &block).await?;
+        Self::prune_to_node_mode(chain_repo, config, block.chapter_number)?;
         
         println!("🎉 New chapter {} mined successfully!", block.chapter_number);
-        Ok(())
+        Ok(block)
     }
 
-    async fn perform_proof_of_work(block: &BlockChapter) -> Result<(String, u64, f64), ChronicleError> {
-        let difficulty_target = block.proof_of_storytelling.difficulty_target;
+    /// The story minting a chapter's reward and fees to its miner
+    fn coinbase_story(height: u64, reward_address: &[u8], value: u64) -> TransactionStory {
+        Self::pooled_coinbase_story(height, &[(reward_address.to_vec(), 1)], value)
+    }
+
+    /// The story minting a chapter's reward and fees to its beneficiaries,
+    /// one output each, named for the first of them. A beneficiary whose
+    /// share rounds to nothing gets no output.
+    fn pooled_coinbase_story(height: u64, beneficiaries: &[(Vec<u8>, u64)], value: u64) -> TransactionStory {
+        let lead_address = beneficiaries.first().map(|(address, _)| address.clone()).unwrap_or_default();
+        let outputs_created = beneficiaries.iter()
+            .zip(split_by_weight(value, beneficiaries))
+            .filter(|(_, share)| *share > 0)
+            .map(|((address, _), share)| UTXOOutput {
+                recipient_address: address.clone(),
+                value_locked: share,
+                locking_script: ScriptOfTruth {
+                    script_type: ScriptType::PayToPublicKey,
+                    required_signatures: 1,
+                    public_keys: vec![address.clone()],
+                },
+            })
+            .collect();
+
+        TransactionStory {
+            version: CURRENT_STORY_VERSION,
+            story_id: format!("coinbase_{}_{}", height, hex::encode(&lead_address)),
+            inputs_consumed: Vec::new(),
+            outputs_created,
+            story_fee: 0,
+            timestamp_of_telling: current_timestamp(),
+            transaction_nonce: 0,
+            digital_signature: Vec::new(),
+            public_key_of_narrator: lead_address,
+            fee_sponsorship: None,
+            input_witnesses: Vec::new(),
+            coinbase_height: Some(height),
+        }
+    }
+
+    /// A chapter may carry one coinbase, as its first story, for its own
+    /// height, paying no more than the height's reward plus the fees of
+    /// the stories it records, less any the fee policy burns, and paying
+    /// the treasury its share when the policy names one. Every output it
+    /// mints must be worth something: an output of zero could never be
+    /// worth spending, yet would sit in every node's UTXO set for good.
+    fn chapter_pays_its_miner_fairly(block: &BlockChapter, config: &ChronicleConfiguration) -> Result<(), ChronicleError> {
+        let coinbase_count = block.transaction_tales.iter().filter(|story| story.is_coinbase()).count();
+        if coinbase_count > 1 {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Chapter {} carries {} coinbase stories", block.chapter_number, coinbase_count
+            )));
+        }
+        let Some(coinbase) = block.transaction_tales.first().filter(|story| story.is_coinbase()) else {
+            if coinbase_count == 1 {
+                return Err(ChronicleError::InvalidChapter(format!(
+                    "Chapter {} hides its coinbase behind other stories", block.chapter_number
+                )));
+            }
+            return Ok(());
+        };
+        if coinbase.coinbase_height != Some(block.chapter_number) {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Coinbase {} pays for another height than chapter {}", coinbase.story_id, block.chapter_number
+            )));
+        }
+        if coinbase.outputs_created.is_empty() || coinbase.outputs_created.iter().any(|output| output.value_locked == 0) {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Coinbase {} mints an output worth nothing", coinbase.story_id
+            )));
+        }
+
+        let collected_fees = block.transaction_tales[1..].iter()
+            .try_fold(0u64, |total, story| total.checked_add(story.story_fee))
+            .ok_or(ChronicleError::ValueOverflow)?;
+        let burned = match config.fee_policy {
+            FeePolicy::BurnBasisPoints(_) => config.fee_policy.diverted_share(collected_fees),
+            _ => 0,
+        };
+        let allowed = Self::mining_reward_at_height(block.chapter_number, config)?
+            .checked_add(collected_fees - burned)
+            .ok_or(ChronicleError::ValueOverflow)?;
+        let claimed = coinbase.outputs_created.iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.value_locked))
+            .ok_or(ChronicleError::ValueOverflow)?;
+        if claimed > allowed {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Coinbase {} claims {} but chapter {} earns only {}",
+                coinbase.story_id, claimed, block.chapter_number, allowed
+            )));
+        }
+        if let Some((treasury, owed)) = config.fee_policy.treasury_share(collected_fees) {
+            let paid = coinbase.outputs_created.iter()
+                .filter(|output| output.recipient_address == treasury)
+                .try_fold(0u64, |total, output| total.checked_add(output.value_locked))
+                .ok_or(ChronicleError::ValueOverflow)?;
+            if paid < owed {
+                return Err(ChronicleError::InvalidChapter(format!(
+                    "Coinbase {} pays the treasury {} of the {} it is owed", coinbase.story_id, paid, owed
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// No tale may be told after the chapter that records it
+    fn chapter_keeps_its_chronology(block: &BlockChapter) -> Result<(), ChronicleError> {
+        if let Some(anachronism) = block.transaction_tales.iter()
+            .find(|story| story.timestamp_of_telling > block.timestamp_of_creation) {
+            return Err(ChronicleError::InvalidTimestamp(format!(
+                "Story {} is dated {}, after its chapter {} at {}",
+                anachronism.story_id, anachronism.timestamp_of_telling,
+                block.chapter_number, block.timestamp_of_creation
+            )));
+        }
+        Ok(())
+    }
+
+    /// A chapter must be dated after the median time past of the chapters
+    /// before it, and no further ahead of the network-adjusted time `now`
+    /// than the drift allowed for stories; a far-future date would otherwise
+    /// pull the retarget
+    fn chapter_is_dated_plausibly(
+        block: &BlockChapter,
+        chain_repo: &ChainRepository,
+        config: &ChronicleConfiguration,
+        now: u64,
+    ) -> Result<(), ChronicleError> {
+        let latest_plausible = now.saturating_add(config.max_future_drift_secs);
+        if block.timestamp_of_creation > latest_plausible {
+            return Err(ChronicleError::InvalidTimestamp(format!(
+                "Chapter {} is dated {}, beyond the latest plausible {}",
+                block.chapter_number, block.timestamp_of_creation, latest_plausible
+            )));
+        }
+        let median_time_past = chain_repo.median_time_past()?;
+        if block.timestamp_of_creation <= median_time_past {
+            return Err(ChronicleError::InvalidTimestamp(format!(
+                "Chapter {} is dated {}, not after the median time past {}",
+                block.chapter_number, block.timestamp_of_creation, median_time_past
+            )));
+        }
+        Ok(())
+    }
+
+    /// A chapter closing a retarget interval sets the next difficulty, so it
+    /// may not be dated before the interval began, nor before the median
+    /// time past of the chapters it follows: either could only be a
+    /// time-warp, not a real passage of time
+    fn chapter_closes_its_interval_in_time(
+        block: &BlockChapter,
+        chain_repo: &ChainRepository,
+        config: &ChronicleConfiguration,
+    ) -> Result<(), ChronicleError> {
+        let interval = config.difficulty_adjustment_interval;
+        if interval <= 1 || block.chapter_number == 0 || !block.chapter_number.is_multiple_of(interval) {
+            return Ok(());
+        }
+
+        let first_chapter_number = block.chapter_number - (interval - 1);
+        let first_block = chain_repo.load_header(first_chapter_number)?
+            .ok_or(ChronicleError::ChapterNotFound(first_chapter_number))?;
+        let median_time_past = chain_repo.median_time_past_at(block.chapter_number - 1)?;
+        let earliest_plausible = first_block.timestamp_of_creation.max(median_time_past);
+        if block.timestamp_of_creation < earliest_plausible {
+            return Err(ChronicleError::InvalidTimestamp(format!(
+                "Chapter {} closes its retarget interval at {}, before the earliest plausible {}",
+                block.chapter_number, block.timestamp_of_creation, earliest_plausible
+            )));
+        }
+        Ok(())
+    }
+
+    async fn perform_proof_of_work(block: &BlockChapter) -> Result<(String, u64, f64), ChronicleError> {
+        let difficulty_target = block.proof_of_storytelling.difficulty_bits.to_target()?;
         let start_time = SystemTime::now();
         
         println!("🎯 Mining with difficulty target: {}", difficulty_target);
@@ -513,7 +2246,7 @@ impl BlockchainChronicler {
                 for nonce in start_nonce..(start_nonce + 10_000_000) {
                     let hash = Self::calculate_block_hash(&block_clone, nonce);
                     
-                    if Self::hash_meets_difficulty(&hash, difficulty_target) {
+                    if Self::hash_meets_difficulty(&hash, &difficulty_target) {
                         let _ = tx_clone.send((hash, nonce));
                         return;
                     }
@@ -547,48 +2280,151 @@ impl BlockchainChronicler {
     /// ## Network Synchronization Saga
     
     async fn begin_network_synchronization(&mut self) -> Result<(), ChronicleError> {
+        self.network_storytellers.begin_listening(self.configuration.network_port)?;
         self.network_storytellers.start_peer_discovery().await?;
-        self.network_storytellers.begin_chain_synchronization().await?;
+        self.network_storytellers.begin_chain_synchronization(self.sync_resume_height()).await?;
         println!("🌐 Network synchronization initiated");
         Ok(())
     }
 
+    /// The first chapter a sync still needs: the one after the highest
+    /// chapter validated so far, even if that was before a restart
+    pub fn sync_resume_height(&self) -> u64 {
+        self.network_storytellers.sync_status.read().unwrap().current_height + 1
+    }
+
+    /// Notes that every chapter up to `validated_height` has been validated
+    /// on the way to `target_height`, and persists it before returning.
+    pub fn record_sync_progress(&self, validated_height: u64, target_height: u64) -> Result<(), ChronicleError> {
+        let status = {
+            let mut status = self.network_storytellers.sync_status.write().unwrap();
+            status.current_height = validated_height;
+            status.target_height = target_height;
+            status.is_syncing = validated_height < target_height;
+            status.sync_progress = if target_height == 0 {
+                1.0
+            } else {
+                (validated_height as f64 / target_height as f64).min(1.0)
+            };
+            status.clone()
+        };
+        self.chain_repository.save_sync_status(&status)
+    }
+
     /// ## Supporting Cast: Helper Functions
     
-    fn calculate_current_difficulty(previous_block: &BlockChapter, config: &ChronicleConfiguration) -> u64 {
-        // Simplified difficulty adjustment
-        // In reality, would look at last N blocks' timing
-        let base_difficulty = 0x00000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
-        
-        if previous_block.chapter_number % config.difficulty_adjustment_interval == 0 && previous_block.chapter_number > 0 {
-            // Adjust difficulty based on block timing
-            // This is a simplified calculation
-            base_difficulty / 2 // Make it harder
-        } else {
-            previous_block.proof_of_storytelling.difficulty_target
+    /// Retargets every `difficulty_adjustment_interval` chapters: the
+    /// previous target scales by how long the last interval actually took
+    /// against how long it should have, by at most a factor of four either
+    /// way. A larger target is an easier one. The new target is folded into
+    /// the compact bits the next chapter will carry. How long the interval
+    /// took is read through `warp_resistant_timespan`, so timestamps bent by
+    /// the miners cannot drag the difficulty down.
+    fn calculate_current_difficulty(
+        previous_block: &BlockChapter,
+        chain_repo: &ChainRepository,
+        config: &ChronicleConfiguration,
+    ) -> Result<CompactTarget, ChronicleError> {
+        let previous_bits = previous_block.proof_of_storytelling.difficulty_bits;
+        let interval = config.difficulty_adjustment_interval;
+        if interval == 0 {
+            return Err(ChronicleError::InvalidConfiguration(
+                "difficulty_adjustment_interval must be greater than zero".to_string(),
+            ));
+        }
+        if previous_block.chapter_number == 0 || !previous_block.chapter_number.is_multiple_of(interval) {
+            return Ok(previous_bits);
+        }
+
+        let first_chapter_number = previous_block.chapter_number.saturating_sub(interval - 1);
+        let first_block = chain_repo.load_header(first_chapter_number)?
+            .ok_or(ChronicleError::ChapterNotFound(first_chapter_number))?;
+
+        let expected_timespan = config.target_block_time.as_secs().saturating_mul(interval).max(1);
+        let actual_timespan = Self::warp_resistant_timespan(&first_block, previous_block, chain_repo, config)?
+            .clamp(expected_timespan / 4, expected_timespan.saturating_mul(4));
+
+        Ok(previous_bits.to_target()?.scaled(actual_timespan, expected_timespan).to_compact())
+    }
+
+    /// How long an interval took, counting each chapter after the first at
+    /// its own timestamp raised to the median time past before it, and no
+    /// later than `RETARGET_GAP_LIMIT` target block times after the chapter
+    /// before it. Back-dated chapters then cannot make the interval look
+    /// longer, and one far-future chapter cannot stretch it on its own.
+    fn warp_resistant_timespan(
+        first_block: &BlockHeader,
+        last_block: &BlockChapter,
+        chain_repo: &ChainRepository,
+        config: &ChronicleConfiguration,
+    ) -> Result<u64, ChronicleError> {
+        let window_start = first_block.chapter_number.saturating_sub(MEDIAN_TIME_SPAN - 1);
+        let max_gap = config.target_block_time.as_secs().saturating_mul(RETARGET_GAP_LIMIT).max(1);
+
+        let mut counted_times: Vec<u64> = Vec::new();
+        let mut first_counted = first_block.timestamp_of_creation;
+        for chapter_number in window_start..=last_block.chapter_number {
+            let timestamp = if chapter_number == last_block.chapter_number {
+                last_block.timestamp_of_creation
+            } else {
+                chain_repo.load_header(chapter_number)?
+                    .ok_or(ChronicleError::ChapterNotFound(chapter_number))?
+                    .timestamp_of_creation
+            };
+            let counted = match counted_times.last() {
+                Some(&previous) if chapter_number > first_block.chapter_number => {
+                    let recent = &counted_times[counted_times.len().saturating_sub(MEDIAN_TIME_SPAN as usize)..];
+                    let floor = median_timestamp(recent);
+                    timestamp.max(floor).min(previous.max(floor).saturating_add(max_gap))
+                }
+                _ => timestamp,
+            };
+            if chapter_number == first_block.chapter_number {
+                first_counted = counted;
+            }
+            counted_times.push(counted);
         }
+
+        let last_counted = counted_times.last().copied().unwrap_or(first_counted);
+        Ok(last_counted.saturating_sub(first_counted))
+    }
+
+    /// The reward for the chapter at `height`. It halves at every multiple
+    /// of `reward_halving_interval`, so the chapter exactly on a boundary is
+    /// the first to earn the smaller reward. Each halving rounds down, and
+    /// the reward reaches zero once the base has been halved away.
+    fn mining_reward_at_height(height: u64, config: &ChronicleConfiguration) -> Result<u64, ChronicleError> {
+        let halvings = height.checked_div(config.reward_halving_interval)
+            .ok_or_else(|| ChronicleError::InvalidConfiguration(
+                "reward_halving_interval must be greater than zero".to_string(),
+            ))?;
+        Ok(u32::try_from(halvings).ok()
+            .and_then(|halvings| config.base_mining_reward.checked_shr(halvings))
+            .unwrap_or(0))
     }
 
     fn calculate_block_hash(block: &BlockChapter, nonce: u64) -> String {
         let mut hasher = Sha256::new();
         let block_data = format!(
-            "{}{}{}{}{}{}",
+            "{}{}{}{}{}{}{}",
             block.chapter_number,
             block.timestamp_of_creation,
             block.previous_chapter_essence,
             block.merkle_tree_of_truth,
-            block.proof_of_storytelling.difficulty_target,
+            block.proof_of_storytelling.difficulty_bits,
+            block.utxo_state_root.as_deref().unwrap_or(""),
             nonce
         );
         hasher.update(block_data.as_bytes());
         format!("{:064x}", hasher.finalize())
     }
 
-    fn hash_meets_difficulty(hash: &str, difficulty_target: u64) -> bool {
-        // Convert hash to number and compare with target
-        // Simplified: just check for leading zeros
-        let leading_zeros = difficulty_target.leading_zeros() as usize / 4;
-        hash.starts_with(&"0".repeat(leading_zeros))
+    /// A hash meets the target when, read as a big-endian 256-bit number,
+    /// it does not exceed it; anything that is not a 32-byte hex hash fails
+    fn hash_meets_difficulty(hash: &str, difficulty_target: &DifficultyTarget) -> bool {
+        DifficultyTarget::from_hex(hash)
+            .map(|hash_value| hash_value <= *difficulty_target)
+            .unwrap_or(false)
     }
 
     fn weave_merkle_tree_of_truth(transactions: &[TransactionStory]) -> String {
@@ -597,11 +2433,7 @@ impl BlockchainChronicler {
         }
         
         let mut hashes: Vec<String> = transactions.iter()
-            .map(|tx| {
-                let mut hasher = Sha256::new();
-                hasher.update(tx.story_id.as_bytes());
-                format!("{:x}", hasher.finalize())
-            })
+            .map(Self::merkle_leaf_hash)
             .collect();
         
         // Build merkle tree
@@ -609,14 +2441,9 @@ impl BlockchainChronicler {
             let mut next_level = Vec::new();
             
             for chunk in hashes.chunks(2) {
-                let mut hasher = Sha256::new();
-                hasher.update(chunk[0].as_bytes());
-                if chunk.len() > 1 {
-                    hasher.update(chunk[1].as_bytes());
-                } else {
-                    hasher.update(chunk[0].as_bytes()); // Duplicate if odd number
-                }
-                next_level.push(format!("{:x}", hasher.finalize()));
+                // Duplicate if odd number
+                let right = chunk.get(1).unwrap_or(&chunk[0]);
+                next_level.push(Self::merkle_parent_hash(&chunk[0], right));
             }
             
             hashes = next_level;
@@ -625,9 +2452,68 @@ impl BlockchainChronicler {
         hashes.into_iter().next().unwrap_or_else(|| "0".repeat(64))
     }
 
+    /// The leaf a story becomes in its chapter's merkle tree
+    pub fn merkle_leaf_hash(story: &TransactionStory) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(story.story_id.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn merkle_parent_hash(left: &str, right: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(left.as_bytes());
+        hasher.update(right.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// ## A Path From One Story to the Root
+    /// 
+    /// The sibling hashes met climbing from the story's leaf to the merkle
+    /// root, each flagged `true` when the sibling sits on the right. A node
+    /// left without a partner is its own sibling, as in the tree itself.
+    /// `None` when the story is not among the transactions.
+    pub fn merkle_proof(transactions: &[TransactionStory], target_id: &str) -> Option<Vec<(String, bool)>> {
+        let mut index = transactions.iter().position(|story| story.story_id == target_id)?;
+        let mut hashes: Vec<String> = transactions.iter().map(Self::merkle_leaf_hash).collect();
+        let mut proof = Vec::new();
+
+        while hashes.len() > 1 {
+            let sibling_is_right = index % 2 == 0;
+            let sibling_index = if sibling_is_right { index + 1 } else { index - 1 };
+            let sibling = hashes.get(sibling_index).unwrap_or(&hashes[index]).clone();
+            proof.push((sibling, sibling_is_right));
+
+            hashes = hashes.chunks(2)
+                .map(|chunk| Self::merkle_parent_hash(&chunk[0], chunk.get(1).unwrap_or(&chunk[0])))
+                .collect();
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+
+    /// Climbs from `tx_hash`, a leaf as `merkle_leaf_hash` makes it, along
+    /// the proof and checks that the climb ends at `root`
+    pub fn verify_merkle_proof(tx_hash: &str, proof: &[(String, bool)], root: &str) -> bool {
+        let climbed = proof.iter().fold(tx_hash.to_string(), |node, (sibling, sibling_is_right)| {
+            if *sibling_is_right {
+                Self::merkle_parent_hash(&node, sibling)
+            } else {
+                Self::merkle_parent_hash(sibling, &node)
+            }
+        });
+        climbed == root
+    }
+
+    /// What a narrator signs: the story's identity, what it spends, every
+    /// output down to its locking script, and the fee the narrator itself
+    /// pays. A sponsored story's fee and sponsorship are the sponsor's to
+    /// sign, over the narrator's own signature, so a narrator who pays no
+    /// fee signs the same message whether or not a patron steps forward.
     fn create_signable_message(&self, story: &TransactionStory) -> Vec<u8> {
         // Create a canonical representation for signing
         let mut message = Vec::new();
+        message.extend_from_slice(&story.version.to_le_bytes());
         message.extend_from_slice(story.story_id.as_bytes());
         message.extend_from_slice(&story.timestamp_of_telling.to_le_bytes());
         message.extend_from_slice(&story.transaction_nonce.to_le_bytes());
@@ -641,37 +2527,64 @@ impl BlockchainChronicler {
         for output in &story.outputs_created {
             message.extend_from_slice(&output.recipient_address);
             message.extend_from_slice(&output.value_locked.to_le_bytes());
+            message.extend_from_slice(&bincode::serialize(&output.locking_script).unwrap_or_default());
         }
+
+        let fee_paid_by_narrator = if story.fee_sponsorship.is_some() { 0 } else { story.story_fee };
+        message.extend_from_slice(&fee_paid_by_narrator.to_le_bytes());
         
         message
     }
 
-    fn verify_spending_authorization(&self, _utxo: &UTXOOutput, _public_key: &[u8]) -> Result<(), ChronicleError> {
-        // Simplified authorization check
-        // In reality, would execute the locking script
-        Ok(())
+    /// What a sponsor signs: the narrator's signed story plus the fee and
+    /// the sponsor's own inputs and change
+    fn create_sponsor_message(&self, story: &TransactionStory, sponsorship: &FeeSponsorship) -> Vec<u8> {
+        let mut message = self.create_signable_message(story);
+        message.extend_from_slice(&story.digital_signature);
+        message.extend_from_slice(&story.story_fee.to_le_bytes());
+        message.extend_from_slice(&sponsorship.sponsor_public_key);
+
+        for input in &sponsorship.sponsor_inputs {
+            message.extend_from_slice(input.previous_story_id.as_bytes());
+            message.extend_from_slice(&input.output_index.to_le_bytes());
+        }
+
+        if let Some(change) = &sponsorship.sponsor_change {
+            message.extend_from_slice(&change.recipient_address);
+            message.extend_from_slice(&change.value_locked.to_le_bytes());
+            message.extend_from_slice(&bincode::serialize(&change.locking_script).unwrap_or_default());
+        }
+
+        message
     }
 
     async fn craft_and_commit_genesis_chapter(&mut self) -> Result<(), ChronicleError> {
-        let genesis_block = BlockChapter {
+        self.chain_repository.add_block_chapter(Self::genesis_chapter()).await?;
+        println!("🏗️  Genesis chapter crafted and committed");
+        Ok(())
+    }
+
+    /// The first chapter, identical on every node: nothing in it depends on
+    /// the clock or on who writes it, so fresh nodes agree on their root.
+    fn genesis_chapter() -> BlockChapter {
+        let mut genesis_block = BlockChapter {
             chapter_number: 0,
-            timestamp_of_creation: current_timestamp(),
+            timestamp_of_creation: GENESIS_TIMESTAMP,
             previous_chapter_essence: "0".repeat(64),
             transaction_tales: vec![],
-            merkle_tree_of_truth: "genesis".to_string(),
-            chapter_essence: "genesis_hash".to_string(),
+            merkle_tree_of_truth: Self::weave_merkle_tree_of_truth(&[]),
+            chapter_essence: String::new(),
             proof_of_storytelling: ProofOfWork {
-                difficulty_target: 0x0FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF,
-                nonce_of_discovery: 0,
+                difficulty_bits: GENESIS_DIFFICULTY_TARGET.to_compact(),
+                nonce_of_discovery: GENESIS_NONCE,
                 storyteller_reward: 0,
                 hash_rate_estimate: 0.0,
             },
             chapter_size_bytes: 0,
+            utxo_state_root: None,
         };
-
-        self.chain_repository.add_block_chapter(genesis_block).await?;
-        println!("🏗️  Genesis chapter crafted and committed");
-        Ok(())
+        genesis_block.chapter_essence = Self::calculate_block_hash(&genesis_block, GENESIS_NONCE);
+        genesis_block
     }
 
     /// ## Public API: Chronicle State and Operations
@@ -694,474 +2607,5586 @@ impl BlockchainChronicler {
         self.chain_repository.verify_chain_integrity().await
     }
 
-    pub async fn get_balance(&self, address: &[u8]) -> Result<u64, ChronicleError> {
-        self.utxo_ledger.calculate_balance(address).await
+    /// Reveals the bounded record of recent mempool admissions, rejections,
+    /// replacements and evictions, oldest first.
+    pub async fn mempool_audit(&self) -> Vec<MempoolAuditEntry> {
+        self.mempool_audit_trail.lock().unwrap().iter().cloned().collect()
     }
 
-    pub async fn create_transaction(
-        &self,
-        from_keypair: &Keypair,
-        to_address: &[u8],
-        amount: u64,
-        fee: u64,
-    ) -> Result<TransactionStory, ChronicleError> {
-        // Find UTXOs for sender
-        let sender_address = from_keypair.public.to_bytes();
-        let utxos = self.utxo_ledger.find_utxos_for_address(&sender_address).await?;
-        
-        // Select UTXOs to cover amount + fee
-        let mut selected_utxos = Vec::new();
-        let mut total_input = 0u64;
-        
-        for (utxo_ref, utxo) in utxos {
-            selected_utxos.push((utxo_ref, utxo));
-            total_input += utxo.value_locked;
-            
-            if total_input >= amount + fee {
-                break;
+    /// ## What the Mempool Has Heard Since
+    /// 
+    /// The stories that entered and left the mempool after sequence `since`,
+    /// each counted once by its net effect: a story that came and went in
+    /// between appears in neither list. Pass the returned `sequence` back
+    /// next time. A `since` older than the journal remembers comes back
+    /// `truncated`, and the peer should fetch the whole mempool instead.
+    pub async fn get_mempool_delta(&self, since: u64) -> MempoolDelta {
+        self.journal_mempool_changes();
+        let journal = self.mempool_journal.lock().unwrap();
+
+        let mut first_and_last: HashMap<&str, (bool, bool)> = HashMap::new();
+        let mut order = Vec::new();
+        for change in journal.changes.iter().filter(|change| change.sequence > since) {
+            first_and_last.entry(&change.story_id)
+                .and_modify(|(_, last_added)| *last_added = change.added)
+                .or_insert_with(|| {
+                    order.push(change.story_id.as_str());
+                    (change.added, change.added)
+                });
+        }
+
+        let mut delta = MempoolDelta {
+            sequence: journal.sequence,
+            truncated: since < journal.forgotten_through,
+            ..MempoolDelta::default()
+        };
+        for story_id in order {
+            match first_and_last[story_id] {
+                (true, true) => delta.added.push(story_id.to_string()),
+                (false, false) => delta.removed.push(story_id.to_string()),
+                // Came and went, or went and came back: no change to relay
+                _ => {}
             }
         }
-        
-        if total_input < amount + fee {
-            return Err(ChronicleError::InsufficientFunds { 
-                required: amount + fee, 
-                available: total_input 
-            });
+        delta
+    }
+
+    /// The mempool's current sequence number, from which a later
+    /// `get_mempool_delta` can be asked
+    pub fn mempool_sequence(&self) -> u64 {
+        self.journal_mempool_changes();
+        self.mempool_journal.lock().unwrap().sequence
+    }
+
+    /// Compares the mempool with what the journal last saw and numbers every
+    /// story that has since entered or left it. Stories leave by many roads,
+    /// among them the mining quest, so the journal looks rather than being told.
+    fn journal_mempool_changes(&self) {
+        let mempool = self.mempool_of_pending_tales.lock().unwrap();
+        let pending: HashSet<String> = mempool.iter()
+            .map(|story| story.story_id.clone())
+            .collect();
+        let mut journal = self.mempool_journal.lock().unwrap();
+
+        let mut departed: Vec<String> = journal.present.difference(&pending).cloned().collect();
+        let mut arrived: Vec<String> = pending.difference(&journal.present).cloned().collect();
+        departed.sort();
+        arrived.sort();
+
+        // The same changes are mirrored to disk, so the mempool survives a restart
+        let arrived_stories: Vec<TransactionStory> = mempool.iter()
+            .filter(|story| !journal.present.contains(&story.story_id))
+            .cloned()
+            .collect();
+        drop(mempool);
+        if let Err(e) = self.chain_repository.record_pending_stories(&arrived_stories, &departed) {
+            eprintln!("Mempool persistence error: {:?}", e);
         }
-        
-        // Create outputs
-        let mut outputs = vec![
-            UTXOOutput {
-                recipient_address: to_address.to_vec(),
-                value_locked: amount,
-                locking_script: ScriptOfTruth {
-                    script_type: ScriptType::PayToPublicKey,
-                    required_signatures: 1,
-                    public_keys: vec![to_address.to_vec()],
-                },
+
+        for (story_id, added) in departed.into_iter().map(|id| (id, false)).chain(arrived.into_iter().map(|id| (id, true))) {
+            journal.sequence += 1;
+            let sequence = journal.sequence;
+            journal.changes.push_back(MempoolChange { sequence, story_id, added });
+        }
+        while journal.changes.len() > MEMPOOL_JOURNAL_MEMORY {
+            if let Some(forgotten) = journal.changes.pop_front() {
+                journal.forgotten_through = forgotten.sequence;
             }
-        ];
-        
-        // Add change output if necessary
-        let change = total_input - amount - fee;
-        if change > 0 {
-            outputs.push(UTXOOutput {
-                recipient_address: sender_address.to_vec(),
-                value_locked: change,
-                locking_script: ScriptOfTruth {
-                    script_type: ScriptType::PayToPublicKey,
-                    required_signatures: 1,
-                    public_keys: vec![sender_address.to_vec()],
-                },
-            });
         }
-        
-        // Create transaction
-        let mut transaction = TransactionStory {
-            story_id: generate_transaction_id(),
-            inputs_consumed: selected_utxos.into_iter().map(|(utxo_ref, _)| utxo_ref).collect(),
-            outputs_created: outputs,
-            story_fee: fee,
-            timestamp_of_telling: current_timestamp(),
-            transaction_nonce: generate_nonce(),
-            digital_signature: Vec::new(), // Will be filled after signing
-            public_key_of_narrator: from_keypair.public.to_bytes().to_vec(),
-        };
-        
-        // Sign the transaction
-        let message = self.create_signable_message(&transaction);
-        let signature = from_keypair.sign(&message);
-        transaction.digital_signature = signature.to_bytes().to_vec();
-        
-        Ok(transaction)
+        journal.present = pending;
     }
-}
 
-/// ## Implementation Details for Supporting Structures
+    /// ## How Long Until the Story Is Told?
+    /// 
+    /// Estimates how many chapters a story paying `fee_rate` (fee per
+    /// thousand serialized bytes) waits before inclusion. The pending stories the
+    /// miner's `mining_order` would place ahead of it (every boosted story,
+    /// and any paying at least as much) are packed into chapters just as
+    /// the miner packs them; the story lands in the first chapter with room
+    /// to spare.
+    pub async fn estimate_confirmation_blocks(&self, fee_rate: u64) -> u64 {
+        let boosts = self.priority_boosts.lock().unwrap().clone();
+        let mut ahead: Vec<TransactionStory> = self.mempool_of_pending_tales.lock().unwrap().iter()
+            .filter(|pending| boosts.contains_key(&pending.story_id) || story_fee_rate(pending) >= fee_rate)
+            .cloned()
+            .collect();
+        ahead.sort_by(|a, b| Self::mining_order(&boosts, a, b));
 
-impl ChainRepository {
-    async fn new(data_dir: &str) -> Result<Self, ChronicleError> {
-        std::fs::create_dir_all(data_dir).map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        
-        let block_db = sled::open(format!("{}/blocks", data_dir))
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        let tx_db = sled::open(format!("{}/transactions", data_dir))
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        let utxo_db = sled::open(format!("{}/utxos", data_dir))
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        
-        Ok(Self {
-            block_db,
-            tx_db,
-            utxo_db,
-            chain_tip: Arc::new(RwLock::new(None)),
-            block_index: Arc::new(RwLock::new(HashMap::new())),
-        })
+        let max_block_size = self.configuration.max_block_size;
+        let mut chapters_filled = 0;
+        let mut unpacked = ahead.as_slice();
+        while !unpacked.is_empty() {
+            let packed = Self::stories_filling_a_chapter(unpacked, max_block_size);
+            let packed_bytes: u64 = unpacked[..packed].iter().map(story_size_bytes).sum();
+            let room_to_spare = packed < MAX_TALES_PER_CHAPTER && packed_bytes < max_block_size as u64;
+            if packed == unpacked.len() && room_to_spare {
+                break;
+            }
+            chapters_filled += 1;
+            unpacked = &unpacked[packed..];
+        }
+        chapters_filled + 1
     }
-    
-    async fn chain_is_empty(&self) -> Result<bool, ChronicleError> {
-        Ok(self.block_db.is_empty())
+
+    /// ## What the Mempool Is Paying
+    /// 
+    /// How many pending stories pay each fee rate (fee per thousand
+    /// serialized bytes), highest rate first, so a wallet can see what it must pay to be
+    /// packed ahead of the crowd.
+    pub fn mempool_fee_histogram(&self) -> Vec<(u64, usize)> {
+        let mut stories_by_rate: BTreeMap<u64, usize> = BTreeMap::new();
+        for pending in self.mempool_of_pending_tales.lock().unwrap().iter() {
+            *stories_by_rate.entry(story_fee_rate(pending)).or_default() += 1;
+        }
+        stories_by_rate.into_iter().rev().collect()
     }
-    
-    async fn add_block_chapter(&self, block: BlockChapter) -> Result<(), ChronicleError> {
-        // Serialize and store block
-        let block_data = bincode::serialize(&block)
-            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-        
-        let block_key = format!("block_{:010}", block.chapter_number);
-        self.block_db.insert(&block_key, block_data)
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        
-        // Store transactions
-        for tx in &block.transaction_tales {
-            let tx_data = bincode::serialize(tx)
-                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-            self.tx_db.insert(&tx.story_id, tx_data)
-                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+
+    pub async fn get_balance(&self, address: &[u8]) -> Result<u64, ChronicleError> {
+        self.utxo_ledger.calculate_balance(address).await
+    }
+
+    /// The state root of the unspent outputs as the ledger holds them now,
+    /// which after a chapter is applied is the root that chapter commits
+    pub async fn utxo_state_root(&self) -> Result<String, ChronicleError> {
+        utxo_state_root(&self.utxo_ledger.unspent_set()?)
+    }
+
+    /// Every unit held in an unspent output: what has been minted, less
+    /// whatever fees were burned
+    pub async fn total_supply(&self) -> Result<u64, ChronicleError> {
+        self.utxo_ledger.calculate_total_supply()
+    }
+
+    /// ## A Balance Worth Crediting
+    /// 
+    /// What `address` holds in outputs buried at least `min_confirmations`
+    /// chapters deep, an output in the tip counting as one confirmation.
+    /// Pending stories never count, and neither does an output no chapter
+    /// on our chain created; with zero confirmations this is `get_balance`.
+    pub async fn get_balance_with_confirmations(&self, address: &[u8], min_confirmations: u64) -> Result<u64, ChronicleError> {
+        let tip_height = self.chain_height().await?;
+        self.utxo_ledger.calculate_confirmed_balance(address, tip_height, min_confirmations)
+    }
+
+    /// ## Looking Back Through the Chronicle
+    /// 
+    /// The chapter at `height` on our chain, if the chain reaches that far.
+    /// A pruned node refuses chapters older than those it keeps.
+    pub async fn get_block_by_height(&self, height: u64) -> Result<Option<BlockChapter>, ChronicleError> {
+        self.chapter_is_kept(height).await?;
+        self.chain_repository.load_chapter(height)
+    }
+
+    /// The chapter on our chain whose essence is `essence`
+    pub async fn get_block_by_hash(&self, essence: &str) -> Result<Option<BlockChapter>, ChronicleError> {
+        match self.chain_repository.chapter_number_of(essence)? {
+            Some(chapter_number) => self.get_block_by_height(chapter_number).await,
+            None => Ok(None),
         }
-        
-        // Update chain tip
-        {
-            let mut tip = self.chain_tip.write().unwrap();
-            *tip = Some(block.clone());
+    }
+
+    /// The lowest height this node keeps: zero for an archival node, and
+    /// for a pruned one the start of its most recent `keep_blocks` chapters
+    pub async fn pruning_horizon(&self) -> Result<u64, ChronicleError> {
+        Ok(Self::pruning_horizon_at(self.chain_height().await?, &self.configuration))
+    }
+
+    fn pruning_horizon_at(tip_height: u64, config: &ChronicleConfiguration) -> u64 {
+        match config.node_mode {
+            NodeMode::Archival => 0,
+            NodeMode::Pruned { keep_blocks } => (tip_height + 1).saturating_sub(keep_blocks),
         }
-        
-        // Update block index
-        {
-            let mut index = self.block_index.write().unwrap();
-            index.insert(block.chapter_essence.clone(), block.chapter_number);
+    }
+
+    /// After a chapter joins a pruned node's chain, lets go of the bodies
+    /// past its horizon, stopping short of the lowest chapter a peer still
+    /// syncing from us has yet to fetch
+    fn prune_to_node_mode(chain_repo: &ChainRepository, config: &ChronicleConfiguration, tip_height: u64) -> Result<(), ChronicleError> {
+        let horizon = Self::pruning_horizon_at(tip_height, config)
+            .min(chain_repo.lowest_height_still_served().unwrap_or(u64::MAX));
+        if horizon == 0 {
+            return Ok(());
+        }
+        match chain_repo.prune_below(horizon) {
+            // A peer that began syncing since we looked simply waits for the next chapter
+            Ok(_) | Err(ChronicleError::PruningRefused(_)) => Ok(()),
+            Err(plot_twist) => Err(plot_twist),
         }
-        
-        // Flush to disk
-        self.block_db.flush_async().await
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-        
-        Ok(())
     }
-    
-    async fn get_chain_tip(&self) -> Result<Option<BlockChapter>, ChronicleError> {
-        let tip = self.chain_tip.read().unwrap().clone();
-        
-        if tip.is_none() {
-            // Load from database
-            if let Some((_, block_data)) = self.block_db.last()
-                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
-                let block: BlockChapter = bincode::deserialize(&block_data)
-                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-                return Ok(Some(block));
+
+    /// The body of a chapter on our chain, telling a body this node has
+    /// pruned apart from a chapter the chain never reached
+    fn load_kept_chapter(&self, chapter_number: u64) -> Result<BlockChapter, ChronicleError> {
+        match self.chain_repository.load_chapter(chapter_number)? {
+            Some(chapter) => Ok(chapter),
+            None if self.chain_repository.load_header(chapter_number)?.is_some() => {
+                Err(ChronicleError::ChapterPruned(chapter_number))
             }
+            None => Err(ChronicleError::ChapterNotFound(chapter_number)),
         }
-        
-        Ok(tip)
-    }
-    
-    async fn transaction_exists(&self, tx_id: &str) -> Result<bool, ChronicleError> {
-        Ok(self.tx_db.contains_key(tx_id)
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?)
     }
-    
-    async fn verify_chain_integrity(&self) -> Result<bool, ChronicleError> {
-        let mut previous_hash = "0".repeat(64);
-        
-        for result in self.block_db.iter() {
-            let (_, block_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-            let block: BlockChapter = bincode::deserialize(&block_data)
-                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-            
-            if block.chapter_number > 0 && block.previous_chapter_essence != previous_hash {
-                return Ok(false);
-            }
-            
-            previous_hash = block.chapter_essence.clone();
+
+    async fn chapter_is_kept(&self, height: u64) -> Result<(), ChronicleError> {
+        if height < self.pruning_horizon().await? {
+            return Err(ChronicleError::ChapterPruned(height));
         }
-        
-        Ok(true)
+        Ok(())
     }
-    
-    fn clone(&self) -> Self {
-        Self {
-            block_db: self.block_db.clone(),
-            tx_db: self.tx_db.clone(),
-            utxo_db: self.utxo_db.clone(),
-            chain_tip: self.chain_tip.clone(),
-            block_index: self.block_index.clone(),
+
+    /// A story committed to one of our chapters
+    pub async fn get_transaction(&self, story_id: &str) -> Result<Option<TransactionStory>, ChronicleError> {
+        self.chain_repository.load_story(story_id)
+    }
+
+    /// ## Whispers From the Network
+    /// 
+    /// Records the tip hash a peer reports at a given height, as heard in
+    /// its handshake.
+    pub fn peer_reports_tip(&self, peer_address: &str, height: u64, tip_hash: &str) {
+        self.network_storytellers.record_peer_tip(peer_address, height, tip_hash);
+    }
+
+    /// Records the time a peer's clock shows, as reported in its handshake,
+    /// so the network-adjusted time can follow the peers' consensus.
+    pub fn peer_reports_clock(&self, peer_address: &str, peer_time: u64) {
+        self.network_storytellers.record_peer_clock(peer_address, peer_time);
+    }
+
+    /// ## The Time the Network Agrees On
+    /// 
+    /// Our clock moved by the median offset the peers' clocks report,
+    /// clamped to `max_peer_clock_offset_secs`, so a node whose own clock
+    /// is skewed still judges future-dated stories and chapters as the
+    /// network does, and no handful of lying peers can move it far.
+    pub fn network_adjusted_time(&self) -> u64 {
+        let offset = self.network_storytellers.network_clock_offset(self.configuration.max_peer_clock_offset_secs);
+        current_timestamp().saturating_add_signed(offset)
+    }
+
+    /// ## When the Story Forks
+    /// 
+    /// Reports the highest height at which peers claim different tips,
+    /// with the peers standing behind each competing version.
+    pub fn detect_chain_split(&self) -> Option<SplitReport> {
+        self.network_storytellers.find_chain_split()
+    }
+
+    /// ## A New Guardian Joins the Council
+    pub fn admit_validator(&mut self, guardian_id: &str, stake_in_truth: u64, public_key: PublicKey) {
+        self.validator_council.admit_validator(guardian_id, stake_in_truth, public_key);
+    }
+
+    /// ## The Watchtower Sees a Signature
+    /// 
+    /// Records a validator's signature over the chapter it backs at a
+    /// height. A validator caught signing two different chapters at one
+    /// height is penalized, and the evidence is returned for slashing.
+    pub fn validator_signs_chapter(
+        &mut self,
+        validator_id: &str,
+        height: u64,
+        chapter_essence: &str,
+        signature: &[u8],
+    ) -> Result<Option<SlashingEvidence>, ChronicleError> {
+        let claim = SignedChapterClaim {
+            chapter_essence: chapter_essence.to_string(),
+            signature: signature.to_vec(),
+        };
+        let evidence = self.validator_council.observe_chapter_signature(validator_id, height, claim)?;
+        if let Some(evidence) = &evidence {
+            println!("⚔️  Validator {} equivocated at height {}", evidence.validator_id, evidence.height);
         }
+        Ok(evidence)
     }
-}
 
-impl UTXOLedger {
-    async fn new(data_dir: &str) -> Result<Self, ChronicleError> {
-        let db = sled::open(format!("{}/utxos", data_dir))
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-            
-        Ok(Self {
-            unspent_outputs: Arc::new(RwLock::new(HashMap::new())),
-            spent_outputs: Arc::new(RwLock::new(HashSet::new())),
-            db,
+    /// The reputation a validator retains after any penalties
+    pub fn validator_reputation(&self, validator_id: &str) -> f64 {
+        self.validator_council.reputation_system.reputation_of(validator_id)
+    }
+
+    /// ## The Chronicle Takes Its Own Pulse
+    /// 
+    /// Summarizes the last `window` chapters, reading only those chapters
+    /// backwards from the tip rather than the whole chain.
+    pub async fn chain_stats(&self, window: u64) -> Result<ChainStats, ChronicleError> {
+        let tip = self.chain_repository.get_chain_tip().await?
+            .ok_or_else(|| ChronicleError::ChronicleCorrupted("No chain tip found".to_string()))?;
+
+        let oldest_chapter = tip.chapter_number.saturating_sub(window.saturating_sub(1));
+        let mut chapters = Vec::with_capacity((tip.chapter_number - oldest_chapter + 1) as usize);
+        for chapter_number in oldest_chapter..tip.chapter_number {
+            chapters.push(self.load_kept_chapter(chapter_number)?);
+        }
+        chapters.push(tip);
+
+        let chapters_observed = chapters.len() as u64;
+        let total_stories: u64 = chapters.iter().map(|chapter| chapter.transaction_tales.len() as u64).sum();
+        let total_fees: u64 = chapters.iter()
+            .flat_map(|chapter| chapter.transaction_tales.iter())
+            .map(|story| story.story_fee)
+            .sum();
+
+        let first_time = chapters[0].timestamp_of_creation;
+        let last_time = chapters[chapters.len() - 1].timestamp_of_creation;
+        let elapsed_secs = last_time.saturating_sub(first_time);
+        let average_block_time_secs = if chapters_observed > 1 {
+            elapsed_secs as f64 / (chapters_observed - 1) as f64
+        } else {
+            0.0
+        };
+
+        // Stories in the first chapter were gathered before the window's clock started
+        let stories_after_first = total_stories - chapters[0].transaction_tales.len() as u64;
+        let estimated_tps = if elapsed_secs > 0 {
+            stories_after_first as f64 / elapsed_secs as f64
+        } else {
+            0.0
+        };
+
+        Ok(ChainStats {
+            chapters_observed,
+            average_block_time_secs,
+            average_stories_per_chapter: total_stories as f64 / chapters_observed as f64,
+            average_fee: if total_stories > 0 { total_fees as f64 / total_stories as f64 } else { 0.0 },
+            estimated_tps,
         })
     }
-    
-    async fn find_unspent_output(&self, utxo_key: &str) -> Result<Option<UTXOOutput>, ChronicleError> {
-        // Check in-memory cache first
-        {
-            let unspent = self.unspent_outputs.read().unwrap();
-            if let Some(utxo) = unspent.get(utxo_key) {
-                return Ok(Some(utxo.clone()));
-            }
+
+    /// ## Freezing the Chronicle at Chapter H
+    /// 
+    /// The chain and unspent outputs as they stood after chapter `height`,
+    /// so long analyses see one consistent world while mining carries on.
+    /// Nothing is copied per snapshot: every snapshot taken at the same tip
+    /// shares one frozen base, a new base shares each chapter the last one
+    /// already held, and a snapshot keeps only the outputs the chapters
+    /// after `height` created or spent. A pruned node can freeze only the
+    /// chapters whose bodies it still keeps.
+    pub async fn snapshot_at(&self, height: u64) -> Result<ChainSnapshot, ChronicleError> {
+        let base = self.snapshot_base_at_tip().await?;
+        let Some(kept_at_height) = usize::try_from(height).ok().and_then(|index| base.chapters.get(index)) else {
+            return Err(ChronicleError::ChapterNotFound(height));
+        };
+        // Bodies are pruned oldest first, so every later chapter is kept too
+        if kept_at_height.is_none() {
+            return Err(ChronicleError::ChapterPruned(height));
         }
-        
-        // Check database
-        if let Some(utxo_data) = self.db.get(utxo_key)
-            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
-            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
-                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-            return Ok(Some(utxo));
+
+        // Undo every chapter written after the frozen height, newest first
+        let mut created_since = HashSet::new();
+        let mut spent_since = HashMap::new();
+        for later_chapter in base.chapters[height as usize + 1..].iter().flatten().rev() {
+            for story in later_chapter.transaction_tales.iter().rev() {
+                for (utxo_key, _) in story_creates(story) {
+                    spent_since.remove(&utxo_key);
+                    created_since.insert(utxo_key);
+                }
+                for input in story_spends(story) {
+                    if let Some(spent_output) = self.chain_repository.load_created_output(input)? {
+                        let utxo_key = format!("{}:{}", input.previous_story_id, input.output_index);
+                        created_since.remove(&utxo_key);
+                        spent_since.insert(utxo_key, spent_output);
+                    }
+                }
+            }
         }
-        
-        Ok(None)
+
+        Ok(ChainSnapshot {
+            height,
+            chapters: base.chapters,
+            unspent_outputs: base.unspent_outputs,
+            created_since,
+            spent_since,
+        })
     }
-    
-    async fn calculate_balance(&self, address: &[u8]) -> Result<u64, ChronicleError> {
-        let mut balance = 0u64;
-        
-        for result in self.db.iter() {
-            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
-                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-            
-            if utxo.recipient_address == address {
-                // Check if not spent
-                let key_str = String::from_utf8_lossy(&key);
-                let spent = self.spent_outputs.read().unwrap();
-                if !spent.contains(key_str.as_ref()) {
-                    balance = balance.checked_add(utxo.value_locked)
-                        .ok_or(ChronicleError::ValueOverflow)?;
-                }
+
+    /// The frozen base for the current tip, built afresh only once the tip
+    /// has moved on. The ledger changes only as chapters join or leave the
+    /// chain, so the tip's essence names the state the base froze. Every
+    /// chapter is known by its header; a pruned body is simply left out.
+    async fn snapshot_base_at_tip(&self) -> Result<SnapshotBase, ChronicleError> {
+        loop {
+            let tip_before = self.chain_repository.get_chain_tip().await?
+                .ok_or_else(|| ChronicleError::ChronicleCorrupted("No chain tip found".to_string()))?;
+            let previous = self.snapshot_base.lock().unwrap().clone();
+            if let Some(base) = previous.as_ref().filter(|base| base.tip_essence == tip_before.chapter_essence) {
+                return Ok(base.clone());
+            }
+
+            let unspent_outputs = Arc::new(self.utxo_ledger.unspent_outputs_as_they_stand()?);
+            let mut chapters = Vec::with_capacity(tip_before.chapter_number as usize + 1);
+            for chapter_number in 0..=tip_before.chapter_number {
+                let header = self.chain_repository.load_header(chapter_number)?
+                    .ok_or(ChronicleError::ChapterNotFound(chapter_number))?;
+                let already_held = previous.as_ref()
+                    .and_then(|base| base.chapters.get(chapter_number as usize))
+                    .and_then(Option::as_ref)
+                    .filter(|chapter| chapter.chapter_essence == header.chapter_essence);
+                let chapter = match already_held {
+                    Some(chapter) => Some(chapter.clone()),
+                    None => self.chain_repository.load_chapter(chapter_number)?.map(Arc::new),
+                };
+                chapters.push(chapter);
+            }
+
+            // A chapter committed mid-copy would blur the picture; take it again
+            let tip_after = self.chain_repository.get_chain_tip().await?.map(|tip| tip.chapter_essence);
+            if tip_after.as_ref() == Some(&tip_before.chapter_essence) {
+                let base = SnapshotBase {
+                    tip_essence: tip_before.chapter_essence,
+                    chapters: chapters.into(),
+                    unspent_outputs,
+                };
+                *self.snapshot_base.lock().unwrap() = Some(base.clone());
+                return Ok(base);
             }
         }
-        
-        Ok(balance)
     }
-    
-    async fn find_utxos_for_address(&self, address: &[u8]) -> Result<Vec<(UTXOReference, UTXOOutput)>, ChronicleError> {
-        let mut utxos = Vec::new();
-        
-        for result in self.db.iter() {
-            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
-            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
-                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
-            
-            if utxo.recipient_address == address {
-                let key_str = String::from_utf8_lossy(&key);
-                let spent = self.spent_outputs.read().unwrap();
-                if !spent.contains(key_str.as_ref()) {
-                    // Parse key to create UTXOReference
-                    let parts: Vec<&str> = key_str.split(':').collect();
-                    if parts.len() == 2 {
-                        if let Ok(output_index) = parts[1].parse::<u32>() {
-                            let utxo_ref = UTXOReference {
-                                previous_story_id: parts[0].to_string(),
-                                output_index,
-                            };
-                            utxos.push((utxo_ref, utxo));
-                        }
-                    }
+
+    /// ## Following the Coins From Hand to Hand
+    /// 
+    /// Every story told in chapters `from_height..=to_height` as a node, and
+    /// every spend they make as an edge from the story whose output is spent.
+    /// A spent output created before `from_height` still gets its edge, so
+    /// the graph shows where the range's value came from.
+    pub async fn export_tx_graph(&self, from_height: u64, to_height: u64) -> Result<TxGraph, ChronicleError> {
+        let mut graph = TxGraph::default();
+        for chapter_number in from_height..=to_height {
+            let chapter = self.load_kept_chapter(chapter_number)?;
+            for story in &chapter.transaction_tales {
+                graph.nodes.push(TxGraphNode {
+                    story_id: story.story_id.clone(),
+                    chapter_number,
+                    value_created: story_creates(story).map(|(_, output)| output.value_locked).sum(),
+                    is_coinbase: story.is_coinbase(),
+                });
+                for input in story_spends(story) {
+                    graph.edges.push(TxGraphEdge {
+                        from_story: input.previous_story_id.clone(),
+                        output_index: input.output_index,
+                        to_story: story.story_id.clone(),
+                        value: self.chain_repository.load_created_output(input)?.map(|output| output.value_locked),
+                    });
                 }
             }
         }
-        
-        Ok(utxos)
+        Ok(graph)
     }
-}
 
-impl NetworkOfStoryTellers {
-    async fn new(port: u16) -> Result<Self, ChronicleError> {
-        let (tx, _rx) = mpsc::unbounded_channel();
-        
-        Ok(Self {
-            peer_connections: Arc::new(Mutex::new(Vec::new())),
-            message_broadcaster: Arc::new(Mutex::new(tx)),
-            sync_status: Arc::new(RwLock::new(SyncStatus {
-                is_syncing: false,
-                current_height: 0,
-                target_height: 0,
-                sync_progress: 0.0,
-            })),
-            known_peers: Arc::new(RwLock::new(HashSet::new())),
+    /// ## The Tale Takes Another Branch
+    /// 
+    /// Adopts a competing branch that carries more work than our chain. The
+    /// branch's chapters come in order and may begin with chapters we
+    /// already share; the rest must join our chain at a common ancestor,
+    /// which may be genesis but never replace it. Only the work past the
+    /// ancestor is weighed, so a longer branch of easier chapters loses to
+    /// a shorter, harder one. Our chapters past the ancestor are unwound
+    /// newest first, and each branch chapter must pass the same trials as a
+    /// chapter arriving from a peer before it is applied. The orphaned
+    /// stories that still validate return to the mempool. A branch that
+    /// fails any trial leaves our own chain as it was.
+    pub async fn reorganize_to(&mut self, mut new_chain: Vec<BlockChapter>) -> Result<ReorganizationReport, ChronicleError> {
+        if new_chain.is_empty() {
+            return Err(ChronicleError::ReorganizationRejected("The branch holds no chapters".to_string()));
+        }
+        if let Some(gap) = new_chain.windows(2).find(|pair| {
+            pair[1].chapter_number != pair[0].chapter_number + 1
+                || pair[1].previous_chapter_essence != pair[0].chapter_essence
+        }) {
+            return Err(ChronicleError::ReorganizationRejected(format!(
+                "The branch breaks between chapters {} and {}",
+                gap[0].chapter_number, gap[1].chapter_number
+            )));
+        }
+
+        // Pass over the chapters both chains already tell
+        let mut shared = 0;
+        while let Some(chapter) = new_chain.get(shared) {
+            match self.chain_repository.load_chapter(chapter.chapter_number)? {
+                Some(ours) if ours.chapter_essence == chapter.chapter_essence => shared += 1,
+                _ => break,
+            }
+        }
+        let new_branch = new_chain.split_off(shared);
+        let Some(fork) = new_branch.first() else {
+            return Err(ChronicleError::ReorganizationRejected(
+                "The branch tells only chapters our chain already holds".to_string(),
+            ));
+        };
+        if fork.chapter_number == 0 {
+            return Err(ChronicleError::ReorganizationRejected(
+                "The branch begins from another genesis chapter".to_string(),
+            ));
+        }
+        let common_ancestor = fork.chapter_number - 1;
+        let joins_our_chain = self.chain_repository.load_chapter(common_ancestor)?
+            .is_some_and(|ancestor| ancestor.chapter_essence == fork.previous_chapter_essence);
+        if !joins_our_chain {
+            return Err(ChronicleError::ReorganizationRejected(format!(
+                "The branch does not join our chain at chapter {}",
+                common_ancestor
+            )));
+        }
+
+        let orphaned = self.chain_repository.chapters_after(common_ancestor)?;
+        let (branch_work, our_work) = (Self::work_of(&new_branch)?, Self::work_of(&orphaned)?);
+        if branch_work <= our_work {
+            return Err(ChronicleError::ReorganizationRejected(format!(
+                "The branch carries {} work past chapter {} but our chain already carries {}",
+                branch_work, common_ancestor, our_work
+            )));
+        }
+
+        // The orphaned stories are forgotten as they are unwound, so a branch
+        // telling the same stories is not refused them as duplicates
+        self.unwind_chapters_to(common_ancestor, &orphaned).await?;
+        Self::forget_stories_of(&self.chain_repository, &orphaned)?;
+        if let Err(plot_twist) = self.wind_branch(&new_branch).await {
+            let half_told = self.chain_repository.chapters_after(common_ancestor)?;
+            self.unwind_chapters_to(common_ancestor, &half_told).await?;
+            Self::forget_stories_of(&self.chain_repository, &half_told)?;
+            self.wind_chapters(&orphaned).await?;
+            return Err(plot_twist);
+        }
+
+        let branch_story_ids: HashSet<&str> = new_branch.iter()
+            .flat_map(|chapter| chapter.transaction_tales.iter())
+            .map(|story| story.story_id.as_str())
+            .collect();
+        self.mempool_of_pending_tales.lock().unwrap()
+            .retain(|story| !branch_story_ids.contains(story.story_id.as_str()));
+
+        let orphaned_stories: Vec<TransactionStory> = orphaned.iter()
+            .flat_map(|chapter| chapter.transaction_tales.iter())
+            .filter(|story| !branch_story_ids.contains(story.story_id.as_str()))
+            .cloned()
+            .collect();
+        let mut returned_to_mempool = Vec::new();
+        for story in orphaned_stories {
+            let story_id = story.story_id.clone();
+            if self.transaction_story_arrives(story).await.is_ok() {
+                returned_to_mempool.push(story_id);
+            }
+        }
+
+        println!("🔀 Chain reorganized past chapter {}: {} chapters orphaned, {} adopted",
+            common_ancestor, orphaned.len(), new_branch.len());
+        self.announce(ChainEvent::ReorgHappened { depth: orphaned.len() as u64 });
+        for chapter in &new_branch {
+            self.announce(ChainEvent::BlockAdded(chapter.clone()));
+        }
+        Ok(ReorganizationReport {
+            common_ancestor,
+            orphaned_chapters: orphaned.len(),
+            adopted_chapters: new_branch.len(),
+            returned_to_mempool,
         })
     }
-    
-    async fn start_peer_discovery(&self) -> Result<(), ChronicleError> {
-        // Implementation for peer discovery
-        // In a real system, this would connect to bootstrap nodes
-        println!("🔍 Starting peer discovery...");
+
+    /// A chapter committing a state root must commit the one replaying it
+    /// onto our ledger gives; a chapter committing none is not held to one
+    async fn chapter_replays_to_its_state_root(&self, block: &BlockChapter) -> Result<(), ChronicleError> {
+        let Some(committed_root) = &block.utxo_state_root else {
+            return Ok(());
+        };
+        let replayed_root = self.utxo_ledger.state_root_after(block).await?;
+        if *committed_root != replayed_root {
+            return Err(ChronicleError::InvalidChapter(format!(
+                "Chapter {} commits state root {} but replays to {}",
+                block.chapter_number, committed_root, replayed_root
+            )));
+        }
         Ok(())
     }
-    
-    async fn begin_chain_synchronization(&self) -> Result<(), ChronicleError> {
-        // Implementation for chain sync
-        println!("🔄 Beginning chain synchronization...");
-        Ok(())
+
+    /// Takes `chapters`, the stored chapters above `height`, back out of the
+    /// ledger newest first and then out of the chain
+    async fn unwind_chapters_to(&self, height: u64, chapters: &[BlockChapter]) -> Result<(), ChronicleError> {
+        for chapter in chapters.iter().rev() {
+            self.utxo_ledger.revert_block(chapter, &self.chain_repository).await?;
+        }
+        self.chain_repository.rewind_to(height).await
     }
-    
-    async fn broadcast_transaction_story(&self, _story: TransactionStory) -> Result<(), ChronicleError> {
-        // Implementation for broadcasting transactions
-        println!("📡 Broadcasting transaction to network...");
+
+    /// Puts back chapters our chain had already accepted, as after a branch
+    /// that failed its trials
+    async fn wind_chapters(&self, chapters: &[BlockChapter]) -> Result<(), ChronicleError> {
+        for chapter in chapters {
+            self.chapter_replays_to_its_state_root(chapter).await?;
+            Self::commit_chapter(&self.utxo_ledger, &self.chain_repository, chapter).await?;
+        }
         Ok(())
     }
-    
-    async fn get_peer_count(&self) -> usize {
-        self.peer_connections.lock().unwrap().len()
-    }
-    
-    async fn get_sync_status(&self) -> SyncStatus {
-        self.sync_status.read().unwrap().clone()
-    }
-}
 
-impl MiningHeart {
-    fn new(reward_address: Vec<u8>, _target_block_time: Duration) -> Self {
-        Self {
-            is_beating: Arc::new(Mutex::new(true)),
-            current_difficulty: Arc::new(RwLock::new(0x0FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF)),
-            hash_rate: Arc::new(RwLock::new(0.0)),
-            mining_reward_address: reward_address,
-            thread_handles: Vec::new(),
+    /// Applies a competing branch chapter by chapter, each first earning
+    /// its place on the chain as the chapters before it left it
+    async fn wind_branch(&self, chapters: &[BlockChapter]) -> Result<(), ChronicleError> {
+        for chapter in chapters {
+            self.chapter_earns_its_place(chapter).await?;
+            Self::commit_chapter(&self.utxo_ledger, &self.chain_repository, chapter).await?;
         }
+        Ok(())
     }
-}
 
-impl ValidatorCouncil {
-    fn new() -> Self {
-        let mut council_members = HashMap::new();
-        let keypair = Keypair::generate(&mut OsRng);
+    /// Applies a chapter to the ledger and writes it to the chain as one
+    /// step. Should the chain refuse the write, the ledger and whatever the
+    /// chain had already written are put back, so the two never disagree
+    /// about which chapters were told.
+    async fn commit_chapter(utxo_ledger: &UTXOLedger, chain_repo: &ChainRepository, block: &BlockChapter) -> Result<(), ChronicleError> {
+        utxo_ledger.apply_block(block).await?;
+        let Err(plot_twist) = chain_repo.add_block_chapter(block.clone()).await else {
+            return Ok(());
+        };
+
+        let undone = async {
+            utxo_ledger.revert_block(block, chain_repo).await?;
+            chain_repo.rewind_to(block.chapter_number.saturating_sub(1)).await?;
+            Self::forget_stories_of(chain_repo, std::slice::from_ref(block))
+        };
+        if let Err(undo_failure) = undone.await {
+            eprintln!("Could not undo chapter {} after a failed write: {:?}", block.chapter_number, undo_failure);
+        }
+        Err(plot_twist)
+    }
+
+    fn forget_stories_of(chain_repo: &ChainRepository, chapters: &[BlockChapter]) -> Result<(), ChronicleError> {
+        for story in chapters.iter().flat_map(|chapter| chapter.transaction_tales.iter()) {
+            chain_repo.forget_story(&story.story_id)?;
+        }
+        Ok(())
+    }
+
+    /// The work the chapters' targets demanded between them, so chains are
+    /// weighed by the effort behind them rather than by their length
+    fn work_of(chapters: &[BlockChapter]) -> Result<u128, ChronicleError> {
+        chapters.iter().try_fold(0u128, |total, chapter| {
+            let work = chapter.proof_of_storytelling.difficulty_bits.to_target()?.difficulty();
+            Ok(total.saturating_add(work as u128))
+        })
+    }
+
+    pub async fn create_transaction(
+        &self,
+        from_keypair: &Keypair,
+        to_address: &[u8],
+        amount: u64,
+        fee: u64,
+    ) -> Result<TransactionStory, ChronicleError> {
+        let unsigned = self.build_unsigned_transaction(
+            &from_keypair.public.to_bytes(),
+            to_address,
+            amount,
+            fee,
+        ).await?;
+        let mut transaction = unsigned.story;
         
-        council_members.insert("validator_1".to_string(), ValidatorGuardian {
-            guardian_id: "validator_1".to_string(),
-            stake_in_truth: 1000,
-            reputation_score: 1.0,
-            tales_validated: 0,
-            public_key: keypair.public,
-            last_validation_time: current_timestamp(),
-        });
+        // Sign the transaction
+        self.narrator_signs_the_story(&mut transaction, from_keypair);
+        
+        Ok(transaction)
+    }
 
-        Self {
-            council_members,
-            consensus_threshold: 0.67,
-            current_storyteller: Some("validator_1".to_string()),
-            reputation_system: ReputationSystem::new(),
+    /// ## A Story Written Now, Signed Elsewhere
+    /// 
+    /// Assembles a transaction for a narrator whose key never touches this
+    /// node (hardware wallets, air-gapped machines), returning it unsigned
+    /// alongside the exact message the narrator must sign.
+    pub async fn build_unsigned_transaction(
+        &self,
+        from_public_key: &[u8],
+        to_address: &[u8],
+        amount: u64,
+        fee: u64,
+    ) -> Result<UnsignedTransactionStory, ChronicleError> {
+        PublicKey::from_bytes(from_public_key)
+            .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
+
+        // Find UTXOs for sender
+        let sender_address = from_public_key;
+        let utxos = self.utxo_ledger.find_utxos_for_address(sender_address).await?;
+        
+        // Select UTXOs to cover amount + fee
+        let mut selected_utxos = Vec::new();
+        let mut total_input = 0u64;
+        
+        for (utxo_ref, utxo) in utxos {
+            total_input += utxo.value_locked;
+            selected_utxos.push((utxo_ref, utxo));
+            
+            if total_input >= amount + fee {
+                break;
+            }
+        }
+        
+        if total_input < amount + fee {
+            return Err(ChronicleError::InsufficientFunds { 
+                required: amount + fee, 
+                available: total_input 
+            });
+        }
+        
+        // Create outputs
+        let mut outputs = vec![
+            UTXOOutput {
+                recipient_address: to_address.to_vec(),
+                value_locked: amount,
+                locking_script: ScriptOfTruth {
+                    script_type: ScriptType::PayToPublicKey,
+                    required_signatures: 1,
+                    public_keys: vec![to_address.to_vec()],
+                },
+            }
+        ];
+        
+        // Add change output if necessary
+        let change = total_input - amount - fee;
+        if change > 0 {
+            outputs.push(UTXOOutput {
+                recipient_address: sender_address.to_vec(),
+                value_locked: change,
+                locking_script: ScriptOfTruth {
+                    script_type: ScriptType::PayToPublicKey,
+                    required_signatures: 1,
+                    public_keys: vec![sender_address.to_vec()],
+                },
+            });
         }
+        
+        // Create transaction
+        let transaction = TransactionStory {
+            version: CURRENT_STORY_VERSION,
+            story_id: generate_transaction_id(),
+            inputs_consumed: selected_utxos.into_iter().map(|(utxo_ref, _)| utxo_ref).collect(),
+            outputs_created: outputs,
+            story_fee: fee,
+            timestamp_of_telling: current_timestamp(),
+            transaction_nonce: self.next_nonce_for(sender_address)?,
+            digital_signature: Vec::new(), // Will be filled after signing
+            public_key_of_narrator: sender_address.to_vec(),
+            fee_sponsorship: None,
+            input_witnesses: Vec::new(),
+            coinbase_height: None,
+        };
+        
+        let signable_message = self.create_signable_message(&transaction);
+        Ok(UnsignedTransactionStory {
+            story: transaction,
+            signable_message,
+        })
     }
-}
 
-impl ReputationSystem {
-    fn new() -> Self {
-        Self {
-            validator_scores: HashMap::new(),
-            penalty_system: PenaltyTracker {
-                recent_penalties: HashMap::new(),
-                cumulative_penalties: HashMap::new(),
-            },
-            reward_multipliers: HashMap::new(),
+    /// ## The Distant Signature Comes Home
+    /// 
+    /// Completes a story built by `build_unsigned_transaction` with a
+    /// signature produced out of process, refusing signatures that do not
+    /// match the story or come from a different narrator.
+    pub fn attach_signature(
+        &self,
+        unsigned: UnsignedTransactionStory,
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<TransactionStory, ChronicleError> {
+        let mut story = unsigned.story;
+        if story.public_key_of_narrator != public_key {
+            return Err(ChronicleError::InvalidPublicKey(
+                "Signer is not the narrator this story was built for".to_string()
+            ));
         }
+
+        let narrator_key = PublicKey::from_bytes(public_key)
+            .map_err(|e| ChronicleError::InvalidPublicKey(e.to_string()))?;
+        let narrator_signature = Signature::from_bytes(signature)
+            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+
+        // Verify against the story itself, not the message we were handed back
+        let message = self.create_signable_message(&story);
+        narrator_key.verify(&message, &narrator_signature)
+            .map_err(|_| ChronicleError::StoryBearsFalseWitness(
+                "Attached signature does not cover this story".to_string()
+            ))?;
+
+        story.digital_signature = signature.to_vec();
+        Ok(story)
     }
-}
 
-impl Default for ChronicleConfiguration {
-    fn default() -> Self {
-        Self {
-            target_block_time: Duration::from_secs(600), // 10 minutes
-            difficulty_adjustment_interval: 2016, // ~2 weeks
-            max_block_size: 1_048_576, // 1MB
-            min_transaction_fee: 1000, // 0.00001 units
-            base_mining_reward: 5_000_000_000, // 50 units
-            reward_halving_interval: 210_000,
-            max_peers: 50,
-            network_port: 8333,
-            data_directory: "./blockchain_data".to_string(),
+    /// ## A Patron Steps Forward
+    /// 
+    /// Covers `fee` for a story signed by its narrator, drawing on the
+    /// sponsor's own unspent outputs and signing over the whole story.
+    pub async fn sponsor_story_fee(
+        &self,
+        mut story: TransactionStory,
+        sponsor_keypair: &Keypair,
+        fee: u64,
+    ) -> Result<TransactionStory, ChronicleError> {
+        let sponsor_address = sponsor_keypair.public.to_bytes().to_vec();
+        let mut sponsor_inputs = Vec::new();
+        let mut sponsor_value = 0u64;
+
+        for (utxo_ref, utxo) in self.utxo_ledger.find_utxos_for_address(&sponsor_address).await? {
+            if story.inputs_consumed.contains(&utxo_ref) {
+                continue;
+            }
+            sponsor_value = sponsor_value.checked_add(utxo.value_locked).ok_or(ChronicleError::ValueOverflow)?;
+            sponsor_inputs.push(utxo_ref);
+            if sponsor_value >= fee {
+                break;
+            }
+        }
+
+        if sponsor_value < fee {
+            return Err(ChronicleError::InsufficientFunds { required: fee, available: sponsor_value });
+        }
+        // The narrator signed for the fee it pays; a patron cannot take it over
+        if story.story_fee > 0 {
+            return Err(ChronicleError::ReplacementRejected(
+                "The narrator already pays this story's fee".to_string()
+            ));
         }
+
+        let sponsor_change = (sponsor_value > fee).then(|| UTXOOutput {
+            recipient_address: sponsor_address.clone(),
+            value_locked: sponsor_value - fee,
+            locking_script: ScriptOfTruth {
+                script_type: ScriptType::PayToPublicKey,
+                required_signatures: 1,
+                public_keys: vec![sponsor_address.clone()],
+            },
+        });
+
+        story.story_fee = fee;
+        let mut sponsorship = FeeSponsorship {
+            sponsor_public_key: sponsor_address,
+            sponsor_inputs,
+            sponsor_change,
+            sponsor_signature: Vec::new(),
+        };
+        // Signed as the story will be told, with the patron already at its side
+        story.fee_sponsorship = Some(sponsorship.clone());
+        let message = self.create_sponsor_message(&story, &sponsorship);
+        sponsorship.sponsor_signature = sponsor_keypair.sign(&message).to_bytes().to_vec();
+        story.fee_sponsorship = Some(sponsorship);
+
+        Ok(story)
     }
-}
 
-/// ## The Current State of Our Chronicle
-#[derive(Debug)]
-pub struct ChronicleState {
-    pub total_chapters: u64,
-    pub pending_stories: usize,
-    pub latest_chapter_essence: String,
-    pub chain_integrity: bool,
-    pub network_peers: usize,
-    pub sync_status: SyncStatus,
-}
+    /// ## The Stuck Story Pays Its Way Forward
+    /// 
+    /// Rebuilds a pending story spending the same inputs, takes the extra fee
+    /// out of the narrator's change, signs it anew and submits it as a replacement.
+    pub async fn bump_fee(
+        &mut self,
+        story_id: &str,
+        from_keypair: &Keypair,
+        additional_fee: u64,
+    ) -> Result<TransactionStory, ChronicleError> {
+        let original = self.mempool_of_pending_tales.lock().unwrap()
+            .iter()
+            .find(|pending| pending.story_id == story_id)
+            .cloned()
+            .ok_or_else(|| ChronicleError::StoryNotInMempool(story_id.to_string()))?;
 
-/// ## Plot Twists: When Things Go Wrong
-#[derive(Debug)]
-pub enum ChronicleError {
-    StoryBearsFalseWitness(String),
-    NarratorLacksResources(String),
-    ChronicleCorrupted(String),
-    ProofOfWorkFailed(String),
-    ConsensusNotReached(String),
-    DatabaseError(String),
-    SerializationError(String),
-    NetworkError(String),
-    InvalidPublicKey(String),
-    InvalidSignature(String),
-    UTXONotFound(String),
-    DuplicateStory(String),
-    InvalidNonce(u64),
-    InsufficientFee(u64),
-    ValueOverflow,
-    InsufficientFunds { required: u64, available: u64 },
-}
+        let sender_address = from_keypair.public.to_bytes().to_vec();
+        if original.public_key_of_narrator != sender_address {
+            return Err(ChronicleError::StoryBearsFalseWitness(
+                "Only the original narrator may bump a story's fee".to_string()
+            ));
+        }
+        if original.fee_sponsorship.is_some() {
+            return Err(ChronicleError::ReplacementRejected(
+                "A sponsored story's fee belongs to its sponsor".to_string()
+            ));
+        }
 
-impl ChronicleError {
-    pub fn resolve_the_conflict(self) -> StoryResolution {
-        match self {
-            ChronicleError::StoryBearsFalseWitness(_) => StoryResolution::RejectTheStory,
-            ChronicleError::NarratorLacksResources(_) => StoryResolution::ReturnToSender,
-            ChronicleError::ChronicleCorrupted(_) => StoryResolution::ResyncWithNetwork,
-            ChronicleError::ProofOfWorkFailed(_) => StoryResolution::AdjustDifficulty,
-            ChronicleError::ConsensusNotReached(_) => StoryResolution::SeekMoreValidators,
-            ChronicleError::DatabaseError(_) => StoryResolution::RepairDatabase,
-            ChronicleError::NetworkError(_) => StoryResolution::ReconnectToNetwork,
-            ChronicleError::InvalidSignature(_) => StoryResolution::RejectTheStory,
-            ChronicleError::UTXONotFound(_) => StoryResolution::RejectTheStory,
-            ChronicleError::InsufficientFunds { .. } => StoryResolution::ReturnToSender,
-            _ => StoryResolution::LogAndContinue,
+        let mut outputs = original.outputs_created.clone();
+        let change_index = outputs.iter()
+            .position(|output| output.recipient_address == sender_address)
+            .ok_or(ChronicleError::InsufficientFunds { required: additional_fee, available: 0 })?;
+
+        let available_change = outputs[change_index].value_locked;
+        if available_change < additional_fee {
+            return Err(ChronicleError::InsufficientFunds {
+                required: additional_fee,
+                available: available_change,
+            });
+        }
+
+        if available_change == additional_fee {
+            outputs.remove(change_index);
+        } else {
+            outputs[change_index].value_locked = available_change - additional_fee;
+        }
+
+        let mut replacement = TransactionStory {
+            version: original.version,
+            story_id: generate_transaction_id(),
+            inputs_consumed: original.inputs_consumed.clone(),
+            outputs_created: outputs,
+            story_fee: original.story_fee.checked_add(additional_fee)
+                .ok_or(ChronicleError::ValueOverflow)?,
+            timestamp_of_telling: current_timestamp(),
+            // The replacement takes the original's place, nonce and all
+            transaction_nonce: original.transaction_nonce,
+            digital_signature: Vec::new(),
+            public_key_of_narrator: sender_address,
+            fee_sponsorship: None,
+            input_witnesses: Vec::new(),
+            coinbase_height: None,
+        };
+        self.narrator_signs_the_story(&mut replacement, from_keypair);
+
+        self.transaction_story_arrives(replacement.clone()).await?;
+        let was_watched = self.own_unconfirmed_stories.lock().unwrap().remove(story_id).is_some();
+        if was_watched {
+            self.watch_for_rebroadcast(replacement.clone());
         }
+        Ok(replacement)
     }
-}
 
-pub enum StoryResolution {
-    RejectTheStory,
-    ReturnToSender,
-    ResyncWithNetwork,
-    AdjustDifficulty,
-    SeekMoreValidators,
-    RepairDatabase,
-    ReconnectToNetwork,
-    LogAndContinue,
+    fn narrator_signs_the_story(&self, story: &mut TransactionStory, narrator: &Keypair) {
+        let message = self.create_signable_message(story);
+        let signature = narrator.sign(&message);
+        story.digital_signature = signature.to_bytes().to_vec();
+    }
 }
 
-// Utility functions
-fn current_timestamp() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
+/// ## One Story, One Encoding
+/// 
+/// Hashes and signatures are only meaningful if the same story always
+/// becomes the same bytes. Every type reachable from a story or chapter is
+/// made of fields encoded in declaration order, sequences and options - no
+/// `HashMap` or `HashSet`, whose iteration order is not stable. Keep it so:
+/// any map added to these types must be a `BTreeMap`.
+impl TransactionStory {
+    pub fn is_coinbase(&self) -> bool {
+        self.coinbase_height.is_some()
+    }
+
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>, ChronicleError> {
+        bincode::serialize(self).map_err(|e| ChronicleError::SerializationError(e.to_string()))
+    }
 }
 
-fn generate_transaction_id() -> String {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    let random_bytes: [u8; 16] = rng.gen();
-    hex::encode(random_bytes)
+impl BlockChapter {
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>, ChronicleError> {
+        bincode::serialize(self).map_err(|e| ChronicleError::SerializationError(e.to_string()))
+    }
 }
 
-fn generate_nonce() -> u64 {
-    use rand::Rng;
-    rand::thread_rng().gen()
+/// ## Implementation Details for Supporting Structures
+
+impl ChainRepository {
+    async fn new(data_dir: &str) -> Result<Self, ChronicleError> {
+        std::fs::create_dir_all(data_dir).map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        
+        let block_db = sled::open(format!("{}/blocks", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let tx_db = sled::open(format!("{}/transactions", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let utxo_db = sled::open(format!("{}/utxos", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let sync_db = sled::open(format!("{}/sync", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let nonce_db = sled::open(format!("{}/nonces", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let mempool_db = sled::open(format!("{}/mempool", data_dir))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let header_tree = block_db.open_tree("headers")
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let essence_tree = block_db.open_tree("essences")
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        
+        let repository = Self {
+            block_db,
+            tx_db,
+            utxo_db,
+            sync_db,
+            nonce_db,
+            mempool_db,
+            header_tree,
+            essence_tree,
+            chain_tip: Arc::new(RwLock::new(None)),
+            block_index: Arc::new(RwLock::new(HashMap::new())),
+            sync_served_from: Arc::new(RwLock::new(HashMap::new())),
+        };
+
+        // A chain written before headers were kept gets them now
+        if repository.header_tree.is_empty() {
+            for result in repository.block_db.iter() {
+                let (_, block_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+                let block: BlockChapter = bincode::deserialize(&block_data)
+                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+                repository.store_header(&block.header())?;
+            }
+        }
+        // Likewise a chain whose headers were kept before they were indexed
+        if repository.essence_tree.is_empty() {
+            let headers: Vec<BlockHeader> = repository.headers().collect::<Result<_, _>>()?;
+            for header in &headers {
+                repository.store_header(header)?;
+            }
+        }
+
+        Ok(repository)
+    }
+
+    fn store_header(&self, header: &BlockHeader) -> Result<(), ChronicleError> {
+        let header_data = bincode::serialize(header)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        self.header_tree.insert(format!("header_{:010}", header.chapter_number), header_data)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.essence_tree.insert(header.chapter_essence.as_bytes(), header.chapter_number.to_be_bytes().to_vec())
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// The header of the chapter at this height, kept even once its body is pruned
+    fn load_header(&self, chapter_number: u64) -> Result<Option<BlockHeader>, ChronicleError> {
+        match self.header_tree.get(format!("header_{:010}", chapter_number))
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(header_data) => Ok(Some(bincode::deserialize(&header_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// ## The Chain's Sense of Now
+    /// 
+    /// The median timestamp of the last `MEDIAN_TIME_SPAN` chapters up to
+    /// the tip. A single miner's clock cannot move it, so a new chapter must
+    /// be dated after it.
+    pub fn median_time_past(&self) -> Result<u64, ChronicleError> {
+        match self.header_tree.last().map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some((_, header_data)) => {
+                let tip: BlockHeader = bincode::deserialize(&header_data)
+                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+                self.median_time_past_at(tip.chapter_number)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// The median timestamp of the `MEDIAN_TIME_SPAN` chapters ending at
+    /// `height`, or of as many as the chain has
+    fn median_time_past_at(&self, height: u64) -> Result<u64, ChronicleError> {
+        let window_start = height.saturating_sub(MEDIAN_TIME_SPAN - 1);
+        let mut timestamps = Vec::new();
+        for chapter_number in window_start..=height {
+            let header = self.load_header(chapter_number)?
+                .ok_or(ChronicleError::ChapterNotFound(chapter_number))?;
+            timestamps.push(header.timestamp_of_creation);
+        }
+        Ok(median_timestamp(&timestamps))
+    }
+
+    fn headers(&self) -> impl Iterator<Item = Result<BlockHeader, ChronicleError>> + '_ {
+        self.header_tree.iter().map(|result| {
+            let (_, header_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            bincode::deserialize(&header_data).map_err(|e| ChronicleError::SerializationError(e.to_string()))
+        })
+    }
+
+    /// ## Letting the Oldest Chapters Go
+    /// 
+    /// Deletes the bodies of every chapter below `height`, keeping their
+    /// headers so the chain can still be verified. The UTXO set, the
+    /// committed stories and the tip are left alone. Refused while any peer
+    /// syncing from us has yet to fetch chapters below `height`.
+    pub fn prune_below(&self, height: u64) -> Result<usize, ChronicleError> {
+        if let Some(served_from) = self.lowest_height_still_served() {
+            if served_from < height {
+                return Err(ChronicleError::PruningRefused(format!(
+                    "A peer syncing from us still needs chapters from {}", served_from
+                )));
+            }
+        }
+        // The tip is never pruned, whatever the height asked for
+        let tip_key = self.block_db.last()
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?
+            .map(|(block_key, _)| block_key);
+
+        let mut batch = sled::Batch::default();
+        let mut pruned = 0;
+        for result in self.block_db.range(..format!("block_{:010}", height)) {
+            let (block_key, _) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            if Some(&block_key) != tip_key.as_ref() {
+                batch.remove(block_key);
+                pruned += 1;
+            }
+        }
+        self.block_db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.block_db.flush()
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(pruned)
+    }
+
+    /// The lowest height any peer syncing from us will ask for next
+    fn lowest_height_still_served(&self) -> Option<u64> {
+        self.sync_served_from.read().unwrap().values().min().copied()
+    }
+
+    /// Remembers how far the peer at `peer_address` has come syncing from
+    /// us: a full batch means it will ask again from where the batch ended,
+    /// a short one that it has caught up and need not be waited for
+    fn note_sync_served(&self, peer_address: &str, from_height: u64, chapters_served: u64) {
+        let mut served_from = self.sync_served_from.write().unwrap();
+        if chapters_served == SYNC_BATCH_CHAPTERS {
+            served_from.insert(peer_address.to_string(), from_height + chapters_served);
+        } else {
+            served_from.remove(peer_address);
+        }
+    }
+    
+    async fn chain_is_empty(&self) -> Result<bool, ChronicleError> {
+        Ok(self.block_db.is_empty())
+    }
+    
+    async fn add_block_chapter(&self, block: BlockChapter) -> Result<(), ChronicleError> {
+        // Serialize and store block
+        let block_data = bincode::serialize(&block)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        
+        let block_key = format!("block_{:010}", block.chapter_number);
+        self.block_db.insert(&block_key, block_data)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.store_header(&block.header())?;
+        
+        // Store transactions
+        for tx in &block.transaction_tales {
+            let tx_data = bincode::serialize(tx)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            self.tx_db.insert(&tx.story_id, tx_data)
+                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        }
+        self.record_committed_nonces(&block)?;
+        
+        // Update chain tip
+        {
+            let mut tip = self.chain_tip.write().unwrap();
+            *tip = Some(block.clone());
+        }
+        
+        // Update block index
+        {
+            let mut index = self.block_index.write().unwrap();
+            index.insert(block.chapter_essence.clone(), block.chapter_number);
+        }
+        
+        // Flush to disk
+        self.block_db.flush_async().await
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    fn knows_chapter(&self, chapter_essence: &str) -> bool {
+        matches!(self.chapter_number_of(chapter_essence), Ok(Some(_)))
+    }
+
+    /// The height of the chapter with this essence. The in-memory index is
+    /// only a cache of the essence tree on disk, so a restarted node still
+    /// finds its chapters, and an essence it has never stored is a single
+    /// lookup away from `None`.
+    fn chapter_number_of(&self, chapter_essence: &str) -> Result<Option<u64>, ChronicleError> {
+        if let Some(chapter_number) = self.block_index.read().unwrap().get(chapter_essence) {
+            return Ok(Some(*chapter_number));
+        }
+
+        let Some(height_data) = self.essence_tree.get(chapter_essence.as_bytes())
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? else {
+            return Ok(None);
+        };
+        let height_bytes: [u8; 8] = height_data.as_ref().try_into()
+            .map_err(|_| ChronicleError::SerializationError(format!("Garbled height for chapter {}", chapter_essence)))?;
+        let chapter_number = u64::from_be_bytes(height_bytes);
+        self.block_index.write().unwrap().insert(chapter_essence.to_string(), chapter_number);
+        Ok(Some(chapter_number))
+    }
+
+    async fn get_chain_tip(&self) -> Result<Option<BlockChapter>, ChronicleError> {
+        let tip = self.chain_tip.read().unwrap().clone();
+        
+        if tip.is_none() {
+            // Load from database
+            if let Some((_, block_data)) = self.block_db.last()
+                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+                let block: BlockChapter = bincode::deserialize(&block_data)
+                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+                return Ok(Some(block));
+            }
+        }
+        
+        Ok(tip)
+    }
+    
+    fn save_sync_status(&self, status: &SyncStatus) -> Result<(), ChronicleError> {
+        let status_data = bincode::serialize(status)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        self.sync_db.insert(SYNC_STATUS_KEY, status_data)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.sync_db.flush()
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+    
+    fn load_sync_status(&self) -> Result<Option<SyncStatus>, ChronicleError> {
+        match self.sync_db.get(SYNC_STATUS_KEY).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(status_data) => Ok(Some(bincode::deserialize(&status_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+    
+    fn load_chapter(&self, chapter_number: u64) -> Result<Option<BlockChapter>, ChronicleError> {
+        let block_key = format!("block_{:010}", chapter_number);
+        match self.block_db.get(&block_key).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(block_data) => Ok(Some(bincode::deserialize(&block_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+
+    fn load_story(&self, tx_id: &str) -> Result<Option<TransactionStory>, ChronicleError> {
+        match self.tx_db.get(tx_id).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(tx_data) => Ok(Some(bincode::deserialize(&tx_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+    
+    /// The output a reference points at, recovered from the story that
+    /// created it whether or not it has since been spent
+    fn load_created_output(&self, reference: &UTXOReference) -> Result<Option<UTXOOutput>, ChronicleError> {
+        Ok(self.load_story(&reference.previous_story_id)?
+            .and_then(|source| story_creates(&source)
+                .nth(reference.output_index as usize)
+                .map(|(_, output)| output.clone())))
+    }
+
+    fn forget_story(&self, tx_id: &str) -> Result<(), ChronicleError> {
+        self.tx_db.remove(tx_id)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Every stored chapter above `height`, in order
+    fn chapters_after(&self, height: u64) -> Result<Vec<BlockChapter>, ChronicleError> {
+        let mut chapters = Vec::new();
+        while let Some(chapter) = self.load_chapter(height + 1 + chapters.len() as u64)? {
+            chapters.push(chapter);
+        }
+        Ok(chapters)
+    }
+
+    /// At most `limit` stored chapters, in order, starting at `height`
+    fn chapters_from(&self, height: u64, limit: u64) -> Result<Vec<BlockChapter>, ChronicleError> {
+        let mut chapters = Vec::new();
+        for chapter_number in height..height.saturating_add(limit) {
+            match self.load_chapter(chapter_number)? {
+                Some(chapter) => chapters.push(chapter),
+                None => break,
+            }
+        }
+        Ok(chapters)
+    }
+
+    /// The highest nonce of any committed story told by this narrator; zero
+    /// if none has been committed
+    fn highest_committed_nonce(&self, narrator: &[u8]) -> Result<u64, ChronicleError> {
+        match self.nonce_db.get(nonce_key(narrator)).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(nonce_data) => Ok(bincode::deserialize(&nonce_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?),
+            None => Ok(0),
+        }
+    }
+
+    /// Raises each narrator's committed nonce to the highest in the chapter,
+    /// remembering the values replaced so the chapter can be unwound
+    fn record_committed_nonces(&self, block: &BlockChapter) -> Result<(), ChronicleError> {
+        let mut raised: HashMap<&[u8], u64> = HashMap::new();
+        let mut replaced: Vec<(Vec<u8>, u64)> = Vec::new();
+        for story in block.transaction_tales.iter().filter(|story| !story.is_coinbase()) {
+            let narrator = story.public_key_of_narrator.as_slice();
+            let highest = match raised.get(narrator) {
+                Some(&highest) => highest,
+                None => {
+                    let committed = self.highest_committed_nonce(narrator)?;
+                    replaced.push((narrator.to_vec(), committed));
+                    committed
+                }
+            };
+            raised.insert(narrator, highest.max(story.transaction_nonce));
+        }
+
+        let mut batch = sled::Batch::default();
+        for (narrator, nonce) in raised {
+            let nonce_data = bincode::serialize(&nonce)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            batch.insert(nonce_key(narrator).as_bytes(), nonce_data);
+        }
+        let undo_data = bincode::serialize(&replaced)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        batch.insert(format!("undo_{:010}", block.chapter_number).as_bytes(), undo_data);
+        self.nonce_db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Puts back the nonces a chapter replaced when it was committed
+    fn restore_nonces_before(&self, chapter_number: u64) -> Result<(), ChronicleError> {
+        let undo_key = format!("undo_{:010}", chapter_number);
+        let Some(undo_data) = self.nonce_db.get(&undo_key).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? else {
+            return Ok(());
+        };
+        let replaced: Vec<(Vec<u8>, u64)> = bincode::deserialize(&undo_data)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+
+        let mut batch = sled::Batch::default();
+        for (narrator, nonce) in replaced {
+            if nonce == 0 {
+                batch.remove(nonce_key(&narrator).as_bytes());
+            } else {
+                let nonce_data = bincode::serialize(&nonce)
+                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+                batch.insert(nonce_key(&narrator).as_bytes(), nonce_data);
+            }
+        }
+        batch.remove(undo_key.as_bytes());
+        self.nonce_db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Forgets every chapter above `height`, leaving that chapter as the tip
+    async fn rewind_to(&self, height: u64) -> Result<(), ChronicleError> {
+        let new_tip = self.load_chapter(height)?
+            .ok_or(ChronicleError::ChapterNotFound(height))?;
+        for chapter in self.chapters_after(height)?.into_iter().rev() {
+            self.restore_nonces_before(chapter.chapter_number)?;
+            self.block_db.remove(format!("block_{:010}", chapter.chapter_number))
+                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            self.header_tree.remove(format!("header_{:010}", chapter.chapter_number))
+                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            self.essence_tree.remove(chapter.chapter_essence.as_bytes())
+                .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            self.block_index.write().unwrap().remove(&chapter.chapter_essence);
+        }
+        *self.chain_tip.write().unwrap() = Some(new_tip);
+
+        self.block_db.flush_async().await
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+    
+    /// Writes down the stories that entered the mempool and forgets those
+    /// that left it, so the pending tales outlive a restart
+    fn record_pending_stories(&self, arrived: &[TransactionStory], departed: &[String]) -> Result<(), ChronicleError> {
+        let mut batch = sled::Batch::default();
+        for story in arrived {
+            let story_data = bincode::serialize(story)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            batch.insert(story.story_id.as_bytes(), story_data);
+        }
+        for story_id in departed {
+            batch.remove(story_id.as_bytes());
+        }
+        self.mempool_db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Every story the mempool held when it was last written down
+    fn load_pending_stories(&self) -> Result<Vec<TransactionStory>, ChronicleError> {
+        self.mempool_db.iter()
+            .map(|entry| {
+                let (_, story_data) = entry.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+                bincode::deserialize(&story_data).map_err(|e| ChronicleError::SerializationError(e.to_string()))
+            })
+            .collect()
+    }
+    
+    async fn transaction_exists(&self, tx_id: &str) -> Result<bool, ChronicleError> {
+        Ok(self.tx_db.contains_key(tx_id)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?)
+    }
+    
+    /// Walks the headers rather than the bodies, so a pruned chain verifies too
+    async fn verify_chain_integrity(&self) -> Result<bool, ChronicleError> {
+        let mut previous_hash = "0".repeat(64);
+        
+        for header in self.headers() {
+            let header = header?;
+            
+            if header.chapter_number > 0 && header.previous_chapter_essence != previous_hash {
+                return Ok(false);
+            }
+            
+            previous_hash = header.chapter_essence;
+        }
+        
+        Ok(true)
+    }
+    
+    fn clone(&self) -> Self {
+        Self {
+            block_db: self.block_db.clone(),
+            tx_db: self.tx_db.clone(),
+            utxo_db: self.utxo_db.clone(),
+            sync_db: self.sync_db.clone(),
+            nonce_db: self.nonce_db.clone(),
+            mempool_db: self.mempool_db.clone(),
+            header_tree: self.header_tree.clone(),
+            essence_tree: self.essence_tree.clone(),
+            chain_tip: self.chain_tip.clone(),
+            block_index: self.block_index.clone(),
+            sync_served_from: self.sync_served_from.clone(),
+        }
+    }
+}
+
+impl UTXOLedger {
+    async fn new(db: Db) -> Result<Self, ChronicleError> {
+        let spend_provenance = db.open_tree("spend_provenance")
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        let output_birthplace = db.open_tree("output_birthplace")
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        Ok(Self {
+            unspent_outputs: Arc::new(RwLock::new(HashMap::new())),
+            spent_outputs: Arc::new(RwLock::new(HashSet::new())),
+            db,
+            spend_provenance,
+            output_birthplace,
+        })
+    }
+
+    /// The story that spent `utxo_key` and the chapter it was spent in, or
+    /// `None` while the output is unspent (or was never created)
+    pub fn get_spending_tx(&self, utxo_key: &str) -> Result<Option<(String, u64)>, ChronicleError> {
+        match self.spend_provenance.get(utxo_key).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(provenance_data) => Ok(Some(bincode::deserialize(&provenance_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The chapter that created `utxo_key`, or `None` for an output no
+    /// chapter on our chain created
+    fn chapter_of_creation(&self, utxo_key: &[u8]) -> Result<Option<u64>, ChronicleError> {
+        match self.output_birthplace.get(utxo_key).map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            Some(height_data) => Ok(Some(bincode::deserialize(&height_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn find_unspent_output(&self, utxo_key: &str) -> Result<Option<UTXOOutput>, ChronicleError> {
+        // Check in-memory cache first
+        {
+            let unspent = self.unspent_outputs.read().unwrap();
+            if let Some(utxo) = unspent.get(utxo_key) {
+                return Ok(Some(utxo.clone()));
+            }
+        }
+        
+        // Check database
+        if let Some(utxo_data) = self.db.get(utxo_key)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))? {
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            return Ok(Some(utxo));
+        }
+        
+        Ok(None)
+    }
+    
+    async fn calculate_balance(&self, address: &[u8]) -> Result<u64, ChronicleError> {
+        let mut balance = 0u64;
+        
+        for result in self.db.iter() {
+            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            
+            if utxo.recipient_address == address {
+                // Check if not spent
+                let key_str = String::from_utf8_lossy(&key);
+                let spent = self.spent_outputs.read().unwrap();
+                if !spent.contains(key_str.as_ref()) {
+                    balance = balance.checked_add(utxo.value_locked)
+                        .ok_or(ChronicleError::ValueOverflow)?;
+                }
+            }
+        }
+        
+        Ok(balance)
+    }
+
+    fn calculate_total_supply(&self) -> Result<u64, ChronicleError> {
+        self.unspent_set()?.values()
+            .try_fold(0u64, |supply, utxo| supply.checked_add(utxo.value_locked))
+            .ok_or(ChronicleError::ValueOverflow)
+    }
+
+    /// Like `calculate_balance`, counting only outputs created at least
+    /// `min_confirmations` chapters deep below `tip_height`, the tip itself
+    /// being one confirmation deep
+    fn calculate_confirmed_balance(&self, address: &[u8], tip_height: u64, min_confirmations: u64) -> Result<u64, ChronicleError> {
+        let mut balance = 0u64;
+
+        for result in self.db.iter() {
+            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            if utxo.recipient_address != address
+                || self.spent_outputs.read().unwrap().contains(String::from_utf8_lossy(&key).as_ref()) {
+                continue;
+            }
+
+            let buried_deep_enough = min_confirmations == 0 || self.chapter_of_creation(&key)?
+                .is_some_and(|created_at| created_at <= tip_height && tip_height - created_at + 1 >= min_confirmations);
+            if buried_deep_enough {
+                balance = balance.checked_add(utxo.value_locked)
+                    .ok_or(ChronicleError::ValueOverflow)?;
+            }
+        }
+
+        Ok(balance)
+    }
+    
+    /// Writes a committed chapter into the ledger: every output its stories
+    /// consume becomes spent and every output they create becomes spendable.
+    /// The chapter lands as one batch, so a story that cannot be applied
+    /// leaves the ledger exactly as it was.
+    pub async fn apply_block(&self, block: &BlockChapter) -> Result<(), ChronicleError> {
+        let (consumed, created) = self.chapter_reshapes_the_ledger(block).await?;
+
+        let mut provenance = sled::Batch::default();
+        for story in &block.transaction_tales {
+            for input in story_spends(story) {
+                let utxo_key = format!("{}:{}", input.previous_story_id, input.output_index);
+                let spent_by = bincode::serialize(&(&story.story_id, block.chapter_number))
+                    .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+                provenance.insert(utxo_key.as_bytes(), spent_by);
+            }
+        }
+
+        let mut batch = sled::Batch::default();
+        let mut birthplace = sled::Batch::default();
+        let created_at = bincode::serialize(&block.chapter_number)
+            .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+        for utxo_key in &consumed {
+            batch.remove(utxo_key.as_bytes());
+        }
+        for (utxo_key, output) in &created {
+            let output_data = bincode::serialize(output)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            batch.insert(utxo_key.as_bytes(), output_data);
+            birthplace.insert(utxo_key.as_bytes(), created_at.clone());
+        }
+        self.db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.spend_provenance.apply_batch(provenance)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.output_birthplace.apply_batch(birthplace)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+
+        let mut unspent = self.unspent_outputs.write().unwrap();
+        let mut spent = self.spent_outputs.write().unwrap();
+        for utxo_key in consumed {
+            unspent.remove(&utxo_key);
+            spent.insert(utxo_key);
+        }
+        unspent.extend(created);
+        Ok(())
+    }
+    
+    /// The outputs a chapter would consume and those it would leave
+    /// unspent, refusing a chapter that spends anything not unspent
+    async fn chapter_reshapes_the_ledger(
+        &self,
+        block: &BlockChapter,
+    ) -> Result<(Vec<String>, HashMap<String, UTXOOutput>), ChronicleError> {
+        let mut consumed = Vec::new();
+        let mut created: HashMap<String, UTXOOutput> = HashMap::new();
+
+        for story in &block.transaction_tales {
+            for input in story_spends(story) {
+                let utxo_key = format!("{}:{}", input.previous_story_id, input.output_index);
+                // A story may spend what an earlier story in the same chapter created
+                if created.remove(&utxo_key).is_some() {
+                    continue;
+                }
+                let already_spent = consumed.contains(&utxo_key)
+                    || self.spent_outputs.read().unwrap().contains(&utxo_key);
+                if already_spent || self.find_unspent_output(&utxo_key).await?.is_none() {
+                    return Err(ChronicleError::UTXONotFound(format!(
+                        "Chapter {} spends {} which is not unspent",
+                        block.chapter_number, utxo_key
+                    )));
+                }
+                consumed.push(utxo_key);
+            }
+            for (utxo_key, output) in story_creates(story) {
+                created.insert(utxo_key, output.clone());
+            }
+        }
+        Ok((consumed, created))
+    }
+
+    /// Every unspent output, keyed and ordered by its `story_id:index`
+    fn unspent_set(&self) -> Result<BTreeMap<String, UTXOOutput>, ChronicleError> {
+        let spent = self.spent_outputs.read().unwrap();
+        let mut unspent = BTreeMap::new();
+        for result in self.db.iter() {
+            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            let utxo_key = String::from_utf8_lossy(&key).into_owned();
+            if spent.contains(&utxo_key) {
+                continue;
+            }
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            unspent.insert(utxo_key, utxo);
+        }
+        Ok(unspent)
+    }
+
+    /// The state root the unspent outputs would have once `block` is
+    /// applied, leaving the ledger untouched
+    async fn state_root_after(&self, block: &BlockChapter) -> Result<String, ChronicleError> {
+        let (consumed, created) = self.chapter_reshapes_the_ledger(block).await?;
+        let mut unspent = self.unspent_set()?;
+        for utxo_key in &consumed {
+            unspent.remove(utxo_key);
+        }
+        unspent.extend(created);
+        utxo_state_root(&unspent)
+    }
+
+    /// Undoes `apply_block` for a chapter leaving the chain: the outputs it
+    /// created are removed and the outputs it spent are unspent again, their
+    /// contents recovered from the stories that created them.
+    async fn revert_block(&self, block: &BlockChapter, chain_repo: &ChainRepository) -> Result<(), ChronicleError> {
+        let created: HashSet<String> = block.transaction_tales.iter()
+            .flat_map(|story| story_creates(story).map(|(utxo_key, _)| utxo_key))
+            .collect();
+
+        let mut restored = HashMap::new();
+        let mut provenance = sled::Batch::default();
+        for input in block.transaction_tales.iter().flat_map(story_spends) {
+            let utxo_key = format!("{}:{}", input.previous_story_id, input.output_index);
+            provenance.remove(utxo_key.as_bytes());
+            // Created and spent within the chapter, so it simply vanishes
+            if created.contains(&utxo_key) {
+                continue;
+            }
+            let spent_output = chain_repo.load_created_output(input)?
+                .ok_or_else(|| ChronicleError::UTXONotFound(format!(
+                    "Cannot recover {} spent in chapter {}",
+                    utxo_key, block.chapter_number
+                )))?;
+            restored.insert(utxo_key, spent_output);
+        }
+
+        let mut batch = sled::Batch::default();
+        let mut birthplace = sled::Batch::default();
+        for utxo_key in &created {
+            batch.remove(utxo_key.as_bytes());
+            birthplace.remove(utxo_key.as_bytes());
+        }
+        for (utxo_key, output) in &restored {
+            let output_data = bincode::serialize(output)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            batch.insert(utxo_key.as_bytes(), output_data);
+        }
+        self.db.apply_batch(batch)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.spend_provenance.apply_batch(provenance)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+        self.output_birthplace.apply_batch(birthplace)
+            .map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+
+        let mut unspent = self.unspent_outputs.write().unwrap();
+        let mut spent = self.spent_outputs.write().unwrap();
+        for utxo_key in &created {
+            unspent.remove(utxo_key);
+        }
+        for utxo_key in restored.keys() {
+            spent.remove(utxo_key);
+        }
+        unspent.extend(restored);
+        Ok(())
+    }
+    
+    /// Every output currently unspent, keyed `story_id:output_index`
+    fn unspent_outputs_as_they_stand(&self) -> Result<HashMap<String, UTXOOutput>, ChronicleError> {
+        let spent = self.spent_outputs.read().unwrap().clone();
+        let mut unspent = self.unspent_outputs.read().unwrap().clone();
+        
+        for result in self.db.iter() {
+            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            let key_str = String::from_utf8_lossy(&key).to_string();
+            if spent.contains(&key_str) {
+                continue;
+            }
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            unspent.insert(key_str, utxo);
+        }
+        
+        unspent.retain(|key, _| !spent.contains(key));
+        Ok(unspent)
+    }
+    
+    async fn find_utxos_for_address(&self, address: &[u8]) -> Result<Vec<(UTXOReference, UTXOOutput)>, ChronicleError> {
+        let mut utxos = Vec::new();
+        
+        for result in self.db.iter() {
+            let (key, utxo_data) = result.map_err(|e| ChronicleError::DatabaseError(e.to_string()))?;
+            let utxo: UTXOOutput = bincode::deserialize(&utxo_data)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            
+            if utxo.recipient_address == address {
+                let key_str = String::from_utf8_lossy(&key);
+                let spent = self.spent_outputs.read().unwrap();
+                if !spent.contains(key_str.as_ref()) {
+                    // Parse key to create UTXOReference
+                    let parts: Vec<&str> = key_str.split(':').collect();
+                    if parts.len() == 2 {
+                        if let Ok(output_index) = parts[1].parse::<u32>() {
+                            let utxo_ref = UTXOReference {
+                                previous_story_id: parts[0].to_string(),
+                                output_index,
+                            };
+                            utxos.push((utxo_ref, utxo));
+                        }
+                    }
+                }
+            }
+        }
+        
+        Ok(utxos)
+    }
+}
+
+impl NetworkOfStoryTellers {
+    async fn new(port: u16) -> Result<Self, ChronicleError> {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let (incoming_sender, incoming_messages) = mpsc::unbounded_channel();
+        let peer_connections = Arc::new(Mutex::new(Vec::new()));
+        let transport: Arc<dyn Transport> = Arc::new(TcpTransport { peer_connections: peer_connections.clone() });
+        
+        Ok(Self {
+            peer_connections,
+            message_broadcaster: Arc::new(Mutex::new(tx)),
+            transport: Arc::new(RwLock::new(transport)),
+            incoming_sender,
+            incoming_messages: Arc::new(Mutex::new(incoming_messages)),
+            sync_status: Arc::new(RwLock::new(SyncStatus {
+                is_syncing: false,
+                current_height: 0,
+                target_height: 0,
+                sync_progress: 0.0,
+            })),
+            known_peers: Arc::new(RwLock::new(HashSet::new())),
+            peer_tip_reports: Arc::new(RwLock::new(HashMap::new())),
+            peer_clock_offsets: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+    
+    /// Accepts peer connections on `port` for as long as the node runs,
+    /// returning the address actually bound (port 0 picks a free one)
+    fn begin_listening(&self, port: u16) -> Result<std::net::SocketAddr, ChronicleError> {
+        let listener = TcpListener::bind(("0.0.0.0", port))
+            .map_err(|e| ChronicleError::NetworkError(format!("Cannot listen on port {}: {}", port, e)))?;
+        let local_address = listener.local_addr()
+            .map_err(|e| ChronicleError::NetworkError(e.to_string()))?;
+        println!("👂 Listening for story tellers on {}", local_address);
+
+        let network = self.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let welcomed = stream
+                    .map_err(|e| ChronicleError::NetworkError(e.to_string()))
+                    .and_then(|stream| network.welcome_peer(stream));
+                if let Err(plot_twist) = welcomed {
+                    eprintln!("Peer connection failed: {:?}", plot_twist);
+                }
+            }
+        });
+        Ok(local_address)
+    }
+
+    /// Starts hearing a newly connected peer, returning the address it goes by
+    fn welcome_peer(&self, stream: TcpStream) -> Result<String, ChronicleError> {
+        let peer_address = stream.peer_addr()
+            .map_err(|e| ChronicleError::NetworkError(e.to_string()))?
+            .to_string();
+        let mut reader = stream.try_clone()
+            .map_err(|e| ChronicleError::NetworkError(e.to_string()))?;
+
+        self.known_peers.write().unwrap().insert(peer_address.clone());
+        self.peer_connections.lock().unwrap().push(PeerConnection {
+            peer_address: peer_address.clone(),
+            connection_stream: Arc::new(Mutex::new(stream)),
+            last_seen: current_timestamp(),
+            sync_height: 0,
+        });
+
+        let welcomed_address = peer_address.clone();
+        let network = self.clone();
+        thread::spawn(move || {
+            while let Ok(message) = read_network_frame(&mut reader) {
+                if let Some(peer) = network.peer_connections.lock().unwrap().iter_mut()
+                    .find(|peer| peer.peer_address == peer_address) {
+                    peer.last_seen = current_timestamp();
+                    if let NetworkMessage::Heartbeat(height) | NetworkMessage::Handshake { chain_height: height, .. } = message {
+                        peer.sync_height = height;
+                    }
+                }
+                if network.incoming_sender.send((peer_address.clone(), message)).is_err() {
+                    break;
+                }
+            }
+            network.peer_connections.lock().unwrap().retain(|peer| peer.peer_address != peer_address);
+            println!("👋 Peer {} has left", peer_address);
+        });
+        Ok(welcomed_address)
+    }
+    
+    async fn start_peer_discovery(&self) -> Result<(), ChronicleError> {
+        // Implementation for peer discovery
+        // In a real system, this would connect to bootstrap nodes
+        println!("🔍 Starting peer discovery...");
+        Ok(())
+    }
+    
+    async fn begin_chain_synchronization(&self, from_height: u64) -> Result<(), ChronicleError> {
+        println!("🔄 Beginning chain synchronization from chapter {}...", from_height);
+        self.sync_status.write().unwrap().is_syncing = true;
+        self.announce(NetworkMessage::RequestChainSync(from_height))
+    }
+    
+    async fn broadcast_transaction_story(&self, story: TransactionStory) -> Result<(), ChronicleError> {
+        println!("📡 Broadcasting transaction to network...");
+        self.announce(NetworkMessage::NewTransactionStory(story))
+    }
+
+    async fn announce_block_chapter(&self, block: BlockChapter) -> Result<(), ChronicleError> {
+        println!("📡 Announcing chapter {} to network...", block.chapter_number);
+        self.announce(NetworkMessage::NewBlockChapter(block))
+    }
+
+    /// Hands a message to whoever is tapping our announcements and to the
+    /// transport that carries it to peers
+    fn announce(&self, message: NetworkMessage) -> Result<(), ChronicleError> {
+        // Nobody tapping is not an error; the tap simply goes unheard
+        let _ = self.message_broadcaster.lock().unwrap().send(message.clone());
+        let transport = self.transport.read().unwrap().clone();
+        transport.carry(&message)
+    }
+
+    /// Like `announce`, but the transport carries the message to the one
+    /// peer at `peer_address` only
+    fn tell_peer(&self, peer_address: &str, message: NetworkMessage) -> Result<(), ChronicleError> {
+        let _ = self.message_broadcaster.lock().unwrap().send(message.clone());
+        let transport = self.transport.read().unwrap().clone();
+        transport.carry_to(peer_address, &message)
+    }
+    
+    /// Remembers how far a peer's reported clock stands from ours. A clock
+    /// too far off to measure in seconds counts as the farthest it could be,
+    /// and is clamped like any other.
+    fn record_peer_clock(&self, peer_address: &str, peer_time: u64) {
+        let our_time = current_timestamp();
+        let offset = if peer_time >= our_time {
+            i64::try_from(peer_time - our_time).unwrap_or(i64::MAX)
+        } else {
+            i64::try_from(our_time - peer_time).map_or(i64::MIN, |behind| -behind)
+        };
+        self.peer_clock_offsets.write().unwrap().insert(peer_address.to_string(), offset);
+    }
+
+    /// The median of the peers' clock offsets, clamped to `max_offset_secs`
+    /// either way; zero while no peer has reported
+    fn network_clock_offset(&self, max_offset_secs: u64) -> i64 {
+        let mut offsets: Vec<i64> = self.peer_clock_offsets.read().unwrap().values().copied().collect();
+        if offsets.is_empty() {
+            return 0;
+        }
+        offsets.sort_unstable();
+        let middle = offsets.len() / 2;
+        let median = if offsets.len().is_multiple_of(2) {
+            offsets[middle - 1].saturating_add(offsets[middle]).div_euclid(2)
+        } else {
+            offsets[middle]
+        };
+        let max_offset = max_offset_secs.min(i64::MAX as u64) as i64;
+        median.clamp(-max_offset, max_offset)
+    }
+
+    /// Remembers the tip a peer claims at a height, forgetting heights
+    /// too far below the newest report to matter for fork detection
+    fn record_peer_tip(&self, peer_address: &str, height: u64, tip_hash: &str) {
+        let mut reports = self.peer_tip_reports.write().unwrap();
+        reports.entry(height).or_default().insert(peer_address.to_string(), tip_hash.to_string());
+
+        let newest_height = reports.keys().copied().max().unwrap_or(height);
+        reports.retain(|&reported_height, _| reported_height + PEER_TIP_MEMORY > newest_height);
+    }
+
+    /// The highest height at which peers disagree about the chain's tip
+    fn find_chain_split(&self) -> Option<SplitReport> {
+        let reports = self.peer_tip_reports.read().unwrap();
+        let (height, tips_by_peer) = reports.iter()
+            .filter(|(_, tips_by_peer)| tips_by_peer.values().collect::<HashSet<_>>().len() > 1)
+            .max_by_key(|(height, _)| **height)?;
+
+        let mut peers_by_tip: HashMap<&str, Vec<String>> = HashMap::new();
+        for (peer_address, tip_hash) in tips_by_peer {
+            peers_by_tip.entry(tip_hash.as_str()).or_default().push(peer_address.clone());
+        }
+
+        let mut competing_tips: Vec<CompetingTip> = peers_by_tip.into_iter()
+            .map(|(tip_hash, mut peers)| {
+                peers.sort();
+                CompetingTip { tip_hash: tip_hash.to_string(), peers }
+            })
+            .collect();
+        competing_tips.sort_by(|a, b| b.peers.len().cmp(&a.peers.len()).then(a.tip_hash.cmp(&b.tip_hash)));
+
+        Some(SplitReport { height: *height, competing_tips })
+    }
+    
+    async fn get_peer_count(&self) -> usize {
+        self.peer_connections.lock().unwrap().len()
+    }
+    
+    async fn get_sync_status(&self) -> SyncStatus {
+        self.sync_status.read().unwrap().clone()
+    }
+}
+
+impl TcpTransport {
+    /// Writes the frame to each of the streams, with the list of peers
+    /// unlocked so one slow peer cannot keep others from joining or leaving,
+    /// then forgets the peers whose streams broke
+    fn write_to(&self, streams: Vec<(String, Arc<Mutex<TcpStream>>)>, frame: &[u8]) {
+        let dropped: HashSet<String> = streams.into_iter()
+            .filter_map(|(peer_address, stream)| {
+                let written = stream.lock().unwrap().write_all(frame);
+                written.err().map(|e| {
+                    println!("👋 Peer {} dropped mid-write: {}", peer_address, e);
+                    peer_address
+                })
+            })
+            .collect();
+        if !dropped.is_empty() {
+            self.peer_connections.lock().unwrap().retain(|peer| !dropped.contains(&peer.peer_address));
+        }
+    }
+}
+
+impl Transport for TcpTransport {
+    fn carry(&self, message: &NetworkMessage) -> Result<(), ChronicleError> {
+        let frame = frame_network_message(message)?;
+        let streams = self.peer_connections.lock().unwrap().iter()
+            .map(|peer| (peer.peer_address.clone(), peer.connection_stream.clone()))
+            .collect();
+        self.write_to(streams, &frame);
+        Ok(())
+    }
+
+    fn carry_to(&self, peer_address: &str, message: &NetworkMessage) -> Result<(), ChronicleError> {
+        let frame = frame_network_message(message)?;
+        // A peer gone before its answer is ready simply goes unanswered
+        let streams = self.peer_connections.lock().unwrap().iter()
+            .filter(|peer| peer.peer_address == peer_address)
+            .map(|peer| (peer.peer_address.clone(), peer.connection_stream.clone()))
+            .collect();
+        self.write_to(streams, &frame);
+        Ok(())
+    }
+}
+
+impl SimNetwork {
+    /// An empty network whose losses are drawn from `seed`
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(SimState {
+                now: Duration::ZERO,
+                node_count: 0,
+                links: HashMap::new(),
+                in_flight: BinaryHeap::new(),
+                messages: HashMap::new(),
+                next_sequence: 0,
+                // Xorshift never leaves zero, so a zero seed is nudged off it
+                rng_state: seed.max(1),
+            })),
+        }
+    }
+
+    /// Adds a node to the network and hands back its transport
+    pub fn join(&self) -> SimTransport {
+        let mut state = self.state.lock().unwrap();
+        let node = state.node_count;
+        state.node_count += 1;
+        SimTransport { node, network: self.clone() }
+    }
+
+    /// Joins two nodes by a link that behaves the same in both directions
+    pub fn link(&self, a: usize, b: usize, link: SimLink) {
+        let mut state = self.state.lock().unwrap();
+        state.links.insert((a, b), link);
+        state.links.insert((b, a), link);
+    }
+
+    /// How much simulated time has passed
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now
+    }
+
+    /// Moves the clock to the next message in flight and hands it over.
+    /// Messages landing at the same moment arrive in the order they were sent.
+    pub fn deliver_next(&self) -> Option<SimDelivery> {
+        let mut state = self.state.lock().unwrap();
+        let Reverse((arrives_at, sequence, to_node)) = state.in_flight.pop()?;
+        state.now = arrives_at;
+        let (from_node, message) = state.messages.remove(&sequence)?;
+        Some(SimDelivery { arrives_at, from_node, to_node, message })
+    }
+}
+
+impl SimState {
+    /// A xorshift draw in [0, 1)
+    fn roll(&mut self) -> f64 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl SimTransport {
+    pub fn node(&self) -> usize {
+        self.node
+    }
+
+    /// The address a sim node goes by, as its peers name it when answering
+    pub fn address_of(node: usize) -> String {
+        format!("sim:{}", node)
+    }
+}
+
+impl SimState {
+    /// Puts the message on the link, unless the link loses it
+    fn send_over(&mut self, from_node: usize, to_node: usize, link: SimLink, message: &NetworkMessage) {
+        if self.roll() < link.loss_probability {
+            return;
+        }
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        let arrives_at = self.now + link.latency;
+        self.in_flight.push(Reverse((arrives_at, sequence, to_node)));
+        self.messages.insert(sequence, (from_node, message.clone()));
+    }
+}
+
+impl Transport for SimTransport {
+    fn carry(&self, message: &NetworkMessage) -> Result<(), ChronicleError> {
+        let mut state = self.network.state.lock().unwrap();
+        let mut outgoing: Vec<(usize, SimLink)> = state.links.iter()
+            .filter(|((from, _), _)| *from == self.node)
+            .map(|((_, to), link)| (*to, *link))
+            .collect();
+        // Link order must not depend on the hash map, or losses would not replay
+        outgoing.sort_by_key(|(to, _)| *to);
+
+        for (to_node, link) in outgoing {
+            state.send_over(self.node, to_node, link, message);
+        }
+        Ok(())
+    }
+
+    fn carry_to(&self, peer_address: &str, message: &NetworkMessage) -> Result<(), ChronicleError> {
+        let mut state = self.network.state.lock().unwrap();
+        let linked = (0..state.node_count)
+            .find(|node| Self::address_of(*node) == peer_address)
+            .and_then(|to_node| state.links.get(&(self.node, to_node)).map(|link| (to_node, *link)));
+        let Some((to_node, link)) = linked else {
+            return Err(ChronicleError::NetworkError(format!("No road leads to {}", peer_address)));
+        };
+        state.send_over(self.node, to_node, link, message);
+        Ok(())
+    }
+}
+
+impl MiningHeart {
+    fn new(reward_address: Vec<u8>, _target_block_time: Duration) -> Self {
+        Self {
+            is_beating: Arc::new(Mutex::new(true)),
+            is_stilled: Arc::new(Mutex::new(false)),
+            current_difficulty: Arc::new(RwLock::new(GENESIS_DIFFICULTY_TARGET)),
+            hash_rate: Arc::new(RwLock::new(0.0)),
+            mining_reward_address: reward_address,
+            mining_quest: None,
+            thread_handles: Vec::new(),
+        }
+    }
+}
+
+impl ValidatorCouncil {
+    fn new() -> Self {
+        let mut council_members = HashMap::new();
+        let keypair = Keypair::generate(&mut OsRng);
+        
+        council_members.insert("validator_1".to_string(), ValidatorGuardian {
+            guardian_id: "validator_1".to_string(),
+            stake_in_truth: 1000,
+            reputation_score: 1.0,
+            tales_validated: 0,
+            public_key: keypair.public,
+            last_validation_time: current_timestamp(),
+        });
+
+        Self {
+            council_members,
+            consensus_threshold: 0.67,
+            current_storyteller: Some("validator_1".to_string()),
+            reputation_system: ReputationSystem::new(),
+            equivocation_watchtower: EquivocationWatchtower::default(),
+        }
+    }
+
+    fn admit_validator(&mut self, guardian_id: &str, stake_in_truth: u64, public_key: PublicKey) {
+        self.council_members.insert(guardian_id.to_string(), ValidatorGuardian {
+            guardian_id: guardian_id.to_string(),
+            stake_in_truth,
+            reputation_score: 1.0,
+            tales_validated: 0,
+            public_key,
+            last_validation_time: current_timestamp(),
+        });
+    }
+
+    /// The council member whose public key this is, if any
+    fn validator_with_key(&self, public_key: &[u8]) -> Option<String> {
+        self.council_members.values()
+            .find(|validator| validator.public_key.as_bytes().as_slice() == public_key)
+            .map(|validator| validator.guardian_id.clone())
+    }
+
+    /// Penalizes a council member, carrying its new reputation onto it and
+    /// slashing `SLASHED_STAKE_PERCENT` of its stake when a slash is due.
+    /// Returns `None` for a validator not on the council.
+    fn penalize_validator(&mut self, validator_id: &str, penalty: PenaltyType) -> Option<PenaltyOutcome> {
+        let validator = self.council_members.get_mut(validator_id)?;
+        let outcome = self.reputation_system.penalize(validator_id, penalty);
+        validator.reputation_score = outcome.reputation_score;
+        if outcome.slash_due {
+            validator.stake_in_truth -= validator.stake_in_truth * SLASHED_STAKE_PERCENT / 100;
+        }
+        Some(outcome)
+    }
+
+    /// Verifies a validator's signature over a chapter and hands it to the
+    /// watchtower; conflicting evidence is penalized once per height.
+    fn observe_chapter_signature(
+        &mut self,
+        validator_id: &str,
+        height: u64,
+        claim: SignedChapterClaim,
+    ) -> Result<Option<SlashingEvidence>, ChronicleError> {
+        let validator = self.council_members.get_mut(validator_id)
+            .ok_or_else(|| ChronicleError::UnknownValidator(validator_id.to_string()))?;
+
+        let signature = Signature::from_bytes(&claim.signature)
+            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+        validator.public_key
+            .verify(&chapter_claim_message(height, &claim.chapter_essence), &signature)
+            .map_err(|e| ChronicleError::InvalidSignature(e.to_string()))?;
+
+        let evidence = self.equivocation_watchtower.witness_claim(validator_id, height, claim);
+        if evidence.is_some() {
+            self.reputation_system.apply_penalty(validator_id, height, PenaltyType::Equivocation);
+            validator.reputation_score = self.reputation_system.reputation_of(validator_id);
+        }
+        Ok(evidence)
+    }
+}
+
+impl EquivocationWatchtower {
+    /// Remembers the claim, returning slashing evidence the first time a
+    /// validator's claim at a height contradicts one it made before
+    fn witness_claim(&mut self, validator_id: &str, height: u64, claim: SignedChapterClaim) -> Option<SlashingEvidence> {
+        let claims_at_height = self.signed_claims.entry(height).or_default();
+        let evidence = match claims_at_height.get(validator_id) {
+            None => {
+                claims_at_height.insert(validator_id.to_string(), claim);
+                None
+            }
+            Some(earlier) if earlier.chapter_essence == claim.chapter_essence => None,
+            Some(earlier) => self.slashed_at.insert((validator_id.to_string(), height)).then(|| SlashingEvidence {
+                validator_id: validator_id.to_string(),
+                height,
+                first_claim: earlier.clone(),
+                conflicting_claim: claim,
+            }),
+        };
+
+        let newest_height = self.signed_claims.keys().copied().max().unwrap_or(height);
+        self.signed_claims.retain(|&signed_height, _| signed_height + EQUIVOCATION_MEMORY > newest_height);
+        self.slashed_at.retain(|(_, slashed_height)| slashed_height + EQUIVOCATION_MEMORY > newest_height);
+
+        evidence
+    }
+}
+
+impl ReputationSystem {
+    fn new() -> Self {
+        Self {
+            validator_scores: HashMap::new(),
+            penalty_system: PenaltyTracker {
+                recent_penalties: HashMap::new(),
+                cumulative_penalties: HashMap::new(),
+            },
+            reward_multipliers: HashMap::new(),
+        }
+    }
+
+    fn reputation_of(&self, validator_id: &str) -> f64 {
+        self.validator_scores.get(validator_id).copied().unwrap_or(1.0)
+    }
+
+    fn apply_penalty(&mut self, validator_id: &str, at: u64, penalty: PenaltyType) {
+        let reduced = (self.reputation_of(validator_id) - penalty.reputation_cost()).max(0.0);
+        self.validator_scores.insert(validator_id.to_string(), reduced);
+
+        let penalties = &mut self.penalty_system;
+        penalties.recent_penalties.entry(validator_id.to_string()).or_default().push((at, penalty));
+        *penalties.cumulative_penalties.entry(validator_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// ## A Validator Answers for Its Offence
+    /// 
+    /// Records the penalty as of now and takes its cost off the validator's
+    /// reputation. Every `SLASHING_PENALTY_THRESHOLD` penalties, the
+    /// outcome calls for the validator's stake to be slashed.
+    pub fn penalize(&mut self, validator_id: &str, penalty: PenaltyType) -> PenaltyOutcome {
+        self.apply_penalty(validator_id, current_timestamp(), penalty);
+        let cumulative = self.penalty_system.cumulative_penalties.get(validator_id).copied().unwrap_or(0);
+        PenaltyOutcome {
+            reputation_score: self.reputation_of(validator_id),
+            slash_due: cumulative % SLASHING_PENALTY_THRESHOLD == 0,
+        }
+    }
+}
+
+impl Default for ChronicleConfiguration {
+    fn default() -> Self {
+        Self {
+            target_block_time: Duration::from_secs(600), // 10 minutes
+            difficulty_adjustment_interval: 2016, // ~2 weeks
+            max_block_size: 1_048_576, // 1MB
+            min_transaction_fee: 1000, // 0.00001 units
+            min_fee_rate: 1_000, // one unit per byte
+            max_mempool_stories: 10_000,
+            base_mining_reward: 5_000_000_000, // 50 units
+            reward_halving_interval: 210_000,
+            reward_beneficiaries: Vec::new(),
+            fee_policy: FeePolicy::AllToMiner,
+            max_peers: 50,
+            network_port: 8333,
+            data_directory: "./blockchain_data".to_string(),
+            max_mempool_audit_entries: 1000,
+            max_future_drift_secs: 2 * 60 * 60,
+            max_peer_clock_offset_secs: 70 * 60,
+            strict_story_versions: true,
+            rebroadcast_interval: Duration::from_secs(60),
+            rebroadcast_backoff: 2.0,
+            max_rebroadcast_interval: Duration::from_secs(30 * 60),
+            rebroadcast_expiry: Duration::from_secs(24 * 60 * 60),
+            node_mode: NodeMode::Archival,
+            observer_mode: false,
+            commit_state_roots: false,
+        }
+    }
+}
+
+impl ChronicleConfiguration {
+    /// Rejects settings the chronicle would otherwise divide by: a zero
+    /// interval, block size or block time is a misconfiguration, not a
+    /// request to skip the rule.
+    pub fn validate(&self) -> Result<(), ChronicleError> {
+        let basis_points = self.fee_policy.diverted_basis_points();
+        if basis_points > FEE_BASIS_POINTS_WHOLE {
+            return Err(ChronicleError::InvalidConfiguration(format!(
+                "fee_policy share must not exceed {} basis points, not {}", FEE_BASIS_POINTS_WHOLE, basis_points
+            )));
+        }
+        let zero_setting = [
+            ("difficulty_adjustment_interval", self.difficulty_adjustment_interval == 0),
+            ("reward_halving_interval", self.reward_halving_interval == 0),
+            ("max_block_size", self.max_block_size == 0),
+            ("target_block_time", self.target_block_time.is_zero()),
+            ("node_mode keep_blocks", self.node_mode == NodeMode::Pruned { keep_blocks: 0 }),
+            ("reward_beneficiaries weight", self.reward_beneficiaries.iter().any(|(_, weight)| *weight == 0)),
+        ].into_iter().find(|(_, is_zero)| *is_zero);
+
+        match zero_setting {
+            Some((setting, _)) => Err(ChronicleError::InvalidConfiguration(format!("{} must be greater than zero", setting))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// ## The Current State of Our Chronicle
+#[derive(Debug)]
+pub struct ChronicleState {
+    pub total_chapters: u64,
+    pub pending_stories: usize,
+    pub latest_chapter_essence: String,
+    pub chain_integrity: bool,
+    pub network_peers: usize,
+    pub sync_status: SyncStatus,
+}
+
+/// ## A Fork in the Tale
+/// 
+/// Competing tips are listed with the most-supported version first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitReport {
+    pub height: u64,
+    pub competing_tips: Vec<CompetingTip>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompetingTip {
+    pub tip_hash: String,
+    pub peers: Vec<String>,
+}
+
+/// ## The Tale Took Another Branch
+/// 
+/// What a reorganization unwound and adopted, and which orphaned stories
+/// still validated and went back to the mempool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReorganizationReport {
+    pub common_ancestor: u64,
+    pub orphaned_chapters: usize,
+    pub adopted_chapters: usize,
+    pub returned_to_mempool: Vec<String>,
+}
+
+/// ## News From the Chronicle
+///
+/// What subscribers hear once a change is stored. A reorganization is
+/// told by its depth, the chapters it orphaned, followed by each chapter
+/// it adopted.
+#[derive(Debug, Clone)]
+pub enum ChainEvent {
+    BlockAdded(BlockChapter),
+    TransactionAccepted(TransactionStory),
+    ReorgHappened { depth: u64 },
+}
+
+/// ## Proof That a Validator Told Two Tales at Once
+/// 
+/// Two valid signatures by the same validator over different chapters at
+/// the same height; enough for anyone to verify and slash.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlashingEvidence {
+    pub validator_id: String,
+    pub height: u64,
+    pub first_claim: SignedChapterClaim,
+    pub conflicting_claim: SignedChapterClaim,
+}
+
+/// ## The Chronicle's Vital Signs Over Recent Chapters
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainStats {
+    pub chapters_observed: u64,
+    pub average_block_time_secs: f64,
+    pub average_stories_per_chapter: f64,
+    pub average_fee: f64,
+    pub estimated_tps: f64,
+}
+
+/// The chapters and unspent outputs at one tip, frozen and shared
+#[derive(Debug, Clone)]
+struct SnapshotBase {
+    tip_essence: String,
+    /// Every chapter up to the tip, `None` where the body has been pruned
+    chapters: Arc<[Option<Arc<BlockChapter>>]>,
+    unspent_outputs: Arc<HashMap<String, UTXOOutput>>,
+}
+
+/// ## The Chronicle Frozen in Time
+/// 
+/// A read-only view of the chain as it stood after chapter `height`,
+/// untouched by anything mined or spent after it was taken. The chapters
+/// and unspent outputs are shared with other snapshots; only the outputs
+/// the later chapters created or spent are its own.
+#[derive(Debug, Clone)]
+pub struct ChainSnapshot {
+    height: u64,
+    chapters: Arc<[Option<Arc<BlockChapter>>]>,
+    unspent_outputs: Arc<HashMap<String, UTXOOutput>>,
+    /// Outputs created after `height`, hidden from the shared set
+    created_since: HashSet<String>,
+    /// Outputs spent after `height`, as they stood before
+    spent_since: HashMap<String, UTXOOutput>,
+}
+
+impl ChainSnapshot {
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+
+    pub fn get_block(&self, chapter_number: u64) -> Option<&BlockChapter> {
+        if chapter_number > self.height {
+            return None;
+        }
+        self.chapters.get(usize::try_from(chapter_number).ok()?)?.as_deref()
+    }
+
+    /// Every output unspent at `height`, keyed `story_id:output_index`
+    fn unspent_outputs(&self) -> impl Iterator<Item = (&String, &UTXOOutput)> {
+        self.unspent_outputs.iter()
+            .filter(|(utxo_key, _)| !self.created_since.contains(*utxo_key) && !self.spent_since.contains_key(*utxo_key))
+            .chain(self.spent_since.iter())
+    }
+
+    pub fn get_balance(&self, address: &[u8]) -> Result<u64, ChronicleError> {
+        self.unspent_outputs()
+            .map(|(_, utxo)| utxo)
+            .filter(|utxo| utxo.recipient_address == address)
+            .try_fold(0u64, |balance, utxo| balance.checked_add(utxo.value_locked))
+            .ok_or(ChronicleError::ValueOverflow)
+    }
+
+    pub fn find_unspent_output(&self, utxo_key: &str) -> Option<&UTXOOutput> {
+        if let Some(spent_later) = self.spent_since.get(utxo_key) {
+            return Some(spent_later);
+        }
+        if self.created_since.contains(utxo_key) {
+            return None;
+        }
+        self.unspent_outputs.get(utxo_key)
+    }
+
+    pub fn find_utxos_for_address(&self, address: &[u8]) -> Vec<(UTXOReference, UTXOOutput)> {
+        self.unspent_outputs()
+            .filter(|(_, utxo)| utxo.recipient_address == address)
+            .filter_map(|(key, utxo)| {
+                let (story_id, output_index) = key.split_once(':')?;
+                Some((UTXOReference {
+                    previous_story_id: story_id.to_string(),
+                    output_index: output_index.parse().ok()?,
+                }, utxo.clone()))
+            })
+            .collect()
+    }
+}
+
+/// ## The Flow of Value, Ready to Be Drawn
+/// 
+/// Stories as nodes and spends as edges, serializable to JSON as it is
+/// or rendered as Graphviz DOT by `to_dot`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxGraph {
+    pub nodes: Vec<TxGraphNode>,
+    pub edges: Vec<TxGraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TxGraphNode {
+    pub story_id: String,
+    pub chapter_number: u64,
+    pub value_created: u64,
+    pub is_coinbase: bool,
+}
+
+/// Output `output_index` of `from_story`, spent by `to_story`. The value is
+/// unknown only when the story that created the output was never stored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TxGraphEdge {
+    pub from_story: String,
+    pub output_index: u32,
+    pub to_story: String,
+    pub value: Option<u64>,
+}
+
+impl TxGraph {
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph tx_graph {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "  \"{}\" [label=\"{}\\nchapter {}\\n{}\"{}];\n",
+                node.story_id, node.story_id, node.chapter_number, node.value_created,
+                if node.is_coinbase { ", shape=box" } else { "" }
+            ));
+        }
+        for edge in &self.edges {
+            let value = edge.value.map(|value| value.to_string()).unwrap_or_else(|| "?".to_string());
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"#{}: {}\"];\n",
+                edge.from_story, edge.to_story, edge.output_index, value
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// ## The Verdict of Every Trial
+#[derive(Debug)]
+pub struct ValidationResult {
+    pub failures: Vec<ChronicleError>,
+}
+
+impl ValidationResult {
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// ## A Story Awaiting Its Narrator's Seal
+/// 
+/// `signable_message` is exactly what the narrator must sign; hand the
+/// resulting signature to `attach_signature` to complete the story.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsignedTransactionStory {
+    pub story: TransactionStory,
+    pub signable_message: Vec<u8>,
+}
+
+/// ## The Mempool Audit Trail
+/// 
+/// Every decision made at the mempool's gate is remembered,
+/// so operators can later learn why a story came or went.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MempoolAuditEntry {
+    pub story_id: String,
+    pub recorded_at: u64,
+    pub verdict: MempoolVerdict,
+}
+
+/// The stories a peer must add to and remove from its copy of our mempool
+/// to catch up with `sequence`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MempoolDelta {
+    pub sequence: u64,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub truncated: bool,
+}
+
+/// Every numbered entry and departure, as far back as it is remembered
+#[derive(Debug, Default)]
+struct MempoolJournal {
+    sequence: u64,
+    present: HashSet<String>,
+    changes: VecDeque<MempoolChange>,
+    forgotten_through: u64,
+}
+
+#[derive(Debug)]
+struct MempoolChange {
+    sequence: u64,
+    story_id: String,
+    added: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MempoolVerdict {
+    Admitted,
+    Rejected(String),
+    Replaced { replaced_by: String },
+    Evicted(String),
+}
+
+/// ## Custom Guardians of the Mempool Gate
+/// 
+/// Operators enforce their own admission rules (blacklists, value caps,
+/// confirmation requirements) by implementing this trait; returning
+/// `Err(reason)` turns the story away.
+pub trait MempoolPolicyHook: Send + Sync {
+    fn name(&self) -> &str;
+    fn evaluate(&self, story: &TransactionStory) -> Result<(), String>;
+}
+
+/// ## Plot Twists: When Things Go Wrong
+#[derive(Debug)]
+pub enum ChronicleError {
+    StoryBearsFalseWitness(String),
+    NarratorLacksResources(String),
+    ChronicleCorrupted(String),
+    ProofOfWorkFailed(String),
+    ConsensusNotReached(String),
+    DatabaseError(String),
+    SerializationError(String),
+    NetworkError(String),
+    InvalidPublicKey(String),
+    InvalidSignature(String),
+    UTXONotFound(String),
+    DuplicateStory(String),
+    InvalidNonce(u64),
+    InsufficientFee(u64),
+    FeeRateTooLow(u64),
+    ValueOverflow,
+    InsufficientFunds { required: u64, available: u64 },
+    ReplacementRejected(String),
+    StoryNotInMempool(String),
+    PolicyRejected { policy: String, reason: String },
+    ChapterNotFound(u64),
+    InvalidTimestamp(String),
+    UnsupportedStoryVersion(String),
+    UnknownValidator(String),
+    KeyDerivationFailed(String),
+    ScriptFailed(String),
+    ReorganizationRejected(String),
+    InvalidConfiguration(String),
+    InvalidChapter(String),
+    ChapterPruned(u64),
+    PruningRefused(String),
+}
+
+impl ChronicleError {
+    /// The reason a peer is told its story or chapter was refused; `None`
+    /// for failures of our own, such as a database error, which are no
+    /// fault of the peer and are not reported back
+    pub fn reject_code(&self) -> Option<RejectCode> {
+        match self {
+            ChronicleError::SerializationError(_) => Some(RejectCode::Malformed),
+            ChronicleError::StoryBearsFalseWitness(_)
+            | ChronicleError::NarratorLacksResources(_)
+            | ChronicleError::ProofOfWorkFailed(_)
+            | ChronicleError::InvalidPublicKey(_)
+            | ChronicleError::InvalidSignature(_)
+            | ChronicleError::UTXONotFound(_)
+            | ChronicleError::InvalidNonce(_)
+            | ChronicleError::ValueOverflow
+            | ChronicleError::InsufficientFunds { .. }
+            | ChronicleError::InvalidTimestamp(_)
+            | ChronicleError::ScriptFailed(_)
+            | ChronicleError::InvalidChapter(_) => Some(RejectCode::Invalid),
+            ChronicleError::UnsupportedStoryVersion(_) => Some(RejectCode::Obsolete),
+            ChronicleError::DuplicateStory(_) | ChronicleError::ReplacementRejected(_) => Some(RejectCode::Duplicate),
+            ChronicleError::PolicyRejected { .. } => Some(RejectCode::NonStandard),
+            ChronicleError::InsufficientFee(_) | ChronicleError::FeeRateTooLow(_) => Some(RejectCode::InsufficientFee),
+            ChronicleError::ReorganizationRejected(_) => Some(RejectCode::Checkpoint),
+            _ => None,
+        }
+    }
+
+    pub fn resolve_the_conflict(self) -> StoryResolution {
+        match self {
+            ChronicleError::StoryBearsFalseWitness(_) => StoryResolution::RejectTheStory,
+            ChronicleError::NarratorLacksResources(_) => StoryResolution::ReturnToSender,
+            ChronicleError::ChronicleCorrupted(_) => StoryResolution::ResyncWithNetwork,
+            ChronicleError::ProofOfWorkFailed(_) => StoryResolution::AdjustDifficulty,
+            ChronicleError::ConsensusNotReached(_) => StoryResolution::SeekMoreValidators,
+            ChronicleError::DatabaseError(_) => StoryResolution::RepairDatabase,
+            ChronicleError::NetworkError(_) => StoryResolution::ReconnectToNetwork,
+            ChronicleError::InvalidSignature(_) => StoryResolution::RejectTheStory,
+            ChronicleError::UTXONotFound(_) => StoryResolution::RejectTheStory,
+            ChronicleError::InsufficientFunds { .. } => StoryResolution::ReturnToSender,
+            ChronicleError::ReplacementRejected(_) => StoryResolution::ReturnToSender,
+            ChronicleError::PolicyRejected { .. } => StoryResolution::RejectTheStory,
+            ChronicleError::InvalidTimestamp(_) => StoryResolution::RejectTheStory,
+            ChronicleError::UnsupportedStoryVersion(_) => StoryResolution::RejectTheStory,
+            ChronicleError::ScriptFailed(_) => StoryResolution::RejectTheStory,
+            ChronicleError::ReorganizationRejected(_) => StoryResolution::RejectTheStory,
+            ChronicleError::InvalidChapter(_) => StoryResolution::RejectTheStory,
+            _ => StoryResolution::LogAndContinue,
+        }
+    }
+}
+
+pub enum StoryResolution {
+    RejectTheStory,
+    ReturnToSender,
+    ResyncWithNetwork,
+    AdjustDifficulty,
+    SeekMoreValidators,
+    RepairDatabase,
+    ReconnectToNetwork,
+    LogAndContinue,
+}
+
+impl ScriptInterpreter {
+    /// An interpreter for spends authorized by `offered_signatures` over `message`
+    pub fn new(message: Vec<u8>, offered_signatures: Vec<Vec<u8>>) -> Self {
+        Self { message, offered_signatures }
+    }
+
+    /// Runs the locking script against the offered signatures together with
+    /// the input's own witness
+    pub fn unlocks(&self, locking_script: &ScriptOfTruth, witness: Option<&InputWitness>) -> Result<(), ChronicleError> {
+        let witness_signatures = witness.map(|witness| witness.signatures.as_slice()).unwrap_or_default();
+        let signatures: Vec<&[u8]> = self.offered_signatures.iter()
+            .chain(witness_signatures)
+            .map(Vec::as_slice)
+            .collect();
+
+        match locking_script.script_type {
+            ScriptType::PayToPublicKey => {
+                let key = locking_script.public_keys.first()
+                    .ok_or_else(|| ChronicleError::ScriptFailed("Pay-to-public-key names no key".to_string()))?;
+                if !self.signed_by(key, &signatures) {
+                    return Err(ChronicleError::ScriptFailed("No signature from the locking key".to_string()));
+                }
+                Ok(())
+            }
+            ScriptType::PayToMultiSig => {
+                let required = locking_script.required_signatures as usize;
+                let mut distinct_keys: Vec<&Vec<u8>> = Vec::new();
+                for key in &locking_script.public_keys {
+                    if !distinct_keys.contains(&key) {
+                        distinct_keys.push(key);
+                    }
+                }
+                if required == 0 || required > distinct_keys.len() {
+                    return Err(ChronicleError::ScriptFailed(format!(
+                        "A {}-of-{} multisig can never be satisfied honestly", required, distinct_keys.len()
+                    )));
+                }
+                let signed = distinct_keys.iter().filter(|key| self.signed_by(key, &signatures)).count();
+                if signed < required {
+                    return Err(ChronicleError::ScriptFailed(format!(
+                        "{} of {} required signatures", signed, required
+                    )));
+                }
+                Ok(())
+            }
+            ScriptType::PayToScriptHash => {
+                let committed = locking_script.public_keys.first()
+                    .ok_or_else(|| ChronicleError::ScriptFailed("Pay-to-script-hash commits to nothing".to_string()))?;
+                let revealed = witness.and_then(|witness| witness.revealed_script.as_ref())
+                    .ok_or_else(|| ChronicleError::ScriptFailed("The committed script was not revealed".to_string()))?;
+                if script_hash(revealed) != *committed {
+                    return Err(ChronicleError::ScriptFailed("The revealed script does not match its hash".to_string()));
+                }
+                if revealed.script_type == ScriptType::PayToScriptHash {
+                    return Err(ChronicleError::ScriptFailed("A script hash cannot reveal another".to_string()));
+                }
+                // The witness's signatures carry over; its revealed script has done its work
+                let inner_witness = InputWitness { signatures: witness_signatures.to_vec(), revealed_script: None };
+                self.unlocks(revealed, Some(&inner_witness))
+            }
+        }
+    }
+
+    fn signed_by(&self, key: &[u8], signatures: &[&[u8]]) -> bool {
+        let Ok(public_key) = PublicKey::from_bytes(key) else {
+            return false;
+        };
+        signatures.iter().any(|signature| Signature::from_bytes(signature)
+            .map(|signature| public_key.verify(&self.message, &signature).is_ok())
+            .unwrap_or(false))
+    }
+}
+
+/// ## Many Voices From One Seed
+/// 
+/// Hierarchical deterministic keys following SLIP-0010 for ed25519: the
+/// same seed and path always yield the same keypair. Ed25519 only permits
+/// hardened derivation, so public keys cannot be derived without the seed;
+/// instead a wallet hands out a list of receiving addresses, which carry
+/// no spending power.
+pub mod hd_wallet {
+    use super::*;
+    use sha2::Sha512;
+
+    /// The HMAC key SLIP-0010 uses to turn a seed into an ed25519 master key
+    const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+    /// Indices at or above this are hardened
+    const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+    /// SHA-512's block size, which HMAC pads its key to
+    const SHA512_BLOCK_BYTES: usize = 128;
+
+    pub struct HdWallet {
+        master_secret: [u8; 32],
+        master_chain_code: [u8; 32],
+    }
+
+    impl HdWallet {
+        /// Seeds must be 16 to 64 bytes, as BIP32 demands
+        pub fn from_seed(seed: &[u8]) -> Result<Self, ChronicleError> {
+            if !(16..=64).contains(&seed.len()) {
+                return Err(ChronicleError::KeyDerivationFailed(format!(
+                    "Seed must be 16 to 64 bytes, got {}", seed.len()
+                )));
+            }
+            let (master_secret, master_chain_code) = hmac_sha512_split(ED25519_SEED_KEY, seed);
+            Ok(Self { master_secret, master_chain_code })
+        }
+
+        /// Derives the keypair at a path such as `m/44'/0'/3'`; every
+        /// segment must be hardened, marked with `'` or `h`
+        pub fn derive(&self, path: &str) -> Result<Keypair, ChronicleError> {
+            let mut secret = self.master_secret;
+            let mut chain_code = self.master_chain_code;
+
+            for index in parse_hardened_path(path)? {
+                let mut data = Vec::with_capacity(37);
+                data.push(0);
+                data.extend_from_slice(&secret);
+                data.extend_from_slice(&index.to_be_bytes());
+                (secret, chain_code) = hmac_sha512_split(&chain_code, &data);
+            }
+
+            let secret_key = SecretKey::from_bytes(&secret)
+                .map_err(|e| ChronicleError::KeyDerivationFailed(e.to_string()))?;
+            let public_key = PublicKey::from(&secret_key);
+            Ok(Keypair { secret: secret_key, public: public_key })
+        }
+
+        /// The first `count` receiving addresses under an account path,
+        /// at `<account_path>/0'`, `<account_path>/1'` and so on - safe to
+        /// share with a service that should only ever receive.
+        pub fn receiving_addresses(&self, account_path: &str, count: u32) -> Result<Vec<Vec<u8>>, ChronicleError> {
+            (0..count)
+                .map(|index| {
+                    let keypair = self.derive(&format!("{}/{}'", account_path.trim_end_matches('/'), index))?;
+                    Ok(keypair.public.to_bytes().to_vec())
+                })
+                .collect()
+        }
+    }
+
+    fn parse_hardened_path(path: &str) -> Result<Vec<u32>, ChronicleError> {
+        let mut segments = path.split('/');
+        if segments.next() != Some("m") {
+            return Err(ChronicleError::KeyDerivationFailed(format!("Path {} must begin with m", path)));
+        }
+
+        segments
+            .map(|segment| {
+                let index = segment.strip_suffix('\'')
+                    .or_else(|| segment.strip_suffix('h'))
+                    .ok_or_else(|| ChronicleError::KeyDerivationFailed(format!(
+                        "Segment {} is not hardened; ed25519 keys only derive hardened children", segment
+                    )))?;
+                let index: u32 = index.parse()
+                    .map_err(|_| ChronicleError::KeyDerivationFailed(format!("Segment {} is not an index", segment)))?;
+                if index >= HARDENED_OFFSET {
+                    return Err(ChronicleError::KeyDerivationFailed(format!("Index {} is too large", index)));
+                }
+                Ok(index + HARDENED_OFFSET)
+            })
+            .collect()
+    }
+
+    /// HMAC-SHA512, split into the derived key (left half) and chain code (right half)
+    fn hmac_sha512_split(key: &[u8], data: &[u8]) -> ([u8; 32], [u8; 32]) {
+        let mut padded_key = [0u8; SHA512_BLOCK_BYTES];
+        if key.len() > SHA512_BLOCK_BYTES {
+            let digest = Sha512::digest(key);
+            padded_key[..digest.len()].copy_from_slice(&digest);
+        } else {
+            padded_key[..key.len()].copy_from_slice(key);
+        }
+
+        let inner_pad: Vec<u8> = padded_key.iter().map(|byte| byte ^ 0x36).collect();
+        let outer_pad: Vec<u8> = padded_key.iter().map(|byte| byte ^ 0x5c).collect();
+
+        let inner = Sha512::new().chain_update(&inner_pad).chain_update(data).finalize();
+        let outer = Sha512::new().chain_update(&outer_pad).chain_update(inner).finalize();
+
+        let mut left = [0u8; 32];
+        let mut right = [0u8; 32];
+        left.copy_from_slice(&outer[..32]);
+        right.copy_from_slice(&outer[32..64]);
+        (left, right)
+    }
+}
+
+// Utility functions
+/// What a validator signs to back a chapter at a height
+fn chapter_claim_message(height: u64, chapter_essence: &str) -> Vec<u8> {
+    let mut message = height.to_le_bytes().to_vec();
+    message.extend_from_slice(chapter_essence.as_bytes());
+    message
+}
+
+/// Every output a story spends, the sponsor's included
+fn story_spends(story: &TransactionStory) -> impl Iterator<Item = &UTXOReference> {
+    story.inputs_consumed.iter()
+        .chain(story.fee_sponsorship.iter().flat_map(|sponsorship| sponsorship.sponsor_inputs.iter()))
+}
+
+/// Every output a story creates, keyed `story_id:output_index`; a sponsor's
+/// change takes the index after the story's own outputs
+fn story_creates(story: &TransactionStory) -> impl Iterator<Item = (String, &UTXOOutput)> {
+    story.outputs_created.iter()
+        .chain(story.fee_sponsorship.iter().flat_map(|sponsorship| sponsorship.sponsor_change.iter()))
+        .enumerate()
+        .map(move |(output_index, output)| (format!("{}:{}", story.story_id, output_index), output))
+}
+
+fn stories_share_inputs(first: &TransactionStory, second: &TransactionStory) -> bool {
+    story_spends(first).any(|input| story_spends(second).any(|other| other == input))
+}
+
+fn story_size_bytes(story: &TransactionStory) -> u64 {
+    bincode::serialized_size(story).unwrap_or(1).max(1)
+}
+
+/// What a story pays per thousand serialized bytes, fine enough that two
+/// stories paying a little over two per byte are not told apart as equals
+fn story_fee_rate(story: &TransactionStory) -> u64 {
+    let rate = story.story_fee as u128 * FEE_RATE_BYTES / story_size_bytes(story) as u128;
+    u64::try_from(rate).unwrap_or(u64::MAX)
+}
+
+/// What a pay-to-script-hash output commits to
+fn script_hash(script: &ScriptOfTruth) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(bincode::serialize(script).unwrap_or_default());
+    hasher.finalize().to_vec()
+}
+
+fn nonce_key(narrator: &[u8]) -> String {
+    format!("nonce_{}", hex::encode(narrator))
+}
+
+/// A network message as it travels a stream: its bincode length as four
+/// big-endian bytes, then the bincode itself
+fn frame_network_message(message: &NetworkMessage) -> Result<Vec<u8>, ChronicleError> {
+    let body = bincode::serialize(message)
+        .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+    let length = u32::try_from(body.len())
+        .map_err(|_| ChronicleError::SerializationError("Message too long to frame".to_string()))?;
+    let mut frame = length.to_be_bytes().to_vec();
+    frame.extend_from_slice(&body);
+    Ok(frame)
+}
+
+/// Reads one framed message off a stream, refusing frames longer than
+/// `MAX_NETWORK_FRAME_BYTES`
+fn read_network_frame(stream: &mut impl Read) -> Result<NetworkMessage, ChronicleError> {
+    let mut length = [0u8; 4];
+    stream.read_exact(&mut length)
+        .map_err(|e| ChronicleError::NetworkError(e.to_string()))?;
+    let length = u32::from_be_bytes(length);
+    if length > MAX_NETWORK_FRAME_BYTES {
+        return Err(ChronicleError::NetworkError(format!("A frame of {} bytes is too long", length)));
+    }
+
+    let mut body = vec![0u8; length as usize];
+    stream.read_exact(&mut body)
+        .map_err(|e| ChronicleError::NetworkError(e.to_string()))?;
+    bincode::deserialize(&body)
+        .map_err(|e| ChronicleError::SerializationError(e.to_string()))
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Splits `value` in proportion to the weights. Each share is rounded
+/// down, and the units left over go one apiece to the largest fractions
+/// cut off, earlier entries first on ties, so the shares always sum to `value`.
+fn split_by_weight(value: u64, weighted: &[(Vec<u8>, u64)]) -> Vec<u64> {
+    let total_weight: u128 = weighted.iter().map(|(_, weight)| *weight as u128).sum();
+    if total_weight == 0 {
+        return vec![0; weighted.len()];
+    }
+
+    let exact: Vec<(u64, u128)> = weighted.iter()
+        .map(|(_, weight)| {
+            let scaled = value as u128 * *weight as u128;
+            ((scaled / total_weight) as u64, scaled % total_weight)
+        })
+        .collect();
+    let mut shares: Vec<u64> = exact.iter().map(|(share, _)| *share).collect();
+    let left_over = value - shares.iter().sum::<u64>();
+
+    let mut by_fraction: Vec<usize> = (0..exact.len()).collect();
+    by_fraction.sort_by(|&a, &b| exact[b].1.cmp(&exact[a].1).then(a.cmp(&b)));
+    for &index in by_fraction.iter().take(left_over as usize) {
+        shares[index] += 1;
+    }
+    shares
+}
+
+/// ## The Root of Everything Unspent
+/// 
+/// A merkle root over the unspent outputs in key order, each leaf hashing
+/// an output's `story_id:index` key with its serialized form, and an odd
+/// node at any level paired with itself as in a chapter's merkle tree.
+/// No outputs at all root to zeros.
+fn utxo_state_root(unspent: &BTreeMap<String, UTXOOutput>) -> Result<String, ChronicleError> {
+    let mut hashes = unspent.iter()
+        .map(|(utxo_key, output)| {
+            let output_data = bincode::serialize(output)
+                .map_err(|e| ChronicleError::SerializationError(e.to_string()))?;
+            let mut hasher = Sha256::new();
+            hasher.update(utxo_key.as_bytes());
+            hasher.update(&output_data);
+            Ok(format!("{:x}", hasher.finalize()))
+        })
+        .collect::<Result<Vec<String>, ChronicleError>>()?;
+    if hashes.is_empty() {
+        return Ok("0".repeat(64));
+    }
+
+    while hashes.len() > 1 {
+        hashes = hashes.chunks(2)
+            .map(|pair| BlockchainChronicler::merkle_parent_hash(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    Ok(hashes.remove(0))
+}
+
+/// The middle of the timestamps once sorted, the later of the two middles
+/// for an even count; zero for none
+fn median_timestamp(timestamps: &[u64]) -> u64 {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort_unstable();
+    sorted.get(sorted.len() / 2).copied().unwrap_or(0)
+}
+
+fn generate_transaction_id() -> String {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let random_bytes: [u8; 16] = rng.gen();
+    hex::encode(random_bytes)
+}
+
+// =============================================================================
+// The Tales - Where Alternative Endings Are Explored
+// =============================================================================
+
+#[cfg(test)]
+mod tales {
+    use super::*;
+
+    /// A fresh data directory, named after the tale that writes in it
+    fn tale_directory(tale_name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("chronicle_tale_{}_{}", tale_name, generate_transaction_id()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// A chronicle gathered under `config` in a data directory of its own,
+    /// with no mining heart beating.
+    async fn chronicle_with(tale_name: &str, config: ChronicleConfiguration) -> BlockchainChronicler {
+        BlockchainChronicler::chronicle_gathers_its_cast(ChronicleConfiguration {
+            data_directory: tale_directory(tale_name),
+            ..config
+        }).await.unwrap()
+    }
+
+    /// A chronicle with its own data directory and no mining heart,
+    /// so the mempool only changes when a tale changes it.
+    async fn quiet_chronicle(tale_name: &str) -> BlockchainChronicler {
+        chronicle_with(tale_name, ChronicleConfiguration::default()).await
+    }
+
+    /// Places an unspent output owned by the narrator straight into the ledger.
+    /// The story that created it is remembered, so the output can be
+    /// recovered should a chapter spending it be unwound.
+    fn grant_unspent_tale(chronicle: &BlockchainChronicler, narrator: &Keypair, story_id: &str, value: u64) {
+        let funding = funding_story(chronicle, narrator, story_id, value);
+        chronicle.utxo_ledger.db
+            .insert(format!("{}:0", story_id), bincode::serialize(&funding.outputs_created[0]).unwrap())
+            .unwrap();
+        chronicle.chain_repository.tx_db
+            .insert(story_id, bincode::serialize(&funding).unwrap())
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn the_audit_trail_remembers_duplicates_and_replacements() {
+        let mut chronicle = quiet_chronicle("mempool_audit").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let original = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(original.clone()).await.unwrap();
+
+        let duplicate = chronicle.transaction_story_arrives(original.clone()).await;
+        assert!(matches!(duplicate, Err(ChronicleError::DuplicateStory(_))));
+
+        let replacement = chronicle.create_transaction(&alice, &bob, 10_000, 2_000).await.unwrap();
+        chronicle.transaction_story_arrives(replacement.clone()).await.unwrap();
+
+        let audit = chronicle.mempool_audit().await;
+        let verdicts: Vec<(&str, &MempoolVerdict)> = audit.iter()
+            .map(|entry| (entry.story_id.as_str(), &entry.verdict))
+            .collect();
+
+        assert_eq!(verdicts.len(), 4);
+        assert_eq!(verdicts[0], (original.story_id.as_str(), &MempoolVerdict::Admitted));
+        assert!(matches!(verdicts[1], (id, MempoolVerdict::Rejected(reason))
+            if id == original.story_id && reason.contains("DuplicateStory")));
+        assert_eq!(verdicts[2], (original.story_id.as_str(), &MempoolVerdict::Replaced {
+            replaced_by: replacement.story_id.clone(),
+        }));
+        assert_eq!(verdicts[3], (replacement.story_id.as_str(), &MempoolVerdict::Admitted));
+
+        let mempool = chronicle.mempool_of_pending_tales.lock().unwrap();
+        assert_eq!(mempool.len(), 1);
+        assert_eq!(mempool[0].story_id, replacement.story_id);
+    }
+
+    #[tokio::test]
+    async fn a_returning_peer_hears_only_what_changed() {
+        let mut chronicle = quiet_chronicle("mempool_delta").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let carol = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &carol, "carol_funding", 100_000);
+
+        let carol_pays = chronicle.create_transaction(&carol, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(carol_pays.clone()).await.unwrap();
+        let last_synced = chronicle.mempool_sequence();
+
+        // Alice's first story is replaced before the peer returns, and Carol's is mined
+        let original = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(original).await.unwrap();
+        let replacement = chronicle.create_transaction(&alice, &bob, 10_000, 2_000).await.unwrap();
+        chronicle.transaction_story_arrives(replacement.clone()).await.unwrap();
+        let chapter = honestly_mined_chapter(&chronicle, vec![carol_pays.clone()]).await;
+        chronicle.receive_block_chapter(chapter).await.unwrap();
+
+        let delta = chronicle.get_mempool_delta(last_synced).await;
+        assert_eq!(delta.added, vec![replacement.story_id.clone()]);
+        assert_eq!(delta.removed, vec![carol_pays.story_id.clone()]);
+        assert!(!delta.truncated);
+        assert_eq!(delta.sequence, chronicle.mempool_sequence());
+
+        let caught_up = chronicle.get_mempool_delta(delta.sequence).await;
+        assert!(caught_up.added.is_empty() && caught_up.removed.is_empty());
+        let from_the_start = chronicle.get_mempool_delta(0).await;
+        assert_eq!((from_the_start.added, from_the_start.removed), (vec![replacement.story_id], vec![]));
+    }
+
+    #[tokio::test]
+    async fn a_story_signed_far_away_still_rings_true() {
+        let mut chronicle = quiet_chronicle("external_signer").await;
+        let air_gapped = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &air_gapped, "cold_funding", 50_000);
+
+        let narrator = air_gapped.public.to_bytes();
+        let fee = chronicle.configuration.min_transaction_fee;
+        let unsigned = chronicle.build_unsigned_transaction(&narrator, &bob, 20_000, fee).await.unwrap();
+        assert!(unsigned.story.digital_signature.is_empty());
+
+        // The signing happens out of process, seeing only the message
+        let signature = air_gapped.sign(&unsigned.signable_message).to_bytes();
+
+        let stranger = Keypair::generate(&mut OsRng);
+        let forged = stranger.sign(&unsigned.signable_message).to_bytes();
+        assert!(chronicle.attach_signature(unsigned.clone(), &forged, &narrator).is_err());
+
+        let signed = chronicle.attach_signature(unsigned, &signature, &narrator).unwrap();
+        assert!(chronicle.validate_verbose(&signed).await.is_valid());
+        chronicle.transaction_story_arrives(signed).await.unwrap();
+    }
+
+    struct ValueCeiling(u64);
+
+    impl MempoolPolicyHook for ValueCeiling {
+        fn name(&self) -> &str {
+            "value_ceiling"
+        }
+
+        fn evaluate(&self, story: &TransactionStory) -> Result<(), String> {
+            let total: u64 = story.outputs_created.iter()
+                .filter(|output| output.recipient_address != story.public_key_of_narrator)
+                .map(|output| output.value_locked)
+                .sum();
+            if total > self.0 {
+                return Err(format!("moves {} above the ceiling of {}", total, self.0));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn the_operators_policy_stands_beside_the_built_in_trials() {
+        let mut chronicle = quiet_chronicle("policy_hook").await;
+        chronicle.register_mempool_policy(Box::new(ValueCeiling(30_000)));
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "modest_funding", 25_000);
+        grant_unspent_tale(&chronicle, &alice, "grand_funding", 100_000);
+
+        let grand = chronicle.create_transaction(&alice, &bob, 90_000, 1_000).await.unwrap();
+        let verdict = chronicle.transaction_story_arrives(grand).await;
+        assert!(matches!(verdict, Err(ChronicleError::PolicyRejected { ref policy, .. }) if policy == "value_ceiling"));
+
+        let mut forged = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        forged.digital_signature = vec![0; 64];
+        let verdict = chronicle.transaction_story_arrives(forged).await;
+        assert!(matches!(verdict, Err(ChronicleError::StoryBearsFalseWitness(_) | ChronicleError::InvalidSignature(_))));
+
+        let modest = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(modest).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_forgotten_story_is_told_again() {
+        let rebroadcast_interval = Duration::from_millis(100);
+        let mut narrator_node = chronicle_with("rebroadcast", ChronicleConfiguration {
+            rebroadcast_interval,
+            ..ChronicleConfiguration::default()
+        }).await;
+        let mut peer_node = quiet_chronicle("rebroadcast_peer").await;
+
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&narrator_node, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&peer_node, &alice, "alice_funding", 100_000);
+
+        let mut wire = narrator_node.take_outgoing_messages();
+        let story = narrator_node.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        narrator_node.submit_own_story(story.clone()).await.unwrap();
+
+        let Some(NetworkMessage::NewTransactionStory(heard)) = wire.recv().await else {
+            panic!("the story was never announced");
+        };
+        peer_node.transaction_story_arrives(heard).await.unwrap();
+
+        // The peer restarts and forgets everything it had pending
+        peer_node.mempool_of_pending_tales.lock().unwrap().clear();
+
+        let _rebroadcaster = narrator_node.begin_rebroadcasting_own_stories();
+        let retold = tokio::time::timeout(rebroadcast_interval * 2, wire.recv()).await
+            .expect("the story was not retold within the interval");
+        let Some(NetworkMessage::NewTransactionStory(retold)) = retold else {
+            panic!("the retelling was not a transaction story");
+        };
+        assert_eq!(retold.story_id, story.story_id);
+
+        peer_node.transaction_story_arrives(retold).await.unwrap();
+        let peer_mempool = peer_node.mempool_of_pending_tales.lock().unwrap();
+        assert!(peer_mempool.iter().any(|pending| pending.story_id == story.story_id));
+    }
+
+    /// A signed story paying Bob through a script-hash output, at the given version.
+    async fn script_hash_story(
+        chronicle: &BlockchainChronicler,
+        alice: &Keypair,
+        version: u32,
+    ) -> TransactionStory {
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let mut story = chronicle.build_unsigned_transaction(&alice.public.to_bytes(), &bob, 10_000, 1_000)
+            .await.unwrap().story;
+        story.version = version;
+        story.outputs_created[0].locking_script.script_type = ScriptType::PayToScriptHash;
+        chronicle.narrator_signs_the_story(&mut story, alice);
+        story
+    }
+
+    #[tokio::test]
+    async fn an_old_story_cannot_speak_in_script_hashes() {
+        let mut chronicle = quiet_chronicle("story_versions").await;
+        let alice = Keypair::generate(&mut OsRng);
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let premature = script_hash_story(&chronicle, &alice, 1).await;
+        let refused = chronicle.transaction_story_arrives(premature).await;
+        assert!(matches!(refused, Err(ChronicleError::UnsupportedStoryVersion(ref reason))
+            if reason.contains("require version 2")));
+
+        let from_the_future = script_hash_story(&chronicle, &alice, CURRENT_STORY_VERSION + 1).await;
+        let refused = chronicle.transaction_story_arrives(from_the_future).await;
+        assert!(matches!(refused, Err(ChronicleError::UnsupportedStoryVersion(ref reason))
+            if reason.contains("newer")));
+
+        let well_versioned = script_hash_story(&chronicle, &alice, SCRIPT_HASH_MIN_VERSION).await;
+        chronicle.transaction_story_arrives(well_versioned).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_two_faced_validator_is_caught_and_punished() {
+        let mut chronicle = quiet_chronicle("equivocation").await;
+        let fickle = Keypair::generate(&mut OsRng);
+        chronicle.admit_validator("fickle_validator", 5_000, fickle.public);
+
+        let sign = |essence: &str| fickle.sign(&chapter_claim_message(7, essence)).to_bytes().to_vec();
+        let first = chronicle.validator_signs_chapter("fickle_validator", 7, "chapter_a", &sign("chapter_a")).unwrap();
+        assert!(first.is_none());
+        let repeated = chronicle.validator_signs_chapter("fickle_validator", 7, "chapter_a", &sign("chapter_a")).unwrap();
+        assert!(repeated.is_none());
+        assert_eq!(chronicle.validator_reputation("fickle_validator"), 1.0);
+
+        let evidence = chronicle.validator_signs_chapter("fickle_validator", 7, "chapter_b", &sign("chapter_b"))
+            .unwrap()
+            .expect("conflicting signatures must yield evidence");
+        assert_eq!(evidence.validator_id, "fickle_validator");
+        assert_eq!(evidence.height, 7);
+        assert_eq!(evidence.first_claim.chapter_essence, "chapter_a");
+        assert_eq!(evidence.conflicting_claim.chapter_essence, "chapter_b");
+
+        assert_eq!(chronicle.validator_reputation("fickle_validator"), 0.0);
+        let penalties = &chronicle.validator_council.reputation_system.penalty_system;
+        assert_eq!(penalties.cumulative_penalties["fickle_validator"], 1);
+        assert!(matches!(penalties.recent_penalties["fickle_validator"][..], [(7, PenaltyType::Equivocation)]));
+
+        // A forged signature cannot frame the validator
+        let forged = Keypair::generate(&mut OsRng).sign(&chapter_claim_message(8, "chapter_c")).to_bytes();
+        let framed = chronicle.validator_signs_chapter("fickle_validator", 8, "chapter_c", &forged);
+        assert!(matches!(framed, Err(ChronicleError::InvalidSignature(_))));
+    }
+
+    #[test]
+    fn a_repeat_offender_wears_down_its_reputation_then_its_stake() {
+        let mut council = ValidatorCouncil::new();
+        council.admit_validator("careless_validator", 1_000, Keypair::generate(&mut OsRng).public);
+
+        let mut outcomes = Vec::new();
+        for _ in 0..SLASHING_PENALTY_THRESHOLD {
+            outcomes.push(council.penalize_validator("careless_validator", PenaltyType::InvalidSignature).unwrap());
+        }
+        let careless = &council.council_members["careless_validator"];
+        assert!((careless.reputation_score - 0.7).abs() < 1e-9);
+        assert_eq!(outcomes.iter().map(|outcome| outcome.slash_due).collect::<Vec<_>>(), vec![false, false, true]);
+        assert_eq!(careless.stake_in_truth, 900);
+        assert!(outcomes.windows(2).all(|pair| pair[1].reputation_score < pair[0].reputation_score));
+
+        let penalties = &council.reputation_system.penalty_system;
+        assert_eq!(penalties.cumulative_penalties["careless_validator"], 3);
+        assert!(penalties.recent_penalties["careless_validator"].iter()
+            .all(|(at, penalty)| *at > 0 && matches!(penalty, PenaltyType::InvalidSignature)));
+        assert!(council.penalize_validator("stranger", PenaltyType::DoubleSpending).is_none());
+    }
+
+    #[tokio::test]
+    async fn a_validator_pays_for_forging_and_double_spending() {
+        let mut chronicle = quiet_chronicle("validator_penalties").await;
+        let validator = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        chronicle.admit_validator("shady_validator", 1_000, validator.public);
+        grant_unspent_tale(&chronicle, &validator, "validator_funding", 100_000);
+
+        let mut forged = chronicle.create_transaction(&validator, &bob, 10_000, 1_000).await.unwrap();
+        forged.digital_signature = Keypair::generate(&mut OsRng).sign(b"something else").to_bytes().to_vec();
+        assert!(chronicle.transaction_story_arrives(forged).await.is_err());
+        assert!((chronicle.validator_reputation("shady_validator") - 0.9).abs() < 1e-9);
+
+        let honest = chronicle.create_transaction(&validator, &bob, 10_000, 1_000).await.unwrap();
+        let twice_spent = chronicle.create_transaction(&validator, &bob, 20_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![honest]).await;
+        assert!(matches!(
+            chronicle.transaction_story_arrives(twice_spent).await,
+            Err(ChronicleError::UTXONotFound(_))
+        ));
+        assert!((chronicle.validator_reputation("shady_validator") - 0.4).abs() < 1e-9);
+
+        let penalties = &chronicle.validator_council.reputation_system.penalty_system;
+        assert!(matches!(
+            penalties.recent_penalties["shady_validator"][..],
+            [(_, PenaltyType::InvalidSignature), (_, PenaltyType::DoubleSpending)]
+        ));
+    }
+
+    #[test]
+    fn one_seed_the_same_path_the_same_voice() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let wallet = hd_wallet::HdWallet::from_seed(&seed).unwrap();
+
+        // SLIP-0010 ed25519 test vector 1
+        assert_eq!(
+            hex::encode(wallet.derive("m").unwrap().secret.to_bytes()),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(wallet.derive("m/0'").unwrap().secret.to_bytes()),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+
+        let first = wallet.derive("m/44'/0'/0'").unwrap();
+        let again = hd_wallet::HdWallet::from_seed(&seed).unwrap().derive("m/44h/0h/0h").unwrap();
+        assert_eq!(first.to_bytes(), again.to_bytes());
+
+        let sibling = wallet.derive("m/44'/0'/1'").unwrap();
+        assert_ne!(first.public.to_bytes(), sibling.public.to_bytes());
+
+        let receiving = wallet.receiving_addresses("m/44'/0'", 2).unwrap();
+        assert_eq!(receiving, vec![first.public.to_bytes().to_vec(), sibling.public.to_bytes().to_vec()]);
+
+        assert!(matches!(wallet.derive("m/44'/0"), Err(ChronicleError::KeyDerivationFailed(_))));
+        assert!(matches!(hd_wallet::HdWallet::from_seed(&[0; 8]), Err(ChronicleError::KeyDerivationFailed(_))));
+    }
+
+    #[test]
+    fn the_reward_halves_exactly_on_the_boundary() {
+        let config = ChronicleConfiguration {
+            base_mining_reward: 5_000_000_001,
+            reward_halving_interval: 210_000,
+            ..ChronicleConfiguration::default()
+        };
+        let reward_at = |height| BlockchainChronicler::mining_reward_at_height(height, &config).unwrap();
+
+        assert_eq!(reward_at(0), 5_000_000_001);
+        assert_eq!(reward_at(209_999), 5_000_000_001);
+        // The odd half-unit is rounded away at the boundary itself
+        assert_eq!(reward_at(210_000), 2_500_000_000);
+        assert_eq!(reward_at(420_000), 1_250_000_000);
+        assert_eq!(reward_at(210_000 * 64), 0);
+        assert_eq!(reward_at(u64::MAX), 0);
+    }
+
+    #[tokio::test]
+    async fn a_zero_interval_is_refused_rather_than_divided_by() {
+        let config = ChronicleConfiguration {
+            reward_halving_interval: 0,
+            ..ChronicleConfiguration::default()
+        };
+        assert!(matches!(BlockchainChronicler::mining_reward_at_height(1, &config),
+            Err(ChronicleError::InvalidConfiguration(ref reason)) if reason.contains("reward_halving_interval")));
+        assert!(matches!(BlockchainChronicler::chronicle_gathers_its_cast(config).await,
+            Err(ChronicleError::InvalidConfiguration(_))));
+
+        let chronicle = quiet_chronicle("zero_interval").await;
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let retargetless = ChronicleConfiguration {
+            difficulty_adjustment_interval: 0,
+            ..ChronicleConfiguration::default()
+        };
+        assert!(matches!(
+            BlockchainChronicler::calculate_current_difficulty(&tip, &chronicle.chain_repository, &retargetless),
+            Err(ChronicleError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_time_warp_cannot_drag_the_difficulty_down() {
+        let config = ChronicleConfiguration {
+            difficulty_adjustment_interval: 4,
+            ..ChronicleConfiguration::default()
+        };
+        let chronicle = chronicle_with("time_warp", config.clone()).await;
+        let commit_dated = |timestamp: u64| {
+            let chain_repo = chronicle.chain_repository.clone();
+            let utxo_ledger = chronicle.utxo_ledger.clone();
+            async move {
+                let tip = chain_repo.get_chain_tip().await.unwrap().unwrap();
+                let mut chapter = chapter_following(&tip, Vec::new(), format!("chapter_{}", tip.chapter_number + 1));
+                chapter.timestamp_of_creation = timestamp;
+                utxo_ledger.apply_block(&chapter).await.unwrap();
+                chain_repo.add_block_chapter(chapter).await.unwrap();
+            }
+        };
+
+        // The miners hold the clock still through the interval
+        let held = current_timestamp() - 24 * 60 * 60;
+        for _ in 0..3 {
+            commit_dated(held).await;
+        }
+
+        // A closing chapter dated before the interval began is refused outright
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let mut backdated = chapter_following(&tip, Vec::new(), "chapter_4".to_string());
+        backdated.timestamp_of_creation = held - 1;
+        assert!(matches!(
+            BlockchainChronicler::chapter_closes_its_interval_in_time(&backdated, &chronicle.chain_repository, &config),
+            Err(ChronicleError::InvalidTimestamp(_))
+        ));
+
+        // And one leaping ten days ahead counts only a few block times later,
+        // so the retarget does not ease at all
+        commit_dated(held + 10 * 24 * 60 * 60).await;
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let previous_target = tip.proof_of_storytelling.difficulty_bits.to_target().unwrap();
+        let next_target = BlockchainChronicler::calculate_current_difficulty(&tip, &chronicle.chain_repository, &config)
+            .unwrap()
+            .to_target()
+            .unwrap();
+        assert!(next_target <= previous_target);
+        assert!(next_target < previous_target.scaled(4, 1));
+    }
+
+    #[tokio::test]
+    async fn a_burst_of_swift_chapters_tightens_the_target() {
+        let config = ChronicleConfiguration {
+            difficulty_adjustment_interval: 4,
+            ..ChronicleConfiguration::default()
+        };
+        let chronicle = chronicle_with("retarget", config.clone()).await;
+
+        // Four chapters within moments of each other, against a ten-minute target
+        for _ in 0..4 {
+            commit_chapter_of(&chronicle, Vec::new()).await;
+        }
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(tip.chapter_number, 4);
+
+        let previous_target = tip.proof_of_storytelling.difficulty_bits.to_target().unwrap();
+        let next_bits = BlockchainChronicler::calculate_current_difficulty(&tip, &chronicle.chain_repository, &config)
+            .unwrap();
+        assert!(next_bits.to_target().unwrap() < previous_target);
+        assert_eq!(next_bits, previous_target.scaled(1, 4).to_compact());
+
+        // Between retargets, the target carries over untouched
+        commit_chapter_of(&chronicle, Vec::new()).await;
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(
+            BlockchainChronicler::calculate_current_difficulty(&tip, &chronicle.chain_repository, &config).unwrap(),
+            tip.proof_of_storytelling.difficulty_bits
+        );
+    }
+
+    #[test]
+    fn a_hash_on_the_line_still_counts() {
+        let target = DifficultyTarget::from_hex(
+            "00000fffffffffffffffffffffffffffffffffffffffffffffffffffffffff00"
+        ).unwrap();
+
+        let at_the_line = "00000fffffffffffffffffffffffffffffffffffffffffffffffffffffffff00";
+        let just_above = "00000fffffffffffffffffffffffffffffffffffffffffffffffffffffffff01";
+        let just_below = "00000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffeff";
+        assert!(BlockchainChronicler::hash_meets_difficulty(at_the_line, &target));
+        assert!(!BlockchainChronicler::hash_meets_difficulty(just_above, &target));
+        assert!(BlockchainChronicler::hash_meets_difficulty(just_below, &target));
+
+        // Leading zeros alone are not enough: the digits after them matter too
+        let far_above = "0000100000000000000000000000000000000000000000000000000000000000";
+        assert!(!BlockchainChronicler::hash_meets_difficulty(far_above, &target));
+        assert!(!BlockchainChronicler::hash_meets_difficulty("not a hash", &target));
+
+        assert_eq!(
+            GENESIS_DIFFICULTY_TARGET.to_string(),
+            format!("0{}", "f".repeat(63))
+        );
+        assert_eq!(DifficultyTarget::MAX.scaled(2, 1), DifficultyTarget::MAX);
+        assert_eq!(GENESIS_DIFFICULTY_TARGET.scaled(1, 16), DifficultyTarget::with_leading_zero_bits(8));
+    }
+
+    #[test]
+    fn a_target_folds_into_bits_and_unfolds_again() {
+        // Bitcoin's first target survives the trip exactly
+        let bitcoin_bits = CompactTarget::from_bits(0x1d00ffff);
+        let bitcoin_target = bitcoin_bits.to_target().unwrap();
+        assert_eq!(bitcoin_target, DifficultyTarget::from_be_bytes({
+            let mut bytes = [0u8; 32];
+            bytes[4] = 0xff;
+            bytes[5] = 0xff;
+            bytes
+        }));
+        assert_eq!(bitcoin_target.to_compact(), bitcoin_bits);
+
+        // Folding keeps the leading digits and rounds the rest away
+        let genesis_bits = GENESIS_DIFFICULTY_TARGET.to_compact();
+        assert_eq!(genesis_bits.to_bits(), 0x200f_ffff);
+        let unfolded = genesis_bits.to_target().unwrap();
+        assert!(unfolded <= GENESIS_DIFFICULTY_TARGET);
+        assert_eq!(unfolded.to_compact(), genesis_bits);
+
+        // A leading digit with its top bit set borrows a byte rather than turn negative
+        assert_eq!(DifficultyTarget::MAX.to_compact().to_bits(), 0x2100_ffff);
+        assert_eq!(DifficultyTarget::from_be_bytes([0; 32]).to_compact().to_bits(), 0);
+        assert!(CompactTarget::from_bits(0x0480_0001).to_target().is_err());
+        assert!(CompactTarget::from_bits(0x2201_0000).to_target().is_err());
+
+        // The base difficulty is a small number now, not a 256-bit literal
+        assert_eq!(GENESIS_DIFFICULTY_TARGET.difficulty(), 16);
+        assert_eq!(unfolded.difficulty(), 16);
+        assert_eq!(DifficultyTarget::from_difficulty(16), GENESIS_DIFFICULTY_TARGET);
+        assert_eq!(DifficultyTarget::MAX.difficulty(), 1);
+        assert_eq!(DifficultyTarget::from_be_bytes([0; 32]).difficulty(), u64::MAX);
+        for difficulty in [1, 2, 3, 16, 1_000, 65_536, 1 << 40] {
+            let target = DifficultyTarget::from_difficulty(difficulty);
+            assert_eq!(target.difficulty(), difficulty);
+            let folded = target.to_compact().to_target().unwrap();
+            assert!(folded <= target);
+            assert!(folded.difficulty() >= difficulty);
+        }
+    }
+
+    /// Commits the next chapter straight to the repository, skipping proof of work.
+    fn chapter_following(previous: &BlockChapter, tales: Vec<TransactionStory>, essence: String) -> BlockChapter {
+        BlockChapter {
+            chapter_number: previous.chapter_number + 1,
+            // Chapters told within the same second still move past the median time past
+            timestamp_of_creation: current_timestamp().max(previous.timestamp_of_creation + 1),
+            previous_chapter_essence: previous.chapter_essence.clone(),
+            transaction_tales: tales,
+            merkle_tree_of_truth: String::new(),
+            chapter_essence: essence,
+            proof_of_storytelling: previous.proof_of_storytelling.clone(),
+            chapter_size_bytes: 0,
+            utxo_state_root: None,
+        }
+    }
+
+    async fn commit_chapter_of(chronicle: &BlockchainChronicler, tales: Vec<TransactionStory>) {
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let essence = format!("chapter_{}", tip.chapter_number + 1);
+        let chapter = chapter_following(&tip, tales, essence);
+        chronicle.utxo_ledger.apply_block(&chapter).await.unwrap();
+        chronicle.chain_repository.add_block_chapter(chapter).await.unwrap();
+    }
+
+    /// A story with no inputs that pays `value` to the narrator, so the
+    /// funding it creates has a source the chain can look back on
+    fn funding_story(chronicle: &BlockchainChronicler, narrator: &Keypair, story_id: &str, value: u64) -> TransactionStory {
+        let owner = narrator.public.to_bytes().to_vec();
+        let mut story = TransactionStory {
+            version: CURRENT_STORY_VERSION,
+            story_id: story_id.to_string(),
+            inputs_consumed: Vec::new(),
+            outputs_created: vec![UTXOOutput {
+                recipient_address: owner.clone(),
+                value_locked: value,
+                locking_script: ScriptOfTruth {
+                    script_type: ScriptType::PayToPublicKey,
+                    required_signatures: 1,
+                    public_keys: vec![owner.clone()],
+                },
+            }],
+            story_fee: 0,
+            timestamp_of_telling: current_timestamp(),
+            transaction_nonce: 1,
+            digital_signature: Vec::new(),
+            public_key_of_narrator: owner,
+            fee_sponsorship: None,
+            input_witnesses: Vec::new(),
+            coinbase_height: None,
+        };
+        chronicle.narrator_signs_the_story(&mut story, narrator);
+        story
+    }
+
+    #[tokio::test]
+    async fn a_mined_chapter_moves_the_coins() {
+        let chronicle = quiet_chronicle("apply_block").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        BlockchainChronicler::mine_new_chapter(
+            vec![payment.clone()],
+            &miner,
+            &chronicle.chain_repository,
+            &chronicle.utxo_ledger,
+            &chronicle.configuration,
+        ).await.unwrap();
+
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 10_000);
+        assert_eq!(chronicle.get_balance(&alice.public.to_bytes()).await.unwrap(), 89_000);
+        assert!(chronicle.utxo_ledger.find_unspent_output("alice_funding:0").await.unwrap().is_none());
+
+        // Spending the same funding again fails the whole chapter and changes nothing
+        let echo = funding_story(&chronicle, &alice, "echo", 7_000);
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let doomed = BlockChapter {
+            chapter_number: tip.chapter_number + 1,
+            transaction_tales: vec![echo, payment],
+            ..tip
+        };
+        assert!(matches!(chronicle.utxo_ledger.apply_block(&doomed).await, Err(ChronicleError::UTXONotFound(_))));
+        assert!(chronicle.utxo_ledger.find_unspent_output("echo:0").await.unwrap().is_none());
+        assert_eq!(chronicle.get_balance(&alice.public.to_bytes()).await.unwrap(), 89_000);
+    }
+
+    #[tokio::test]
+    async fn a_chapter_the_chain_cannot_write_leaves_the_ledger_untouched() {
+        let chronicle = quiet_chronicle("commit_undone").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let chapter = chapter_following(&tip, vec![payment.clone()], "chapter_1".to_string());
+
+        // A garbled nonce record makes the chain fail partway through the write
+        let alice_nonce_key = nonce_key(&alice.public.to_bytes());
+        chronicle.chain_repository.nonce_db.insert(alice_nonce_key.as_bytes(), vec![0xff]).unwrap();
+        let refused = BlockchainChronicler::commit_chapter(&chronicle.utxo_ledger, &chronicle.chain_repository, &chapter).await;
+        assert!(matches!(refused, Err(ChronicleError::SerializationError(_))));
+
+        assert!(chronicle.utxo_ledger.find_unspent_output("alice_funding:0").await.unwrap().is_some());
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 0);
+        assert_eq!(chronicle.chain_height().await.unwrap(), 0);
+        assert!(chronicle.chain_repository.load_chapter(1).unwrap().is_none());
+        assert!(chronicle.get_transaction(&payment.story_id).await.unwrap().is_none());
+
+        chronicle.chain_repository.nonce_db.remove(alice_nonce_key.as_bytes()).unwrap();
+        BlockchainChronicler::commit_chapter(&chronicle.utxo_ledger, &chronicle.chain_repository, &chapter).await.unwrap();
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 10_000);
+    }
+
+    #[tokio::test]
+    async fn the_miner_is_paid_its_reward_and_the_fees_it_collected() {
+        let chronicle = quiet_chronicle("coinbase").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        let carol = Keypair::generate(&mut OsRng);
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &carol, "carol_funding", 100_000);
+
+        let first = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let second = chronicle.create_transaction(&carol, &bob, 5_000, 2_500).await.unwrap();
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 0);
+
+        BlockchainChronicler::mine_new_chapter(
+            vec![first, second],
+            &miner,
+            &chronicle.chain_repository,
+            &chronicle.utxo_ledger,
+            &chronicle.configuration,
+        ).await.unwrap();
+
+        let reward = BlockchainChronicler::mining_reward_at_height(1, &chronicle.configuration).unwrap();
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), reward + 3_500);
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert!(tip.transaction_tales[0].is_coinbase());
+        assert!(tip.transaction_tales[1..].iter().all(|story| !story.is_coinbase()));
+    }
+
+    #[tokio::test]
+    async fn a_deposit_counts_only_once_buried_deep_enough() {
+        let mut chronicle = quiet_chronicle("confirmed_balance").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let carol = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &carol, "carol_funding", 100_000);
+
+        let deposit = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        for tales in [vec![deposit], Vec::new()] {
+            BlockchainChronicler::mine_new_chapter(
+                tales, &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &chronicle.configuration,
+            ).await.unwrap();
+        }
+        let late_deposit = chronicle.create_transaction(&carol, &bob, 5_000, 1_000).await.unwrap();
+        BlockchainChronicler::mine_new_chapter(
+            vec![late_deposit], &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &chronicle.configuration,
+        ).await.unwrap();
+        let pending = chronicle.create_transaction(&carol, &bob, 2_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(pending).await.unwrap();
+
+        // Chapter 1 is three deep under the tip at chapter 3; chapter 3 only one
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 15_000);
+        assert_eq!(chronicle.get_balance_with_confirmations(&bob, 3).await.unwrap(), 10_000);
+        assert_eq!(chronicle.get_balance_with_confirmations(&bob, 1).await.unwrap(), 15_000);
+        assert_eq!(chronicle.get_balance_with_confirmations(&bob, 4).await.unwrap(), 0);
+        assert_eq!(chronicle.get_balance_with_confirmations(&bob, 0).await.unwrap(), 15_000);
+    }
+
+    #[tokio::test]
+    async fn a_pool_splits_its_reward_seventy_thirty() {
+        let pool_operator = Keypair::generate(&mut OsRng).public.to_bytes().to_vec();
+        let pool_member = Keypair::generate(&mut OsRng).public.to_bytes().to_vec();
+        let config = ChronicleConfiguration {
+            base_mining_reward: 1_001,
+            reward_beneficiaries: vec![(pool_operator.clone(), 70), (pool_member.clone(), 30)],
+            ..ChronicleConfiguration::default()
+        };
+        let chronicle = chronicle_with("reward_split", config.clone()).await;
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        BlockchainChronicler::mine_new_chapter(
+            Vec::new(), &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &config,
+        ).await.unwrap();
+
+        // 700.7 and 300.3: the unit left over goes to the larger fraction
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let shares: Vec<(Vec<u8>, u64)> = tip.transaction_tales[0].outputs_created.iter()
+            .map(|output| (output.recipient_address.clone(), output.value_locked))
+            .collect();
+        assert_eq!(shares, vec![(pool_operator.clone(), 701), (pool_member.clone(), 300)]);
+        assert_eq!(shares.iter().map(|(_, share)| share).sum::<u64>(), 1_001);
+        assert_eq!(chronicle.get_balance(&pool_member).await.unwrap(), 300);
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 0);
+
+        assert_eq!(split_by_weight(10, &[(vec![1], 1), (vec![2], 1), (vec![3], 1)]), vec![4, 3, 3]);
+        let weightless = ChronicleConfiguration { reward_beneficiaries: vec![(pool_member, 0)], ..config };
+        assert!(matches!(weightless.validate(), Err(ChronicleError::InvalidConfiguration(_))));
+    }
+
+    #[tokio::test]
+    async fn the_coinbase_pays_half_once_the_boundary_is_crossed() {
+        let config = ChronicleConfiguration {
+            base_mining_reward: 1_001,
+            reward_halving_interval: 2,
+            ..ChronicleConfiguration::default()
+        };
+        let chronicle = chronicle_with("halving", config.clone()).await;
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        let mut coinbase_values = Vec::new();
+        for _ in 0..3 {
+            BlockchainChronicler::mine_new_chapter(
+                Vec::new(), &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &config,
+            ).await.unwrap();
+            let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+            coinbase_values.push(tip.transaction_tales[0].outputs_created[0].value_locked);
+            assert_eq!(tip.proof_of_storytelling.storyteller_reward, coinbase_values[coinbase_values.len() - 1]);
+        }
+
+        // Chapter 1 earns the full reward; chapter 2 sits on the boundary
+        assert_eq!(coinbase_values, vec![1_001, 500, 500]);
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 2_001);
+    }
+
+    /// A chronicle whose chapters pay a reward of 10_000 and split
+    /// their fees as `fee_policy` says.
+    async fn chronicle_under_fee_policy(tale_name: &str, fee_policy: FeePolicy) -> BlockchainChronicler {
+        chronicle_with(tale_name, ChronicleConfiguration {
+            base_mining_reward: 10_000,
+            fee_policy,
+            ..ChronicleConfiguration::default()
+        }).await
+    }
+
+    #[tokio::test]
+    async fn burned_fees_never_reach_the_total_supply() {
+        let chronicle = chronicle_under_fee_policy("fee_burn", FeePolicy::BurnBasisPoints(5_000)).await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let supply_before = chronicle.total_supply().await.unwrap();
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_001).await.unwrap();
+        let chapter = BlockchainChronicler::mine_new_chapter(
+            vec![payment], &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &chronicle.configuration,
+        ).await.unwrap();
+
+        // 500.5 of the fee is burned, rounded down to 500; the miner keeps the rest
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 10_000 + 501);
+        assert_eq!(chronicle.total_supply().await.unwrap(), supply_before + 10_000 - 500);
+        BlockchainChronicler::chapter_pays_its_miner_fairly(&chapter, &chronicle.configuration).unwrap();
+
+        // A coinbase that mints the burned share for itself is refused
+        let mut greedy = chapter.clone();
+        greedy.transaction_tales[0].outputs_created[0].value_locked += 500;
+        assert!(matches!(
+            BlockchainChronicler::chapter_pays_its_miner_fairly(&greedy, &chronicle.configuration),
+            Err(ChronicleError::InvalidChapter(_))
+        ));
+
+        let reckless = ChronicleConfiguration { fee_policy: FeePolicy::BurnBasisPoints(15_000), ..chronicle.configuration.clone() };
+        assert!(matches!(reckless.validate(), Err(ChronicleError::InvalidConfiguration(_))));
+    }
+
+    #[tokio::test]
+    async fn the_treasury_is_paid_its_share_of_the_fees_in_the_coinbase() {
+        let treasury = Keypair::generate(&mut OsRng).public.to_bytes().to_vec();
+        let chronicle = chronicle_under_fee_policy(
+            "fee_treasury", FeePolicy::TreasurySplit(treasury.clone(), 2_500),
+        ).await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let supply_before = chronicle.total_supply().await.unwrap();
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 2_000).await.unwrap();
+        let chapter = BlockchainChronicler::mine_new_chapter(
+            vec![payment], &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &chronicle.configuration,
+        ).await.unwrap();
+
+        let coinbase = &chapter.transaction_tales[0];
+        let treasury_output = coinbase.outputs_created.iter()
+            .find(|output| output.recipient_address == treasury)
+            .unwrap();
+        assert_eq!(treasury_output.value_locked, 500);
+        assert_eq!(chronicle.get_balance(&treasury).await.unwrap(), 500);
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 10_000 + 1_500);
+        // Nothing is burned: the fee only changes hands
+        assert_eq!(chronicle.total_supply().await.unwrap(), supply_before + 10_000);
+
+        // A coinbase that keeps the treasury's share for its miner is refused
+        let mut unfaithful = chapter.clone();
+        unfaithful.transaction_tales[0].outputs_created.retain(|output| output.recipient_address != treasury);
+        assert!(matches!(
+            BlockchainChronicler::chapter_pays_its_miner_fairly(&unfaithful, &chronicle.configuration),
+            Err(ChronicleError::InvalidChapter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn the_exported_graph_follows_each_coin_to_its_spender() {
+        let chronicle = quiet_chronicle("tx_graph").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng);
+        let carol = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        commit_chapter_of(&chronicle, vec![funding_story(&chronicle, &alice, "alice_funding", 100_000)]).await;
+        let alice_pays_bob = chronicle.create_transaction(&alice, &bob.public.to_bytes(), 10_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![alice_pays_bob.clone()]).await;
+        let bob_pays_carol = chronicle.create_transaction(&bob, &carol, 4_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![bob_pays_carol.clone()]).await;
+
+        let graph = chronicle.export_tx_graph(1, 3).await.unwrap();
+        let node_ids: Vec<&str> = graph.nodes.iter().map(|node| node.story_id.as_str()).collect();
+        assert_eq!(node_ids, vec!["alice_funding", alice_pays_bob.story_id.as_str(), bob_pays_carol.story_id.as_str()]);
+        assert_eq!(graph.edges, vec![
+            TxGraphEdge {
+                from_story: "alice_funding".to_string(),
+                output_index: 0,
+                to_story: alice_pays_bob.story_id.clone(),
+                value: Some(100_000),
+            },
+            TxGraphEdge {
+                from_story: alice_pays_bob.story_id.clone(),
+                output_index: 0,
+                to_story: bob_pays_carol.story_id.clone(),
+                value: Some(10_000),
+            },
+        ]);
+
+        // A narrower window still shows where its coins came from
+        let last_chapter = chronicle.export_tx_graph(3, 3).await.unwrap();
+        assert_eq!(last_chapter.nodes.len(), 1);
+        assert_eq!(last_chapter.edges, graph.edges[1..]);
+        assert!(last_chapter.to_dot().contains(&format!("\"{}\" -> \"{}\"", alice_pays_bob.story_id, bob_pays_carol.story_id)));
+
+        assert!(matches!(chronicle.export_tx_graph(3, 4).await, Err(ChronicleError::ChapterNotFound(4))));
+    }
+
+    #[tokio::test]
+    async fn a_coinbase_worth_nothing_is_refused_and_never_mined() {
+        let mut chronicle = quiet_chronicle("worthless_coinbase").await;
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        let reward = BlockchainChronicler::mining_reward_at_height(1, &chronicle.configuration).unwrap();
+
+        let mut worthless = BlockchainChronicler::coinbase_story(1, &miner, reward);
+        worthless.outputs_created[0].value_locked = 0;
+        let chapter = honestly_mined_chapter(&chronicle, vec![worthless]).await;
+        assert!(matches!(chronicle.receive_block_chapter(chapter).await,
+            Err(ChronicleError::InvalidChapter(ref reason)) if reason.contains("worth nothing")));
+        assert!(BlockchainChronicler::coinbase_story(1, &miner, 0).outputs_created.is_empty());
+
+        // With the reward halved away and no fees to collect, the chapter carries no coinbase
+        chronicle.configuration.base_mining_reward = 0;
+        let chapter = BlockchainChronicler::mine_new_chapter(
+            Vec::new(),
+            &miner,
+            &chronicle.chain_repository,
+            &chronicle.utxo_ledger,
+            &chronicle.configuration,
+        ).await.unwrap();
+        assert!(chapter.transaction_tales.is_empty());
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn a_chapter_may_mint_only_one_coinbase() {
+        let mut chronicle = quiet_chronicle("extra_coinbase").await;
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        let reward = BlockchainChronicler::mining_reward_at_height(1, &chronicle.configuration).unwrap();
+
+        let mut greedy = BlockchainChronicler::coinbase_story(1, &miner, reward);
+        greedy.story_id = "second_coinbase".to_string();
+        let doubled = honestly_mined_chapter(&chronicle, vec![
+            BlockchainChronicler::coinbase_story(1, &miner, reward),
+            greedy,
+        ]).await;
+        assert!(matches!(chronicle.receive_block_chapter(doubled).await,
+            Err(ChronicleError::InvalidChapter(_))));
+
+        let inflated = honestly_mined_chapter(&chronicle, vec![
+            BlockchainChronicler::coinbase_story(1, &miner, reward + 1),
+        ]).await;
+        assert!(matches!(chronicle.receive_block_chapter(inflated).await,
+            Err(ChronicleError::InvalidChapter(_))));
+
+        let honest = honestly_mined_chapter(&chronicle, vec![
+            BlockchainChronicler::coinbase_story(1, &miner, reward),
+        ]).await;
+        chronicle.receive_block_chapter(honest).await.unwrap();
+        assert_eq!(chronicle.get_balance(&miner).await.unwrap(), reward);
+    }
+
+    #[tokio::test]
+    async fn handshakes_from_two_camps_reveal_a_fork_and_start_a_sync() {
+        let mut chronicle = quiet_chronicle("handshake_split").await;
+        let mut asked = chronicle.take_outgoing_messages();
+        let camps = [
+            ("10.0.0.1:8333", "tip_of_the_majority"),
+            ("10.0.0.2:8333", "tip_of_the_majority"),
+            ("10.0.1.1:8333", "tip_of_the_dissenters"),
+        ];
+        for (peer, tip_essence) in camps {
+            let handshake = NetworkMessage::Handshake {
+                chain_height: 5,
+                tip_essence: tip_essence.to_string(),
+                clock_time: current_timestamp(),
+            };
+            chronicle.network_message_arrives(peer, handshake).await.unwrap();
+        }
+
+        let split = chronicle.detect_chain_split().unwrap();
+        assert_eq!((split.height, split.competing_tips.len()), (5, 2));
+        assert_eq!(split.competing_tips[0].tip_hash, "tip_of_the_majority");
+        // Each taller peer is a reason to catch up, as a heartbeat would be
+        assert!(matches!(asked.try_recv(), Ok(NetworkMessage::RequestChainSync(1))));
+    }
+
+    #[tokio::test]
+    async fn two_camps_of_peers_reveal_a_fork() {
+        let chronicle = quiet_chronicle("chain_split").await;
+        chronicle.peer_reports_tip("10.0.0.1:8333", 41, "shared_ancestor");
+        chronicle.peer_reports_tip("10.0.0.2:8333", 41, "shared_ancestor");
+        assert!(chronicle.detect_chain_split().is_none());
+
+        for peer in ["10.0.0.1:8333", "10.0.0.2:8333", "10.0.0.3:8333"] {
+            chronicle.peer_reports_tip(peer, 42, "tip_of_the_majority");
+        }
+        for peer in ["10.0.1.1:8333", "10.0.1.2:8333"] {
+            chronicle.peer_reports_tip(peer, 42, "tip_of_the_dissenters");
+        }
+
+        let split = chronicle.detect_chain_split().unwrap();
+        assert_eq!(split.height, 42);
+        assert_eq!(split.competing_tips, vec![
+            CompetingTip {
+                tip_hash: "tip_of_the_majority".to_string(),
+                peers: vec!["10.0.0.1:8333".to_string(), "10.0.0.2:8333".to_string(), "10.0.0.3:8333".to_string()],
+            },
+            CompetingTip {
+                tip_hash: "tip_of_the_dissenters".to_string(),
+                peers: vec!["10.0.1.1:8333".to_string(), "10.0.1.2:8333".to_string()],
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn a_chapter_is_found_by_its_height_and_by_its_essence() {
+        let mut chronicle = quiet_chronicle("chain_queries").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let chapter = honestly_mined_chapter(&chronicle, vec![payment.clone()]).await;
+        chronicle.receive_block_chapter(chapter.clone()).await.unwrap();
+
+        let by_height = chronicle.get_block_by_height(1).await.unwrap().unwrap();
+        let by_hash = chronicle.get_block_by_hash(&chapter.chapter_essence).await.unwrap().unwrap();
+        assert_eq!(by_height.chapter_essence, chapter.chapter_essence);
+        assert_eq!(by_hash.chapter_number, 1);
+        let told = chronicle.get_transaction(&payment.story_id).await.unwrap().unwrap();
+        assert_eq!(told.outputs_created, payment.outputs_created);
+
+        // A restarted node has lost its in-memory index, but not its chapters
+        chronicle.chain_repository.block_index.write().unwrap().clear();
+        assert_eq!(chronicle.get_block_by_hash(&chapter.chapter_essence).await.unwrap().unwrap().chapter_number, 1);
+
+        assert!(chronicle.get_block_by_height(2).await.unwrap().is_none());
+        assert!(chronicle.get_block_by_hash(&"f".repeat(64)).await.unwrap().is_none());
+        assert!(chronicle.get_transaction("never_told").await.unwrap().is_none());
+
+        // A miss is answered by the essence tree alone, with no rescan to rebuild the index
+        chronicle.chain_repository.block_index.write().unwrap().clear();
+        assert!(chronicle.chain_repository.chapter_number_of(&"e".repeat(64)).unwrap().is_none());
+        assert!(chronicle.chain_repository.block_index.read().unwrap().is_empty());
+        assert!(chronicle.chain_repository.knows_chapter(&chapter.chapter_essence));
+    }
+
+    #[tokio::test]
+    async fn a_pruned_node_no_longer_tells_of_old_chapters() {
+        let chronicle_in_mode = |tale_name: &'static str, node_mode: NodeMode| {
+            chronicle_with(tale_name, ChronicleConfiguration { node_mode, ..ChronicleConfiguration::default() })
+        };
+        let archival = chronicle_in_mode("archival", NodeMode::Archival).await;
+        let pruned = chronicle_in_mode("pruned", NodeMode::Pruned { keep_blocks: 2 }).await;
+        for _ in 0..4 {
+            commit_chapter_of(&archival, Vec::new()).await;
+            commit_chapter_of(&pruned, Vec::new()).await;
+        }
+
+        assert_eq!(archival.get_block_by_height(1).await.unwrap().unwrap().chapter_number, 1);
+        assert_eq!(pruned.pruning_horizon().await.unwrap(), 3);
+        assert!(matches!(pruned.get_block_by_height(1).await, Err(ChronicleError::ChapterPruned(1))));
+        assert!(matches!(pruned.get_block_by_hash("chapter_2").await, Err(ChronicleError::ChapterPruned(2))));
+        assert_eq!(pruned.get_block_by_height(3).await.unwrap().unwrap().chapter_number, 3);
+
+        let keeps_nothing = ChronicleConfiguration { node_mode: NodeMode::Pruned { keep_blocks: 0 }, ..ChronicleConfiguration::default() };
+        assert!(matches!(keeps_nothing.validate(), Err(ChronicleError::InvalidConfiguration(_))));
+    }
+
+    #[tokio::test]
+    async fn pending_stories_survive_a_restart_unless_spent_meanwhile() {
+        let config = ChronicleConfiguration {
+            data_directory: tale_directory("mempool_restart"),
+            ..ChronicleConfiguration::default()
+        };
+        let alice = Keypair::generate(&mut OsRng);
+        let carol = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        let mut chronicle = BlockchainChronicler::chronicle_gathers_its_cast(config.clone()).await.unwrap();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &carol, "carol_funding", 100_000);
+        let survivor = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let doomed = chronicle.create_transaction(&carol, &bob, 10_000, 1_000).await.unwrap();
+        let rival = chronicle.create_transaction(&carol, &bob, 20_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(survivor.clone()).await.unwrap();
+        chronicle.transaction_story_arrives(doomed.clone()).await.unwrap();
+
+        // The chain moves on without the mempool hearing of it, as it would
+        // while the node was down, and carol's output is spent by a rival
+        commit_chapter_of(&chronicle, vec![rival]).await;
+        drop(chronicle);
+
+        let restarted = BlockchainChronicler::chronicle_gathers_its_cast(config).await.unwrap();
+        let pending: Vec<String> = restarted.mempool_of_pending_tales.lock().unwrap()
+            .iter()
+            .map(|story| story.story_id.clone())
+            .collect();
+        assert_eq!(pending, vec![survivor.story_id]);
+        assert!(restarted.chain_repository.load_pending_stories().unwrap()
+            .iter()
+            .all(|story| story.story_id != doomed.story_id));
+    }
+
+    #[tokio::test]
+    async fn node_mode_pruning_stops_short_of_the_slowest_syncing_peer() {
+        let chronicle = quiet_chronicle("prune_to_slowest_peer").await;
+        for _ in 0..5 {
+            commit_chapter_of(&chronicle, Vec::new()).await;
+        }
+        let pruned_mode = ChronicleConfiguration {
+            node_mode: NodeMode::Pruned { keep_blocks: 2 },
+            ..chronicle.configuration.clone()
+        };
+        let served = &chronicle.chain_repository;
+        served.sync_served_from.write().unwrap().insert("10.0.0.1:8333".to_string(), 3);
+        served.sync_served_from.write().unwrap().insert("10.0.0.2:8333".to_string(), 2);
+
+        // The horizon would be chapter 4, but one peer has yet to fetch chapter 2
+        BlockchainChronicler::prune_to_node_mode(served, &pruned_mode, 5).unwrap();
+        assert!(served.load_chapter(1).unwrap().is_none());
+        assert!(served.load_chapter(2).unwrap().is_some());
+
+        served.note_sync_served("10.0.0.2:8333", 2, 3);
+        BlockchainChronicler::prune_to_node_mode(served, &pruned_mode, 5).unwrap();
+        assert!(served.load_chapter(2).unwrap().is_none());
+        assert!(served.load_chapter(3).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn pruning_keeps_the_headers_and_the_spendable_state() {
+        let chronicle = quiet_chronicle("prune_below").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![payment]).await;
+        for _ in 0..4 {
+            commit_chapter_of(&chronicle, Vec::new()).await;
+        }
+        let first_essence = chronicle.chain_repository.load_chapter(1).unwrap().unwrap().chapter_essence;
+
+        // A full batch means the peer will be back for what follows it
+        chronicle.chain_repository.note_sync_served("10.0.0.1:8333", 2, SYNC_BATCH_CHAPTERS);
+        assert_eq!(chronicle.chain_repository.sync_served_from.read().unwrap().get("10.0.0.1:8333"), Some(&502));
+
+        // A peer part-way through a sync still needs chapter 2 onwards, and
+        // another peer catching up does not speak for it
+        chronicle.chain_repository.sync_served_from.write().unwrap().insert("10.0.0.2:8333".to_string(), 2);
+        chronicle.chain_repository.note_sync_served("10.0.0.1:8333", 502, 3);
+        assert!(matches!(chronicle.chain_repository.prune_below(3), Err(ChronicleError::PruningRefused(_))));
+
+        // A short batch tells us it has caught up
+        chronicle.chain_repository.note_sync_served("10.0.0.2:8333", 2, 3);
+
+        assert_eq!(chronicle.chain_repository.prune_below(3).unwrap(), 3);
+        for pruned in 0..3 {
+            assert!(chronicle.chain_repository.load_chapter(pruned).unwrap().is_none());
+            assert!(chronicle.chain_repository.load_header(pruned).unwrap().is_some());
+        }
+        assert!(chronicle.chain_repository.load_chapter(3).unwrap().is_some());
+        assert_eq!(chronicle.chain_repository.load_header(1).unwrap().unwrap().chapter_essence, first_essence);
+        assert!(chronicle.chain_repository.verify_chain_integrity().await.unwrap());
+        assert_eq!(chronicle.chain_repository.chapter_number_of(&first_essence).unwrap(), Some(1));
+
+        // What the pruned chapters paid out can still be spent
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 10_000);
+
+        // Asking beyond the tip prunes all but the tip
+        assert_eq!(chronicle.chain_repository.prune_below(100).unwrap(), 2);
+        assert_eq!(chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap().chapter_number, 5);
+        assert!(chronicle.chain_repository.load_chapter(5).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn two_fresh_nodes_agree_on_their_first_chapter() {
+        let first = quiet_chronicle("genesis_first").await;
+        let second = quiet_chronicle("genesis_second").await;
+        let first_genesis = first.chain_repository.load_chapter(0).unwrap().unwrap();
+        let second_genesis = second.chain_repository.load_chapter(0).unwrap().unwrap();
+
+        assert_eq!(first_genesis.chapter_essence, second_genesis.chapter_essence);
+        assert_eq!(first_genesis.chapter_essence,
+            BlockchainChronicler::calculate_block_hash(&first_genesis, GENESIS_NONCE));
+        assert_eq!(first_genesis.merkle_tree_of_truth, "0".repeat(64));
+        assert!(first.chain_repository.verify_chain_integrity().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn the_chronicle_measures_its_recent_pulse() {
+        let chronicle = quiet_chronicle("chain_stats").await;
+        let genesis = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let start = genesis.timestamp_of_creation;
+
+        // Chapters 1..=3 arrive every 10 seconds carrying 2, 4 and 6 tales at fee 1000, 2000, 3000
+        for chapter_number in 1..=3u64 {
+            let tales = (0..chapter_number * 2).map(|_| pending_tale(chapter_number * 1_000)).collect();
+            chronicle.chain_repository.add_block_chapter(BlockChapter {
+                chapter_number,
+                timestamp_of_creation: start + chapter_number * 10,
+                transaction_tales: tales,
+                chapter_essence: format!("chapter_{}", chapter_number),
+                ..genesis.clone()
+            }).await.unwrap();
+        }
+
+        let stats = chronicle.chain_stats(3).await.unwrap();
+        assert_eq!(stats.chapters_observed, 3);
+        assert_eq!(stats.average_block_time_secs, 10.0);
+        assert_eq!(stats.average_stories_per_chapter, 4.0);
+        assert_eq!(stats.average_fee, (2_000.0 + 8_000.0 + 18_000.0) / 12.0);
+        assert_eq!(stats.estimated_tps, 10.0 / 20.0);
+
+        let whole_chain = chronicle.chain_stats(100).await.unwrap();
+        assert_eq!(whole_chain.chapters_observed, 4);
+        assert_eq!(whole_chain.average_block_time_secs, 10.0);
+    }
+
+    #[tokio::test]
+    async fn an_interrupted_sync_resumes_where_it_stopped() {
+        let config = ChronicleConfiguration {
+            data_directory: tale_directory("resync"),
+            ..ChronicleConfiguration::default()
+        };
+
+        {
+            let chronicle = BlockchainChronicler::chronicle_gathers_its_cast(config.clone()).await.unwrap();
+            assert_eq!(chronicle.sync_resume_height(), 1);
+            for height in 1..=40 {
+                chronicle.record_sync_progress(height, 100).unwrap();
+            }
+            // The node goes down forty chapters into a hundred
+        }
+
+        let mut chronicle = BlockchainChronicler::chronicle_gathers_its_cast(config).await.unwrap();
+        assert_eq!(chronicle.sync_resume_height(), 41);
+
+        let mut outgoing = chronicle.take_outgoing_messages();
+        chronicle.begin_network_synchronization().await.unwrap();
+        assert!(matches!(outgoing.try_recv(), Ok(NetworkMessage::RequestChainSync(41))));
+    }
+
+    #[tokio::test]
+    async fn a_longer_rival_branch_from_genesis_takes_over() {
+        let mut chronicle = quiet_chronicle("reorganize").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let dave = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let carol = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &dave, "dave_funding", 50_000);
+
+        // Both payments from Alice spend the same funding; only one branch can hold each
+        let alice_pays_bob = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let alice_pays_carol = chronicle.create_transaction(&alice, &carol, 20_000, 1_000).await.unwrap();
+        let dave_pays_bob = chronicle.create_transaction(&dave, &bob, 5_000, 1_000).await.unwrap();
+
+        let genesis = chronicle.chain_repository.load_chapter(0).unwrap().unwrap();
+        commit_chapter_of(&chronicle, vec![alice_pays_bob]).await;
+        commit_chapter_of(&chronicle, vec![dave_pays_bob.clone()]).await;
+        commit_chapter_of(&chronicle, vec![]).await;
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 15_000);
+
+        // Every chapter carries genesis's target, so equal length means equal work
+        let mut rival_branch = vec![seal_chapter(chapter_following(&genesis, vec![alice_pays_carol], String::new()))];
+        for _ in 2..=3 {
+            let previous = rival_branch.last().unwrap();
+            rival_branch.push(seal_chapter(chapter_following(previous, vec![], String::new())));
+        }
+        let no_heavier = chronicle.reorganize_to(rival_branch.clone()).await;
+        assert!(matches!(no_heavier, Err(ChronicleError::ReorganizationRejected(_))));
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 15_000);
+
+        // A heavier branch whose last chapter never did its work is refused whole
+        let mut forged_branch = rival_branch.clone();
+        forged_branch.push(chapter_following(rival_branch.last().unwrap(), vec![], "forged_4".to_string()));
+        let forged = chronicle.reorganize_to(forged_branch).await;
+        assert!(matches!(forged, Err(ChronicleError::ProofOfWorkFailed(_))));
+        assert_eq!(chronicle.chain_height().await.unwrap(), 3);
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 15_000);
+
+        let rival_tip = seal_chapter(chapter_following(rival_branch.last().unwrap(), vec![], String::new()));
+        let rival_tip_essence = rival_tip.chapter_essence.clone();
+        rival_branch.push(rival_tip);
+        let report = chronicle.reorganize_to(rival_branch).await.unwrap();
+
+        assert_eq!(report.common_ancestor, 0);
+        assert_eq!(report.orphaned_chapters, 3);
+        assert_eq!(report.adopted_chapters, 4);
+        assert_eq!(report.returned_to_mempool, vec![dave_pays_bob.story_id.clone()]);
+
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(tip.chapter_essence, rival_tip_essence);
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 0);
+        assert_eq!(chronicle.get_balance(&carol).await.unwrap(), 20_000);
+        assert_eq!(chronicle.get_balance(&alice.public.to_bytes()).await.unwrap(), 79_000);
+        assert_eq!(chronicle.get_balance(&dave.public.to_bytes()).await.unwrap(), 50_000);
+        assert!(chronicle.mempool_of_pending_tales.lock().unwrap()
+            .iter().any(|story| story.story_id == dave_pays_bob.story_id));
+    }
+
+    /// A chapter after the tip carrying `tales`, with its merkle root, target
+    /// and proof of work all as an honest miner would leave them
+    async fn honestly_mined_chapter(chronicle: &BlockchainChronicler, tales: Vec<TransactionStory>) -> BlockChapter {
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let mut chapter = chapter_following(&tip, tales, String::new());
+        chapter.proof_of_storytelling.difficulty_bits = BlockchainChronicler::calculate_current_difficulty(
+            &tip, &chronicle.chain_repository, &chronicle.configuration,
+        ).unwrap();
+        seal_chapter(chapter)
+    }
+
+    /// Re-weaves the merkle root and searches for a nonce meeting the target
+    fn seal_chapter(mut chapter: BlockChapter) -> BlockChapter {
+        chapter.merkle_tree_of_truth = BlockchainChronicler::weave_merkle_tree_of_truth(&chapter.transaction_tales);
+        let target = chapter.proof_of_storytelling.difficulty_bits.to_target().unwrap();
+        let nonce = (0..).find(|&nonce| BlockchainChronicler::hash_meets_difficulty(
+            &BlockchainChronicler::calculate_block_hash(&chapter, nonce), &target,
+        )).unwrap();
+        chapter.chapter_essence = BlockchainChronicler::calculate_block_hash(&chapter, nonce);
+        chapter.proof_of_storytelling.nonce_of_discovery = nonce;
+        chapter
+    }
+
+    /// The unspent outputs as the ledger's own trees hold them, gathered
+    /// without asking the ledger
+    fn unspent_outputs_on_disk(chronicle: &BlockchainChronicler) -> BTreeMap<String, UTXOOutput> {
+        let spent = chronicle.utxo_ledger.spent_outputs.read().unwrap();
+        chronicle.utxo_ledger.db.iter()
+            .map(|entry| entry.unwrap())
+            .map(|(key, data)| (String::from_utf8(key.to_vec()).unwrap(), bincode::deserialize(&data).unwrap()))
+            .filter(|(utxo_key, _)| !spent.contains(utxo_key))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn each_chapter_commits_the_root_of_what_it_leaves_unspent() {
+        let config = ChronicleConfiguration {
+            commit_state_roots: true,
+            ..ChronicleConfiguration::default()
+        };
+        let chronicle = chronicle_with("state_root", config.clone()).await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let miner = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let mut roots = Vec::new();
+        for tales in [vec![payment], Vec::new()] {
+            let chapter = BlockchainChronicler::mine_new_chapter(
+                tales, &miner, &chronicle.chain_repository, &chronicle.utxo_ledger, &config,
+            ).await.unwrap();
+            let committed_root = chapter.utxo_state_root.clone().unwrap();
+            assert_eq!(committed_root, utxo_state_root(&unspent_outputs_on_disk(&chronicle)).unwrap());
+            assert_eq!(committed_root, chronicle.utxo_state_root().await.unwrap());
+            roots.push(committed_root);
+        }
+        // The second coinbase changed what is unspent, and so the root
+        assert_ne!(roots[0], roots[1]);
+        assert_eq!(utxo_state_root(&BTreeMap::new()).unwrap(), "0".repeat(64));
+    }
+
+    #[tokio::test]
+    async fn a_chapter_whose_state_root_does_not_replay_is_turned_away() {
+        let mut chronicle = quiet_chronicle("receive_state_root").await;
+
+        let misremembered = seal_chapter(BlockChapter {
+            utxo_state_root: Some("f".repeat(64)),
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        assert!(matches!(chronicle.receive_block_chapter(misremembered).await,
+            Err(ChronicleError::InvalidChapter(_))));
+        assert_eq!(chronicle.chain_height().await.unwrap(), 0);
+
+        let unsealed = honestly_mined_chapter(&chronicle, vec![]).await;
+        let replayed_root = chronicle.utxo_ledger.state_root_after(&unsealed).await.unwrap();
+        let faithful = seal_chapter(BlockChapter { utxo_state_root: Some(replayed_root.clone()), ..unsealed });
+        chronicle.receive_block_chapter(faithful).await.unwrap();
+        assert_eq!(chronicle.utxo_state_root().await.unwrap(), replayed_root);
+    }
+
+    #[tokio::test]
+    async fn a_peer_cannot_slip_a_forged_story_into_a_chapter() {
+        let mut chronicle = quiet_chronicle("receive_tampered").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+
+        // The forger pays Bob more than Alice signed for, and mines it properly
+        let mut forged = payment.clone();
+        forged.outputs_created[0].value_locked = 60_000;
+        let forged_chapter = honestly_mined_chapter(&chronicle, vec![forged]).await;
+        assert!(matches!(chronicle.receive_block_chapter(forged_chapter).await,
+            Err(ChronicleError::StoryBearsFalseWitness(_))));
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 0);
+
+        // Rewriting a sealed chapter's stories breaks its merkle root
+        let mut rewoven = honestly_mined_chapter(&chronicle, vec![payment.clone()]).await;
+        rewoven.transaction_tales[0].story_id = "someone_else".to_string();
+        assert!(matches!(chronicle.receive_block_chapter(rewoven).await,
+            Err(ChronicleError::InvalidChapter(_))));
+
+        let honest_chapter = honestly_mined_chapter(&chronicle, vec![payment]).await;
+        chronicle.receive_block_chapter(honest_chapter.clone()).await.unwrap();
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 10_000);
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(tip.chapter_essence, honest_chapter.chapter_essence);
+    }
+
+    #[tokio::test]
+    async fn five_hundred_narrators_are_verified_in_one_batch() {
+        let chronicle = quiet_chronicle("batch_signatures").await;
+        let tales: Vec<TransactionStory> = (0..500)
+            .map(|index| funding_story(&chronicle, &Keypair::generate(&mut OsRng), &format!("tale_{}", index), 1_000))
+            .collect();
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let chapter = chapter_following(&tip, tales, "chapter_1".to_string());
+
+        let batch_started = Instant::now();
+        chronicle.verify_block_signatures(&chapter).unwrap();
+        let batched = batch_started.elapsed();
+
+        let single_started = Instant::now();
+        for story in &chapter.transaction_tales {
+            assert!(chronicle.signature_tells_the_truth(story).await.unwrap());
+        }
+        let singly = single_started.elapsed();
+        println!("⏱️  500 signatures: {:?} in one batch, {:?} one at a time", batched, singly);
+    }
+
+    #[tokio::test]
+    async fn one_false_signature_is_named_even_in_a_batch() {
+        let chronicle = quiet_chronicle("batch_signature_forgery").await;
+        let mut tales: Vec<TransactionStory> = (0..20)
+            .map(|index| funding_story(&chronicle, &Keypair::generate(&mut OsRng), &format!("tale_{}", index), 1_000))
+            .collect();
+        tales[7].outputs_created[0].value_locked = 1_000_000;
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let chapter = chapter_following(&tip, tales, "chapter_1".to_string());
+
+        match chronicle.verify_block_signatures(&chapter) {
+            Err(ChronicleError::StoryBearsFalseWitness(reason)) => assert!(reason.contains("tale_7")),
+            verdict => panic!("expected the forgery to be named, got {:?}", verdict),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_chapter_that_forgets_our_tip_is_turned_away() {
+        let mut chronicle = quiet_chronicle("receive_detached").await;
+        let mut detached = honestly_mined_chapter(&chronicle, vec![]).await;
+        detached.previous_chapter_essence = "f".repeat(64);
+        let detached = seal_chapter(detached);
+
+        assert!(matches!(chronicle.receive_block_chapter(detached).await,
+            Err(ChronicleError::InvalidChapter(ref reason)) if reason.contains("our tip")));
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(tip.chapter_number, 0);
+
+        let mut lazy = honestly_mined_chapter(&chronicle, vec![]).await;
+        lazy.proof_of_storytelling.difficulty_bits = DifficultyTarget::MAX.to_compact();
+        let lazy = seal_chapter(lazy);
+        assert!(matches!(chronicle.receive_block_chapter(lazy).await,
+            Err(ChronicleError::ProofOfWorkFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn a_chapter_from_the_far_future_is_turned_away() {
+        let mut chronicle = quiet_chronicle("receive_future_dated").await;
+        let too_far_ahead = current_timestamp() + chronicle.configuration.max_future_drift_secs + 60;
+        let prophecy = seal_chapter(BlockChapter {
+            timestamp_of_creation: too_far_ahead,
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        assert!(matches!(chronicle.receive_block_chapter(prophecy).await,
+            Err(ChronicleError::InvalidTimestamp(_))));
+
+        let within_drift = seal_chapter(BlockChapter {
+            timestamp_of_creation: current_timestamp() + 60,
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        chronicle.receive_block_chapter(within_drift).await.unwrap();
+        assert_eq!(chronicle.chain_height().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_chapter_ahead_of_our_clock_but_not_the_networks_is_accepted() {
+        let mut chronicle = quiet_chronicle("receive_network_adjusted").await;
+        let drift = chronicle.configuration.max_future_drift_secs;
+
+        // Our clock runs ten minutes behind every peer's
+        for peer in ["10.0.0.1:8333", "10.0.0.2:8333", "10.0.0.3:8333"] {
+            chronicle.peer_reports_clock(peer, current_timestamp() + 600);
+        }
+        assert!(chronicle.network_adjusted_time() >= current_timestamp() + 599);
+
+        let ahead_of_us = seal_chapter(BlockChapter {
+            timestamp_of_creation: current_timestamp() + drift + 300,
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        chronicle.receive_block_chapter(ahead_of_us).await.unwrap();
+        assert_eq!(chronicle.chain_height().await.unwrap(), 1);
+
+        // Peers a whole day ahead move the network's time no further than the clamp
+        chronicle.peer_reports_clock("10.0.0.1:8333", current_timestamp() + 24 * 60 * 60);
+        chronicle.peer_reports_clock("10.0.0.2:8333", current_timestamp() + 24 * 60 * 60);
+        let clamped = chronicle.network_adjusted_time().saturating_sub(current_timestamp());
+        assert!(clamped <= chronicle.configuration.max_peer_clock_offset_secs);
+    }
+
+    #[tokio::test]
+    async fn the_clocks_named_in_handshakes_set_the_networks_time() {
+        let mut chronicle = quiet_chronicle("handshake_clocks").await;
+        let genesis = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        for peer in ["10.0.0.1:8333", "10.0.0.2:8333", "10.0.0.3:8333"] {
+            let handshake = NetworkMessage::Handshake {
+                chain_height: 0,
+                tip_essence: genesis.chapter_essence.clone(),
+                clock_time: current_timestamp() + 600,
+            };
+            chronicle.network_message_arrives(peer, handshake).await.unwrap();
+        }
+        assert!(chronicle.network_adjusted_time() >= current_timestamp() + 599);
+
+        // Clocks at the very ends of time neither overflow nor escape the clamp
+        let max_offset = chronicle.configuration.max_peer_clock_offset_secs;
+        chronicle.peer_reports_clock("10.0.0.1:8333", u64::MAX);
+        chronicle.peer_reports_clock("10.0.0.2:8333", u64::MAX);
+        assert!(chronicle.network_adjusted_time() <= current_timestamp() + max_offset);
+        for peer in ["10.0.0.1:8333", "10.0.0.2:8333", "10.0.0.3:8333", "10.0.0.4:8333"] {
+            chronicle.peer_reports_clock(peer, 0);
+        }
+        assert!(chronicle.network_adjusted_time() + max_offset >= current_timestamp());
+    }
+
+    #[tokio::test]
+    async fn a_chapter_dated_before_the_median_time_past_is_turned_away() {
+        let mut chronicle = quiet_chronicle("receive_backdated").await;
+        for _ in 0..3 {
+            let chapter = honestly_mined_chapter(&chronicle, vec![]).await;
+            chronicle.receive_block_chapter(chapter).await.unwrap();
+        }
+
+        // The bar is the median of the chapters so far, which lags behind the tip
+        let median_time_past = chronicle.chain_repository.median_time_past().unwrap();
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert!(median_time_past < tip.timestamp_of_creation);
+        let backdated = seal_chapter(BlockChapter {
+            timestamp_of_creation: median_time_past,
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        assert!(matches!(chronicle.receive_block_chapter(backdated).await,
+            Err(ChronicleError::InvalidTimestamp(_))));
+
+        let just_after = seal_chapter(BlockChapter {
+            timestamp_of_creation: median_time_past + 1,
+            ..honestly_mined_chapter(&chronicle, vec![]).await
+        });
+        chronicle.receive_block_chapter(just_after).await.unwrap();
+        assert_eq!(chronicle.chain_height().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn a_fresh_node_syncs_ten_chapters_from_its_peer() {
+        let mut seasoned = quiet_chronicle("sync_source").await;
+        for _ in 0..10 {
+            let chapter = honestly_mined_chapter(&seasoned, vec![]).await;
+            seasoned.receive_block_chapter(chapter).await.unwrap();
+        }
+        let mut fresh = quiet_chronicle("sync_fresh").await;
+        let mut from_seasoned = seasoned.take_outgoing_messages();
+        let mut from_fresh = fresh.take_outgoing_messages();
+
+        // Hearing a shorter chain starts no sync, only a reply with our height
+        seasoned.network_message_arrives("peer", NetworkMessage::Heartbeat(0)).await.unwrap();
+        assert!(matches!(from_seasoned.try_recv(), Ok(NetworkMessage::Heartbeat(10))));
+        assert!(from_seasoned.try_recv().is_err());
+        assert_eq!(seasoned.network_storytellers.get_sync_status().await.sync_progress, 0.0);
+
+        fresh.network_message_arrives("peer", NetworkMessage::Heartbeat(10)).await.unwrap();
+        assert!(matches!(from_fresh.try_recv(), Ok(NetworkMessage::RequestChainSync(1))));
+        assert!(fresh.network_storytellers.get_sync_status().await.is_syncing);
+        seasoned.network_message_arrives("peer", NetworkMessage::RequestChainSync(1)).await.unwrap();
+
+        let Ok(NetworkMessage::ChainSyncResponse(batch)) = from_seasoned.try_recv() else {
+            panic!("the request should be answered with a batch");
+        };
+        assert_eq!(batch.len(), 10);
+        fresh.network_message_arrives("peer", NetworkMessage::ChainSyncResponse(batch)).await.unwrap();
+        assert!(from_fresh.try_recv().is_err());
+
+        let status = fresh.network_storytellers.get_sync_status().await;
+        assert!(!status.is_syncing);
+        assert_eq!((status.current_height, status.sync_progress), (10, 1.0));
+        let fresh_tip = fresh.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let seasoned_tip = seasoned.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(fresh_tip.chapter_essence, seasoned_tip.chapter_essence);
+
+        // Asking past the tip brings an empty batch
+        assert!(seasoned.chain_repository.chapters_from(11, SYNC_BATCH_CHAPTERS).unwrap().is_empty());
+        assert_eq!(seasoned.chain_repository.chapters_from(9, SYNC_BATCH_CHAPTERS).unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn a_sync_batch_from_a_heavier_fork_reorganizes_our_chain() {
+        let mut seasoned = quiet_chronicle("fork_source").await;
+        for _ in 0..3 {
+            let chapter = honestly_mined_chapter(&seasoned, vec![]).await;
+            seasoned.receive_block_chapter(chapter).await.unwrap();
+        }
+        let mut forked = quiet_chronicle("fork_lagging").await;
+        for _ in 0..2 {
+            // Dated apart from the seasoned chapters, so the two chains part at genesis
+            let chapter = honestly_mined_chapter(&forked, vec![]).await;
+            let chapter = seal_chapter(BlockChapter { timestamp_of_creation: chapter.timestamp_of_creation + 7, ..chapter });
+            forked.receive_block_chapter(chapter).await.unwrap();
+        }
+        let mut from_seasoned = seasoned.take_outgoing_messages();
+        let mut from_forked = forked.take_outgoing_messages();
+
+        forked.network_message_arrives("peer", NetworkMessage::Heartbeat(3)).await.unwrap();
+        assert!(matches!(from_forked.try_recv(), Ok(NetworkMessage::RequestChainSync(3))));
+        seasoned.network_message_arrives("peer", NetworkMessage::RequestChainSync(3)).await.unwrap();
+        let Ok(NetworkMessage::ChainSyncResponse(batch)) = from_seasoned.try_recv() else {
+            panic!("the request should be answered with a batch");
+        };
+
+        // The third chapter follows one we never told, so the sync steps back
+        forked.network_message_arrives("peer", NetworkMessage::ChainSyncResponse(batch)).await.unwrap();
+        assert!(matches!(from_forked.try_recv(), Ok(NetworkMessage::RequestChainSync(1))));
+        assert_eq!(forked.chain_height().await.unwrap(), 2);
+
+        seasoned.network_message_arrives("peer", NetworkMessage::RequestChainSync(1)).await.unwrap();
+        let Ok(NetworkMessage::ChainSyncResponse(batch)) = from_seasoned.try_recv() else {
+            panic!("the request should be answered with a batch");
+        };
+        forked.network_message_arrives("peer", NetworkMessage::ChainSyncResponse(batch)).await.unwrap();
+
+        let forked_tip = forked.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let seasoned_tip = seasoned.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(forked_tip.chapter_essence, seasoned_tip.chapter_essence);
+        assert!(!forked.network_storytellers.get_sync_status().await.is_syncing);
+    }
+
+    #[tokio::test]
+    async fn an_auditor_learns_who_spent_an_output_and_when() {
+        let mut chronicle = quiet_chronicle("spend_provenance").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        commit_chapter_of(&chronicle, vec![funding_story(&chronicle, &alice, "alice_funding", 100_000)]).await;
+        assert_eq!(chronicle.utxo_ledger.get_spending_tx("alice_funding:0").unwrap(), None);
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let chapter = honestly_mined_chapter(&chronicle, vec![payment.clone()]).await;
+        chronicle.receive_block_chapter(chapter.clone()).await.unwrap();
+
+        assert_eq!(chronicle.utxo_ledger.get_spending_tx("alice_funding:0").unwrap(),
+            Some((payment.story_id.clone(), 2)));
+        assert_eq!(chronicle.utxo_ledger.get_spending_tx(&format!("{}:0", payment.story_id)).unwrap(), None);
+
+        // Unwinding the chapter forgets the spend along with it
+        chronicle.utxo_ledger.revert_block(&chapter, &chronicle.chain_repository).await.unwrap();
+        assert_eq!(chronicle.utxo_ledger.get_spending_tx("alice_funding:0").unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn a_paused_heart_mines_nothing_until_it_beats_again() {
+        let mut chronicle = quiet_chronicle("pause_mining").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let carol = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&chronicle, &carol, "carol_funding", 100_000);
+        chronicle.awaken_the_mining_heart().await.unwrap();
+
+        async fn reaches_height(chronicle: &BlockchainChronicler, height: u64) {
+            let grown = async {
+                while chronicle.chain_height().await.unwrap() < height {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+            };
+            tokio::time::timeout(Duration::from_secs(5), grown).await
+                .expect("the heart should mine another chapter");
+        }
+
+        let alice_pays = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(alice_pays).await.unwrap();
+        reaches_height(&chronicle, 1).await;
+
+        chronicle.pause_mining();
+        let carol_pays = chronicle.create_transaction(&carol, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(carol_pays).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1_500)).await;
+        assert_eq!(chronicle.chain_height().await.unwrap(), 1);
+        assert_eq!(chronicle.mempool_of_pending_tales.lock().unwrap().len(), 1);
+
+        chronicle.resume_mining();
+        reaches_height(&chronicle, 2).await;
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 20_000);
+
+        chronicle.stop_mining().await;
+        assert!(chronicle.mining_heart.as_ref().unwrap().mining_quest.is_none());
+    }
+
+    #[tokio::test]
+    async fn a_subscriber_hears_of_the_story_and_the_chapter_mined_from_it() {
+        let mut chronicle = quiet_chronicle("chain_events").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let mut events = chronicle.subscribe_events();
+        chronicle.awaken_the_mining_heart().await.unwrap();
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(payment.clone()).await.unwrap();
+
+        let accepted = tokio::time::timeout(Duration::from_secs(5), events.recv()).await.unwrap().unwrap();
+        assert!(matches!(accepted, ChainEvent::TransactionAccepted(story) if story.story_id == payment.story_id));
+        let added = tokio::time::timeout(Duration::from_secs(5), events.recv()).await
+            .expect("the heart should mine the story into a chapter").unwrap();
+        let ChainEvent::BlockAdded(chapter) = added else {
+            panic!("expected a chapter, heard {:?}", added);
+        };
+        assert_eq!(chapter.chapter_number, 1);
+        assert!(chapter.transaction_tales.iter().any(|story| story.story_id == payment.story_id));
+
+        // By the time the event is heard, the chapter is already stored
+        let tip = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        assert_eq!(tip.chapter_essence, chapter.chapter_essence);
+        chronicle.stop_mining().await;
+    }
+
+    #[tokio::test]
+    async fn an_observer_relays_stories_but_mines_none() {
+        let config = ChronicleConfiguration {
+            data_directory: tale_directory("observer"),
+            network_port: 0,
+            observer_mode: true,
+            ..ChronicleConfiguration::default()
+        };
+        let mut observer = BlockchainChronicler::new_chronicle_begins(config).await.unwrap();
+        assert!(observer.mining_heart.is_none());
+        let mut relayed = observer.take_outgoing_messages();
+
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&observer, &alice, "alice_funding", 100_000);
+        let payment = observer.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        observer.transaction_story_arrives(payment.clone()).await.unwrap();
+        assert!(matches!(relayed.try_recv(),
+            Ok(NetworkMessage::NewTransactionStory(story)) if story.story_id == payment.story_id));
+
+        // A miner would have picked the story up within a second or two
+        tokio::time::sleep(Duration::from_millis(2_500)).await;
+        assert_eq!(observer.chain_height().await.unwrap(), 0);
+        assert_eq!(observer.mempool_of_pending_tales.lock().unwrap().len(), 1);
+        assert_eq!(observer.get_balance(&bob).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn a_story_told_to_one_node_reaches_its_peer_over_tcp() {
+        let mut listening = quiet_chronicle("tcp_listener").await;
+        let mut connecting = quiet_chronicle("tcp_connector").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&listening, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&connecting, &alice, "alice_funding", 100_000);
+
+        let address = listening.network_storytellers.begin_listening(0).unwrap();
+        connecting.connect_to_peer(&format!("127.0.0.1:{}", address.port())).await.unwrap();
+        assert_eq!(connecting.network_storytellers.get_peer_count().await, 1);
+
+        let payment = connecting.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        connecting.transaction_story_arrives(payment.clone()).await.unwrap();
+
+        let arrived = async {
+            loop {
+                listening.heed_incoming_messages().await;
+                if listening.mempool_of_pending_tales.lock().unwrap()
+                    .iter().any(|story| story.story_id == payment.story_id) {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), arrived).await
+            .expect("the story should cross the wire");
+        assert_eq!(listening.network_storytellers.get_peer_count().await, 1);
+
+        // The connecting node's handshake came first and named its tip
+        let connecting_tip = connecting.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let reports = listening.network_storytellers.peer_tip_reports.read().unwrap();
+        let tips_at_genesis: Vec<&String> = reports.get(&0).unwrap().values().collect();
+        assert_eq!(tips_at_genesis, vec![&connecting_tip.chapter_essence]);
+    }
+
+    #[tokio::test]
+    async fn a_running_node_heeds_its_peers_unasked() {
+        let mut listening = quiet_chronicle("tcp_heeding").await;
+        let mut connecting = quiet_chronicle("tcp_telling").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&listening, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&connecting, &alice, "alice_funding", 100_000);
+
+        let address = listening.network_storytellers.begin_listening(0).unwrap();
+        connecting.connect_to_peer(&format!("127.0.0.1:{}", address.port())).await.unwrap();
+        let payment = connecting.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        connecting.transaction_story_arrives(payment.clone()).await.unwrap();
+
+        // The node runs until its task is dropped, here by the timeout
+        let running = tokio::time::timeout(Duration::from_secs(1), listening.keep_heeding_peers()).await;
+        assert!(running.is_err());
+        assert!(listening.mempool_of_pending_tales.lock().unwrap()
+            .iter().any(|story| story.story_id == payment.story_id));
+    }
+
+    #[test]
+    fn a_frame_carries_exactly_one_message() {
+        let message = NetworkMessage::Heartbeat(42);
+        let mut frames = frame_network_message(&message).unwrap();
+        frames.extend(frame_network_message(&NetworkMessage::RequestChainSync(7)).unwrap());
+
+        let mut wire = std::io::Cursor::new(frames);
+        assert!(matches!(read_network_frame(&mut wire), Ok(NetworkMessage::Heartbeat(42))));
+        assert!(matches!(read_network_frame(&mut wire), Ok(NetworkMessage::RequestChainSync(7))));
+        assert!(matches!(read_network_frame(&mut wire), Err(ChronicleError::NetworkError(_))));
+
+        let boastful = (MAX_NETWORK_FRAME_BYTES + 1).to_be_bytes().to_vec();
+        assert!(matches!(read_network_frame(&mut std::io::Cursor::new(boastful)), Err(ChronicleError::NetworkError(_))));
+    }
+
+    #[tokio::test]
+    async fn a_chapter_crosses_the_sim_network_in_simulated_time() {
+        let mut chronicles = vec![
+            quiet_chronicle("sim_ada").await,
+            quiet_chronicle("sim_bram").await,
+            quiet_chronicle("sim_cleo").await,
+        ];
+        let network = SimNetwork::new(7);
+        for chronicle in &chronicles {
+            chronicle.carry_messages_over(Arc::new(network.join()));
+        }
+        let forty_ms = SimLink { latency: Duration::from_millis(40), loss_probability: 0.0 };
+        network.link(0, 1, forty_ms);
+        network.link(1, 2, forty_ms);
+        // The short road from Ada to Cleo loses everything, so Cleo hears through Bram
+        network.link(0, 2, SimLink { latency: Duration::from_millis(10), loss_probability: 1.0 });
+
+        let chapter = honestly_mined_chapter(&chronicles[0], vec![]).await;
+        chronicles[0].network_message_arrives("peer", NetworkMessage::NewBlockChapter(chapter.clone())).await.unwrap();
+
+        let mut joined_at = HashMap::new();
+        while let Some(delivery) = network.deliver_next() {
+            let node = delivery.to_node;
+            chronicles[node].network_message_arrives(&SimTransport::address_of(delivery.from_node), delivery.message).await.unwrap();
+            let tip = chronicles[node].chain_repository.get_chain_tip().await.unwrap().unwrap();
+            if tip.chapter_essence == chapter.chapter_essence {
+                joined_at.entry(node).or_insert(delivery.arrives_at);
+            }
+        }
+
+        assert_eq!(joined_at.get(&1), Some(&Duration::from_millis(40)));
+        assert_eq!(joined_at.get(&2), Some(&Duration::from_millis(80)));
+        // Cleo's relay back to Bram is the last message heard
+        assert_eq!(network.now(), Duration::from_millis(120));
+    }
+
+    #[tokio::test]
+    async fn what_a_peer_asks_is_answered_to_that_peer_alone() {
+        let mut chronicles = vec![
+            quiet_chronicle("asked_ada").await,
+            quiet_chronicle("asked_bram").await,
+            quiet_chronicle("asked_cleo").await,
+        ];
+        for _ in 0..2 {
+            let chapter = honestly_mined_chapter(&chronicles[0], vec![]).await;
+            chronicles[0].receive_block_chapter(chapter).await.unwrap();
+        }
+        let network = SimNetwork::new(11);
+        for chronicle in &chronicles {
+            chronicle.carry_messages_over(Arc::new(network.join()));
+        }
+        let ten_ms = SimLink { latency: Duration::from_millis(10), loss_probability: 0.0 };
+        network.link(0, 1, ten_ms);
+        network.link(0, 2, ten_ms);
+        network.link(1, 2, ten_ms);
+
+        // Bram asks for chapters and Cleo reports a shorter chain
+        chronicles[0].network_message_arrives(&SimTransport::address_of(1), NetworkMessage::RequestChainSync(1)).await.unwrap();
+        chronicles[0].network_message_arrives(&SimTransport::address_of(2), NetworkMessage::Heartbeat(0)).await.unwrap();
+
+        let mut heard = Vec::new();
+        while let Some(delivery) = network.deliver_next() {
+            heard.push(delivery);
+        }
+        assert_eq!(heard.len(), 2);
+        assert!(heard.iter().all(|delivery| delivery.from_node == 0));
+        assert!(matches!(&heard[0], SimDelivery { to_node: 1, message: NetworkMessage::ChainSyncResponse(batch), .. } if batch.len() == 2));
+        assert!(matches!(&heard[1], SimDelivery { to_node: 2, message: NetworkMessage::Heartbeat(2), .. }));
+
+        // No road leads to a stranger, so it cannot be answered
+        let stranger = chronicles[0].network_message_arrives("sim:9", NetworkMessage::RequestChainSync(1)).await;
+        assert!(matches!(stranger, Err(ChronicleError::NetworkError(_))));
+    }
+
+    #[tokio::test]
+    async fn a_told_nonce_cannot_be_told_again() {
+        let mut chronicle = quiet_chronicle("nonce_replay").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let alice_address = alice.public.to_bytes();
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        assert_eq!(payment.transaction_nonce, 1);
+        chronicle.transaction_story_arrives(payment.clone()).await.unwrap();
+        // Waiting in the mempool does not yet use the nonce up
+        assert_eq!(chronicle.chain_repository.highest_committed_nonce(&alice_address).unwrap(), 0);
+        commit_chapter_of(&chronicle, vec![payment.clone()]).await;
+        assert_eq!(chronicle.chain_repository.highest_committed_nonce(&alice_address).unwrap(), 1);
+
+        let mut replay = chronicle.build_unsigned_transaction(&alice_address, &bob, 10_000, 1_000)
+            .await.unwrap().story;
+        replay.transaction_nonce = payment.transaction_nonce;
+        chronicle.narrator_signs_the_story(&mut replay, &alice);
+        assert!(matches!(chronicle.transaction_story_arrives(replay).await, Err(ChronicleError::InvalidNonce(1))));
+
+        let fresh = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        assert_eq!(fresh.transaction_nonce, 2);
+        chronicle.transaction_story_arrives(fresh).await.unwrap();
+
+        // Unwinding the chapter gives the nonce back
+        chronicle.chain_repository.rewind_to(0).await.unwrap();
+        assert_eq!(chronicle.chain_repository.highest_committed_nonce(&alice_address).unwrap(), 0);
+    }
+
+    /// Places an output behind `locking_script` straight into the ledger,
+    /// addressed to `holder` so the holder's wallet finds it
+    fn grant_locked_tale(chronicle: &BlockchainChronicler, holder: &Keypair, story_id: &str, locking_script: ScriptOfTruth) {
+        let output = UTXOOutput {
+            recipient_address: holder.public.to_bytes().to_vec(),
+            value_locked: 100_000,
+            locking_script,
+        };
+        chronicle.utxo_ledger.db
+            .insert(format!("{}:0", story_id), bincode::serialize(&output).unwrap())
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn two_of_three_keepers_must_agree_to_open_the_vault() {
+        let chronicle = quiet_chronicle("multisig").await;
+        let keepers: Vec<Keypair> = (0..3).map(|_| Keypair::generate(&mut OsRng)).collect();
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let vault_script = ScriptOfTruth {
+            script_type: ScriptType::PayToMultiSig,
+            required_signatures: 2,
+            public_keys: keepers.iter().map(|keeper| keeper.public.to_bytes().to_vec()).collect(),
+        };
+        grant_locked_tale(&chronicle, &keepers[0], "vault", vault_script.clone());
+
+        let unsigned = chronicle.build_unsigned_transaction(&keepers[0].public.to_bytes(), &bob, 10_000, 1_000)
+            .await.unwrap();
+        let mut spend = unsigned.story;
+        chronicle.narrator_signs_the_story(&mut spend, &keepers[0]);
+
+        let alone = chronicle.validate_verbose(&spend).await;
+        assert!(alone.failures.iter().any(|failure| matches!(failure,
+            ChronicleError::ScriptFailed(reason) if reason.contains("1 of 2"))));
+
+        let second_signature = keepers[2].sign(&unsigned.signable_message).to_bytes().to_vec();
+        spend.input_witnesses = vec![InputWitness { signatures: vec![second_signature.clone()], revealed_script: None }];
+        assert!(chronicle.validate_verbose(&spend).await.is_valid());
+
+        // The same vault behind a script hash opens only with the true script revealed
+        grant_locked_tale(&chronicle, &keepers[0], "hidden_vault", ScriptOfTruth {
+            script_type: ScriptType::PayToScriptHash,
+            required_signatures: 0,
+            public_keys: vec![script_hash(&vault_script)],
+        });
+        chronicle.utxo_ledger.db.remove("vault:0").unwrap();
+        let unsigned = chronicle.build_unsigned_transaction(&keepers[0].public.to_bytes(), &bob, 10_000, 1_000)
+            .await.unwrap();
+        let mut hidden_spend = unsigned.story;
+        chronicle.narrator_signs_the_story(&mut hidden_spend, &keepers[0]);
+        let second_signature = keepers[1].sign(&unsigned.signable_message).to_bytes().to_vec();
+        let forged_script = ScriptOfTruth { required_signatures: 1, ..vault_script.clone() };
+        hidden_spend.input_witnesses = vec![InputWitness {
+            signatures: vec![second_signature.clone()],
+            revealed_script: Some(forged_script),
+        }];
+        assert!(!chronicle.validate_verbose(&hidden_spend).await.is_valid());
+
+        hidden_spend.input_witnesses[0].revealed_script = Some(vault_script);
+        assert!(chronicle.validate_verbose(&hidden_spend).await.is_valid());
+    }
+
+    #[tokio::test]
+    async fn a_relayer_cannot_relock_an_output_for_itself() {
+        let chronicle = quiet_chronicle("relocked_output").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let thief = Keypair::generate(&mut OsRng).public.to_bytes().to_vec();
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 10_000);
+
+        let honest = chronicle.create_transaction(&alice, &bob, 5_000, 1_000).await.unwrap();
+        assert!(chronicle.validate_verbose(&honest).await.is_valid());
+
+        // Bob's address stays on the output, but only the thief could spend it
+        let mut relocked = honest.clone();
+        relocked.outputs_created[0].locking_script.public_keys = vec![thief];
+        let verdict = chronicle.validate_verbose(&relocked).await;
+        assert!(matches!(verdict.failures[0], ChronicleError::StoryBearsFalseWitness(_)));
+
+        let mut cheapened = honest;
+        cheapened.story_fee = 500;
+        let verdict = chronicle.validate_verbose(&cheapened).await;
+        assert!(matches!(verdict.failures[0], ChronicleError::StoryBearsFalseWitness(_)));
+    }
+
+    #[tokio::test]
+    async fn a_frozen_snapshot_ignores_the_chapters_that_follow() {
+        let chronicle = quiet_chronicle("snapshot").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        commit_chapter_of(&chronicle, vec![funding_story(&chronicle, &alice, "alice_funding", 100_000)]).await;
+
+        let snapshot = chronicle.snapshot_at(1).await.unwrap();
+
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![payment]).await;
+        commit_chapter_of(&chronicle, vec![]).await;
+        grant_unspent_tale(&chronicle, &alice, "alice_windfall", 5_000);
+
+        let alice_address = alice.public.to_bytes();
+        assert_eq!(chronicle.get_balance(&alice_address).await.unwrap(), 94_000);
+        assert_eq!(snapshot.height(), 1);
+        assert_eq!(snapshot.get_balance(&alice_address).unwrap(), 100_000);
+        assert_eq!(snapshot.find_utxos_for_address(&alice_address).len(), 1);
+        assert!(snapshot.get_block(1).is_some());
+        assert!(snapshot.get_block(2).is_none());
+
+        assert!(matches!(chronicle.snapshot_at(9).await, Err(ChronicleError::ChapterNotFound(9))));
+    }
+
+    #[tokio::test]
+    async fn snapshots_share_whatever_has_not_changed() {
+        let chronicle = quiet_chronicle("snapshot_sharing").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        commit_chapter_of(&chronicle, vec![funding_story(&chronicle, &alice, "alice_funding", 100_000)]).await;
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![payment]).await;
+
+        // Two snapshots at one tip share one frozen base
+        let before_payment = chronicle.snapshot_at(1).await.unwrap();
+        let at_tip = chronicle.snapshot_at(2).await.unwrap();
+        assert!(Arc::ptr_eq(&before_payment.unspent_outputs, &at_tip.unspent_outputs));
+        assert!(Arc::ptr_eq(&before_payment.chapters, &at_tip.chapters));
+        assert!(before_payment.find_unspent_output("alice_funding:0").is_some());
+        assert!(at_tip.find_unspent_output("alice_funding:0").is_none());
+        assert_eq!(before_payment.get_balance(&bob).unwrap(), 0);
+        assert_eq!(at_tip.get_balance(&bob).unwrap(), 10_000);
+
+        // The base for a later tip still shares every chapter the last one held
+        commit_chapter_of(&chronicle, vec![]).await;
+        let later = chronicle.snapshot_at(3).await.unwrap();
+        assert!(!Arc::ptr_eq(&later.unspent_outputs, &at_tip.unspent_outputs));
+        assert!(Arc::ptr_eq(later.chapters[2].as_ref().unwrap(), at_tip.chapters[2].as_ref().unwrap()));
+        assert_eq!(at_tip.get_balance(&bob).unwrap(), 10_000);
+        assert!(at_tip.get_block(3).is_none());
+    }
+
+    #[tokio::test]
+    async fn a_pruned_node_freezes_only_the_chapters_it_keeps() {
+        let chronicle = chronicle_with("snapshot_pruned", ChronicleConfiguration {
+            node_mode: NodeMode::Pruned { keep_blocks: 3 },
+            ..ChronicleConfiguration::default()
+        }).await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        commit_chapter_of(&chronicle, vec![funding_story(&chronicle, &alice, "alice_funding", 100_000)]).await;
+        commit_chapter_of(&chronicle, Vec::new()).await;
+        commit_chapter_of(&chronicle, Vec::new()).await;
+        let payment = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        commit_chapter_of(&chronicle, vec![payment]).await;
+        commit_chapter_of(&chronicle, Vec::new()).await;
+        BlockchainChronicler::prune_to_node_mode(&chronicle.chain_repository, &chronicle.configuration, 5).unwrap();
+        assert!(chronicle.chain_repository.load_chapter(2).unwrap().is_none());
+
+        let before_payment = chronicle.snapshot_at(3).await.unwrap();
+        assert_eq!(before_payment.get_balance(&bob).unwrap(), 0);
+        assert!(before_payment.get_block(3).is_some());
+        assert!(before_payment.get_block(2).is_none());
+        assert_eq!(chronicle.snapshot_at(5).await.unwrap().get_balance(&bob).unwrap(), 10_000);
+        assert!(matches!(chronicle.snapshot_at(1).await, Err(ChronicleError::ChapterPruned(1))));
+
+        assert_eq!(chronicle.chain_stats(3).await.unwrap().chapters_observed, 3);
+        assert!(matches!(chronicle.chain_stats(10).await, Err(ChronicleError::ChapterPruned(0))));
+        assert_eq!(chronicle.export_tx_graph(3, 5).await.unwrap().nodes.len(), 1);
+        assert!(matches!(chronicle.export_tx_graph(1, 5).await, Err(ChronicleError::ChapterPruned(1))));
+        assert!(matches!(chronicle.export_tx_graph(3, 9).await, Err(ChronicleError::ChapterNotFound(6))));
+    }
+
+    #[tokio::test]
+    async fn a_stuck_story_bumps_its_fee_and_replaces_itself() {
+        let mut chronicle = quiet_chronicle("bump_fee").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let stuck = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.transaction_story_arrives(stuck.clone()).await.unwrap();
+
+        let bumped = chronicle.bump_fee(&stuck.story_id, &alice, 1_500).await.unwrap();
+
+        assert_eq!(bumped.story_fee, 2_500);
+        assert_eq!(bumped.inputs_consumed, stuck.inputs_consumed);
+        let change = bumped.outputs_created.iter()
+            .find(|output| output.recipient_address == alice.public.to_bytes().to_vec())
+            .unwrap();
+        assert_eq!(change.value_locked, 87_500);
+
+        let mempool = chronicle.mempool_of_pending_tales.lock().unwrap().clone();
+        assert_eq!(mempool.len(), 1);
+        assert_eq!(mempool[0].story_id, bumped.story_id);
+
+        let audit = chronicle.mempool_audit().await;
+        assert!(audit.iter().any(|entry| entry.story_id == stuck.story_id
+            && entry.verdict == MempoolVerdict::Replaced { replaced_by: bumped.story_id.clone() }));
+    }
+
+    #[tokio::test]
+    async fn a_full_mempool_evicts_by_fee_rate_not_by_fee() {
+        let mut chronicle = quiet_chronicle("fee_rate_eviction").await;
+        chronicle.configuration.max_mempool_stories = 2;
+        chronicle.configuration.min_fee_rate = 0;
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        // Carol pays from many small outputs, so her story is large
+        let carol = Keypair::generate(&mut OsRng);
+        for funding in 0..12 {
+            grant_unspent_tale(&chronicle, &carol, &format!("carol_funding_{}", funding), 1_000);
+        }
+        let bulky = chronicle.create_transaction(&carol, &bob, 9_000, 1_500).await.unwrap();
+        let mut small = Vec::new();
+        for narrator_name in ["alice", "dave"] {
+            let narrator = Keypair::generate(&mut OsRng);
+            grant_unspent_tale(&chronicle, &narrator, &format!("{}_funding", narrator_name), 100_000);
+            small.push(chronicle.create_transaction(&narrator, &bob, 10_000, 1_200).await.unwrap());
+        }
+        assert!(bulky.story_fee > small[0].story_fee);
+        assert!(story_fee_rate(&bulky) < story_fee_rate(&small[0]));
+
+        chronicle.transaction_story_arrives(bulky.clone()).await.unwrap();
+        for story in &small {
+            chronicle.transaction_story_arrives(story.clone()).await.unwrap();
+        }
+
+        let pending: HashSet<String> = chronicle.mempool_of_pending_tales.lock().unwrap()
+            .iter()
+            .map(|story| story.story_id.clone())
+            .collect();
+        assert_eq!(pending, small.iter().map(|story| story.story_id.clone()).collect());
+        assert!(chronicle.mempool_audit().await.iter().any(|entry| entry.story_id == bulky.story_id
+            && matches!(entry.verdict, MempoolVerdict::Evicted(_))));
+
+        let histogram = chronicle.mempool_fee_histogram();
+        assert_eq!(histogram.iter().map(|(_, stories)| stories).sum::<usize>(), 2);
+        assert!(histogram.windows(2).all(|pair| pair[0].0 > pair[1].0));
+        assert!(histogram.iter().all(|(fee_rate, _)| *fee_rate > story_fee_rate(&bulky)));
+    }
+
+    #[tokio::test]
+    async fn a_story_paying_too_little_per_byte_is_refused() {
+        let mut chronicle = quiet_chronicle("min_fee_rate").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let stingy = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        chronicle.configuration.min_fee_rate = story_fee_rate(&stingy) + 1;
+
+        let refused = chronicle.transaction_story_arrives(stingy.clone()).await;
+        assert!(matches!(refused, Err(ChronicleError::FeeRateTooLow(fee_rate)) if fee_rate == story_fee_rate(&stingy)));
+        assert!(chronicle.mempool_fee_histogram().is_empty());
+    }
+
+    #[tokio::test]
+    async fn the_flat_minimum_fee_buys_passage_only_for_a_small_story() {
+        let mut chronicle = quiet_chronicle("flat_fee_floor").await;
+        let flat_fee = chronicle.configuration.min_transaction_fee;
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+
+        let alice = Keypair::generate(&mut OsRng);
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+        let small = chronicle.create_transaction(&alice, &bob, 10_000, flat_fee).await.unwrap();
+
+        // Carol gathers many small outputs, so the same fee is spread over far more bytes
+        let carol = Keypair::generate(&mut OsRng);
+        for funding in 0..12 {
+            grant_unspent_tale(&chronicle, &carol, &format!("carol_funding_{}", funding), 1_000);
+        }
+        let bulky = chronicle.create_transaction(&carol, &bob, 9_000, flat_fee).await.unwrap();
+        chronicle.configuration.min_fee_rate = story_fee_rate(&small);
+        assert!(story_fee_rate(&bulky) < chronicle.configuration.min_fee_rate);
+
+        chronicle.transaction_story_arrives(small).await.unwrap();
+        let refused = chronicle.transaction_story_arrives(bulky.clone()).await;
+        assert!(matches!(refused, Err(ChronicleError::FeeRateTooLow(fee_rate)) if fee_rate == story_fee_rate(&bulky)));
+    }
+
+    #[tokio::test]
+    async fn a_peer_is_told_why_its_stingy_story_was_refused() {
+        let mut sender = quiet_chronicle("reject_sender").await;
+        let mut receiver = quiet_chronicle("reject_receiver").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&sender, &alice, "alice_funding", 100_000);
+        grant_unspent_tale(&receiver, &alice, "alice_funding", 100_000);
+        let stingy_fee = receiver.configuration.min_transaction_fee - 1;
+        let stingy = sender.create_transaction(&alice, &bob, 10_000, stingy_fee).await.unwrap();
+
+        let bystander = quiet_chronicle("reject_bystander").await;
+        let network = SimNetwork::new(5);
+        for chronicle in [&sender, &receiver, &bystander] {
+            chronicle.carry_messages_over(Arc::new(network.join()));
+        }
+        let ten_ms = SimLink { latency: Duration::from_millis(10), loss_probability: 0.0 };
+        network.link(0, 1, ten_ms);
+        network.link(1, 2, ten_ms);
+        network.link(0, 2, ten_ms);
+
+        let mut answers = receiver.take_outgoing_messages();
+        let refused = receiver.network_message_arrives(&SimTransport::address_of(0), NetworkMessage::NewTransactionStory(stingy.clone())).await;
+        assert!(matches!(refused, Err(ChronicleError::InsufficientFee(fee)) if fee == stingy_fee));
+
+        let Ok(rejection) = answers.try_recv() else {
+            panic!("the receiver never answered");
+        };
+        let NetworkMessage::Reject { target_id, reason_code, .. } = rejection.clone() else {
+            panic!("the receiver answered with {:?}", rejection);
+        };
+        assert_eq!(target_id, stingy.story_id);
+        assert_eq!(reason_code, RejectCode::InsufficientFee);
+
+        // Only the sender is told; the bystander sent nothing wrong
+        let delivery = network.deliver_next().expect("the rejection should be on its way");
+        assert_eq!((delivery.from_node, delivery.to_node), (1, 0));
+        assert!(network.deliver_next().is_none());
+
+        // The sender hears the rejection and says nothing more
+        let mut echoes = sender.take_outgoing_messages();
+        sender.network_message_arrives("peer", rejection).await.unwrap();
+        assert!(echoes.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn a_flawed_story_hears_every_verdict_at_once() {
+        let chronicle = quiet_chronicle("validate_verbose").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 10_000);
+
+        let honest = chronicle.create_transaction(&alice, &bob, 5_000, 1_000).await.unwrap();
+        assert!(chronicle.validate_verbose(&honest).await.is_valid());
+
+        let mut flawed = honest.clone();
+        flawed.transaction_nonce = 0;
+        flawed.story_fee = 10;
+        flawed.outputs_created[0].value_locked = 20_000;
+
+        // The forged story no longer unlocks Alice's output either, and both are told
+        let verdict = chronicle.validate_verbose(&flawed).await;
+        assert!(!verdict.is_valid());
+        assert_eq!(verdict.failures.len(), 5);
+        assert!(matches!(verdict.failures[0], ChronicleError::StoryBearsFalseWitness(_)));
+        assert!(matches!(verdict.failures[1], ChronicleError::ScriptFailed(_)));
+        assert!(matches!(verdict.failures[2], ChronicleError::NarratorLacksResources(_)));
+        assert!(matches!(verdict.failures[3], ChronicleError::InsufficientFee(10)));
+        assert!(matches!(verdict.failures[4], ChronicleError::InvalidNonce(0)));
+    }
+
+    #[tokio::test]
+    async fn a_story_from_the_far_future_is_turned_away() {
+        let mut chronicle = quiet_chronicle("future_story").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 10_000);
+
+        let mut prophecy = chronicle.create_transaction(&alice, &bob, 5_000, 1_000).await.unwrap();
+        prophecy.timestamp_of_telling = current_timestamp() + chronicle.configuration.max_future_drift_secs + 60;
+        chronicle.narrator_signs_the_story(&mut prophecy, &alice);
+
+        let verdict = chronicle.transaction_story_arrives(prophecy).await;
+        assert!(matches!(verdict, Err(ChronicleError::InvalidTimestamp(_))));
+
+        let mut slightly_ahead = chronicle.create_transaction(&alice, &bob, 5_000, 1_000).await.unwrap();
+        slightly_ahead.timestamp_of_telling = current_timestamp() + 60;
+        chronicle.narrator_signs_the_story(&mut slightly_ahead, &alice);
+        chronicle.transaction_story_arrives(slightly_ahead).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_chapter_cannot_hold_a_story_told_after_it() {
+        let chronicle = quiet_chronicle("anachronism").await;
+        let genesis = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+
+        let mut late_tale = pending_tale(1_000);
+        late_tale.timestamp_of_telling = genesis.timestamp_of_creation + 1;
+        let chapter = BlockChapter {
+            chapter_number: 1,
+            previous_chapter_essence: genesis.chapter_essence.clone(),
+            transaction_tales: vec![late_tale],
+            ..genesis.clone()
+        };
+        assert!(matches!(
+            BlockchainChronicler::chapter_keeps_its_chronology(&chapter),
+            Err(ChronicleError::InvalidTimestamp(_))
+        ));
+
+        let mut timely_tale = pending_tale(1_000);
+        timely_tale.timestamp_of_telling = genesis.timestamp_of_creation;
+        let chapter = BlockChapter { transaction_tales: vec![timely_tale], ..chapter };
+        assert!(BlockchainChronicler::chapter_keeps_its_chronology(&chapter).is_ok());
+    }
+
+    #[test]
+    fn a_vouched_story_is_packed_before_richer_ones() {
+        let now = current_timestamp();
+        let modest = pending_tale(1_000);
+        let rich = pending_tale(50_000);
+        let richer = pending_tale(90_000);
+        let mut pool = vec![rich.clone(), modest.clone(), richer.clone()];
+
+        let boosts = HashMap::from([(modest.story_id.clone(), 1)]);
+        let chapter_tales = BlockchainChronicler::select_tales_for_chapter(&mut pool, &boosts, now, usize::MAX);
+
+        let packed: Vec<&str> = chapter_tales.iter().map(|story| story.story_id.as_str()).collect();
+        assert_eq!(packed, vec![modest.story_id.as_str(), richer.story_id.as_str(), rich.story_id.as_str()]);
+        assert!(pool.is_empty());
+    }
+
+    #[tokio::test]
+    async fn two_stories_spending_one_output_never_share_a_chapter() {
+        let mut chronicle = quiet_chronicle("chapter_double_spend").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        let carol = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 100_000);
+
+        let to_bob = chronicle.create_transaction(&alice, &bob, 10_000, 1_000).await.unwrap();
+        let to_carol = chronicle.create_transaction(&alice, &carol, 10_000, 2_000).await.unwrap();
+        assert_eq!(to_bob.inputs_consumed, to_carol.inputs_consumed);
+
+        // At the gate, the second claim on the output is only let in by displacing the first
+        chronicle.transaction_story_arrives(to_bob.clone()).await.unwrap();
+        chronicle.transaction_story_arrives(to_carol.clone()).await.unwrap();
+        assert_eq!(chronicle.mempool_of_pending_tales.lock().unwrap().len(), 1);
+
+        // A pool that holds both anyway (say, after a reorg) still packs only one
+        let mut pool = vec![to_bob.clone(), to_carol.clone()];
+        let chapter_tales = BlockchainChronicler::select_tales_for_chapter(&mut pool, &HashMap::new(), current_timestamp(), usize::MAX);
+        let packed: Vec<&str> = chapter_tales.iter().map(|story| story.story_id.as_str()).collect();
+        assert_eq!(packed, vec![to_carol.story_id.as_str()]);
+        assert!(pool.is_empty());
+
+        let chapter = honestly_mined_chapter(&chronicle, chapter_tales).await;
+        chronicle.receive_block_chapter(chapter).await.unwrap();
+        assert_eq!(chronicle.get_balance(&carol).await.unwrap(), 10_000);
+        assert_eq!(chronicle.get_balance(&bob).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn a_patron_pays_for_a_feeless_story() {
+        let mut chronicle = quiet_chronicle("sponsored_fee").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let patron = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 10_000);
+        grant_unspent_tale(&chronicle, &patron, "patron_funding", 5_000);
+
+        // Alice spends her whole balance and leaves nothing for the fee
+        let feeless = chronicle.create_transaction(&alice, &bob, 10_000, 0).await.unwrap();
+        assert!(!chronicle.validate_verbose(&feeless).await.is_valid());
+
+        let sponsored = chronicle.sponsor_story_fee(feeless.clone(), &patron, 2_000).await.unwrap();
+        assert!(chronicle.validate_verbose(&sponsored).await.is_valid());
+
+        let mut inflated = sponsored.clone();
+        inflated.story_fee = 4_000;
+        assert!(!chronicle.validate_verbose(&inflated).await.is_valid());
+
+        chronicle.transaction_story_arrives(sponsored).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn the_same_story_always_becomes_the_same_bytes() {
+        let chronicle = quiet_chronicle("canonical_bytes").await;
+        let alice = Keypair::generate(&mut OsRng);
+        let patron = Keypair::generate(&mut OsRng);
+        let bob = Keypair::generate(&mut OsRng).public.to_bytes();
+        grant_unspent_tale(&chronicle, &alice, "alice_funding", 10_000);
+        grant_unspent_tale(&chronicle, &patron, "patron_funding", 5_000);
+
+        let story = chronicle.create_transaction(&alice, &bob, 4_000, 0).await.unwrap();
+        let story = chronicle.sponsor_story_fee(story, &patron, 1_000).await.unwrap();
+
+        let first_telling = story.canonical_bytes().unwrap();
+        for _ in 0..100 {
+            assert_eq!(story.clone().canonical_bytes().unwrap(), first_telling);
+        }
+
+        let retold: TransactionStory = bincode::deserialize(&first_telling).unwrap();
+        assert_eq!(retold.canonical_bytes().unwrap(), first_telling);
+
+        let genesis = chronicle.chain_repository.get_chain_tip().await.unwrap().unwrap();
+        let chapter = BlockChapter { transaction_tales: vec![story; 3], ..genesis };
+        let chapter_bytes = chapter.canonical_bytes().unwrap();
+        for _ in 0..100 {
+            assert_eq!(chapter.clone().canonical_bytes().unwrap(), chapter_bytes);
+        }
+    }
+
+    #[test]
+    fn a_light_client_proves_the_third_story_of_five() {
+        let tales: Vec<TransactionStory> = (0..5).map(|_| pending_tale(1_000)).collect();
+        let root = BlockchainChronicler::weave_merkle_tree_of_truth(&tales);
+
+        let third = &tales[2];
+        let proof = BlockchainChronicler::merkle_proof(&tales, &third.story_id).unwrap();
+        // Five leaves climb three levels to the root
+        assert_eq!(proof.len(), 3);
+        assert!(proof[0].1);
+        let third_leaf = BlockchainChronicler::merkle_leaf_hash(third);
+        assert!(BlockchainChronicler::verify_merkle_proof(&third_leaf, &proof, &root));
+
+        // The proof speaks for the third story only, and only under this root
+        let first_leaf = BlockchainChronicler::merkle_leaf_hash(&tales[0]);
+        assert!(!BlockchainChronicler::verify_merkle_proof(&first_leaf, &proof, &root));
+        let mut flipped = proof.clone();
+        flipped[1].1 = !flipped[1].1;
+        assert!(!BlockchainChronicler::verify_merkle_proof(&third_leaf, &flipped, &root));
+
+        // The odd fifth story, paired with itself, proves just as well
+        for story in &tales {
+            let proof = BlockchainChronicler::merkle_proof(&tales, &story.story_id).unwrap();
+            let leaf = BlockchainChronicler::merkle_leaf_hash(story);
+            assert!(BlockchainChronicler::verify_merkle_proof(&leaf, &proof, &root));
+        }
+        assert!(BlockchainChronicler::merkle_proof(&tales, "never_told").is_none());
+    }
+
+    /// A pending story of fixed shape, so every such story has the same size.
+    fn pending_tale(story_fee: u64) -> TransactionStory {
+        TransactionStory {
+            version: CURRENT_STORY_VERSION,
+            story_id: generate_transaction_id(),
+            inputs_consumed: vec![UTXOReference {
+                previous_story_id: generate_transaction_id(),
+                output_index: 0,
+            }],
+            outputs_created: vec![],
+            story_fee,
+            timestamp_of_telling: current_timestamp(),
+            transaction_nonce: 1,
+            digital_signature: vec![0; 64],
+            public_key_of_narrator: vec![0; 32],
+            fee_sponsorship: None,
+            input_witnesses: Vec::new(),
+            coinbase_height: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn generous_stories_confirm_sooner_than_frugal_ones() {
+        let mut chronicle = quiet_chronicle("confirmation_estimate").await;
+        let tale_size = story_size_bytes(&pending_tale(0));
+        chronicle.configuration.max_block_size = (tale_size * 2) as usize;
+
+        {
+            let mut mempool = chronicle.mempool_of_pending_tales.lock().unwrap();
+            for _ in 0..6 {
+                mempool.push(pending_tale(tale_size * 10));
+            }
+        }
+
+        assert_eq!(chronicle.estimate_confirmation_blocks(20_000).await, 1);
+        assert_eq!(chronicle.estimate_confirmation_blocks(1_000).await, 4);
+    }
+
+    #[tokio::test]
+    async fn the_estimate_packs_chapters_just_as_the_miner_does() {
+        let mut chronicle = quiet_chronicle("estimate_as_mined").await;
+        let tale_size = story_size_bytes(&pending_tale(0));
+        chronicle.configuration.max_block_size = (tale_size * 2) as usize;
+        let frugal = pending_tale(tale_size);
+        {
+            let mut mempool = chronicle.mempool_of_pending_tales.lock().unwrap();
+            mempool.push(frugal.clone());
+            for _ in 0..3 {
+                mempool.push(pending_tale(tale_size * 10));
+            }
+        }
+
+        // Paying five per byte waits only behind the three generous stories
+        assert_eq!(chronicle.estimate_confirmation_blocks(5_000).await, 2);
+        // Once the operator vouches for the frugal story, it goes ahead as well
+        chronicle.priority_boosts.lock().unwrap().insert(frugal.story_id.clone(), 1);
+        assert_eq!(chronicle.estimate_confirmation_blocks(5_000).await, 3);
+
+        // The miner packs them in that very order, two to a chapter
+        let boosts = chronicle.priority_boosts.lock().unwrap().clone();
+        let mut pool = chronicle.mempool_of_pending_tales.lock().unwrap().clone();
+        let first_chapter = BlockchainChronicler::select_tales_for_chapter(
+            &mut pool, &boosts, current_timestamp(), chronicle.configuration.max_block_size,
+        );
+        assert_eq!(first_chapter.len(), 2);
+        assert_eq!(first_chapter[0].story_id, frugal.story_id);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn the_audit_trail_stays_within_its_bounds() {
+        let mut chronicle = quiet_chronicle("mempool_audit_bounds").await;
+        chronicle.configuration.max_mempool_audit_entries = 2;
+
+        for tale in ["first", "second", "third"] {
+            chronicle.record_mempool_verdict(tale, MempoolVerdict::Admitted);
+        }
+
+        let audit = chronicle.mempool_audit().await;
+        assert_eq!(audit.len(), 2);
+        assert_eq!(audit[0].story_id, "second");
+        assert_eq!(audit[1].story_id, "third");
+    }
 }
 
 /// ## Cargo.toml Dependencies
@@ -1213,6 +8238,8 @@ fn generate_nonce() -> u64 {
 ///     println!("Alice balance: {}", alice_balance);
 ///     println!("Bob balance: {}", bob_balance);
 ///     
+///     // Chapter 6: Hear the peers for as long as the node runs
+///     blockchain.keep_heeding_peers().await;
 ///     Ok(())
 /// }
 /// ```
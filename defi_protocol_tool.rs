@@ -7,6 +7,9 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use async_trait::async_trait;
+use sha3::{Digest, Keccak256};
+
 // =============================================================================
 // Act I: Origins - Where Assets Discover Their Purpose
 // =============================================================================
@@ -47,17 +50,89 @@ pub mod asset_awakens {
             let fractional_power = self.current_power % divisor;
             format!("{}.{:0width$}", whole_power, fractional_power, width = self.precision as usize)
         }
+
+        /// The same power, dressed for a user interface. Excess decimals are
+        /// truncated rather than rounded, so the same balance always reads the same.
+        pub fn power_level_becomes_presentable(&self, options: &FormatOptions) -> String {
+            let divisor = 10_u128.pow(self.precision as u32);
+            let whole_power = self.current_power / divisor;
+            let fractional_power = self.current_power % divisor;
+
+            let mut fractional_digits = format!("{:0width$}", fractional_power, width = self.precision as usize);
+            if let Some(max_decimals) = options.max_decimals {
+                fractional_digits.truncate(max_decimals as usize);
+            }
+            if options.trim_trailing_zeros {
+                fractional_digits.truncate(fractional_digits.trim_end_matches('0').len());
+            }
+
+            let mut presentable = group_whole_digits(whole_power, options.thousands_separator);
+            if !fractional_digits.is_empty() {
+                presentable.push('.');
+                presentable.push_str(&fractional_digits);
+            }
+            if options.show_symbol {
+                presentable.push(' ');
+                presentable.push_str(&self.essence);
+            }
+            presentable
+        }
+    }
+
+    /// ## How an Asset Presents Itself to the World
+    /// 
+    /// The default shows every decimal, ungrouped and unlabelled;
+    /// only `max_decimals` can lose precision.
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct FormatOptions {
+        pub thousands_separator: Option<char>,
+        pub max_decimals: Option<u8>,
+        pub trim_trailing_zeros: bool,
+        pub show_symbol: bool,
+    }
+
+    impl FormatOptions {
+        /// Every decimal, grouped and labelled, nothing lost
+        pub fn lossless() -> Self {
+            Self {
+                thousands_separator: Some(','),
+                max_decimals: None,
+                trim_trailing_zeros: false,
+                show_symbol: true,
+            }
+        }
+    }
+
+    fn group_whole_digits(whole_power: u128, separator: Option<char>) -> String {
+        let digits = whole_power.to_string();
+        let Some(separator) = separator else {
+            return digits;
+        };
+
+        digits.as_bytes()
+            .rchunks(3)
+            .rev()
+            .map(|group| String::from_utf8_lossy(group).into_owned())
+            .collect::<Vec<_>>()
+            .join(&separator.to_string())
     }
 
     /// ## Chapter 2: The Wallet Guardian Awakens
     /// 
     /// Every hero needs a guardian. The wallet protects assets
     /// and remembers their journeys through the DeFi landscape.
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     pub struct WalletGuardian {
         pub mystical_address: String,
         pub protected_assets: HashMap<String, DigitalAsset>,
         pub legend_book: Vec<super::quest_unfolds::AssetQuest>,
+        pub allowances: HashMap<(super::quest_unfolds::ProtocolSpirit, String), u128>,
+        /// When each expiring allowance lapses, in seconds since the epoch;
+        /// allowances absent here stand until revoked
+        pub allowance_expiries: HashMap<(super::quest_unfolds::ProtocolSpirit, String), u64>,
+        /// How many quests the legend book has recorded, and so the
+        /// sequence the last of them was given
+        pub quests_recorded: u64,
     }
 
     impl WalletGuardian {
@@ -70,6 +145,9 @@ pub mod asset_awakens {
                 mystical_address,
                 protected_assets: HashMap::new(),
                 legend_book: Vec::new(),
+                allowances: HashMap::new(),
+                allowance_expiries: HashMap::new(),
+                quests_recorded: 0,
             })
         }
 
@@ -91,13 +169,157 @@ pub mod asset_awakens {
             }
         }
 
+        /// How much of an asset the guardian has permitted a spirit to draw
+        pub fn allowance_for(&self, spirit: &super::quest_unfolds::ProtocolSpirit, essence: &str) -> u128 {
+            self.allowances.get(&(spirit.clone(), essence.to_string())).copied().unwrap_or(0)
+        }
+
+        /// Sets a spirit's allowance outright, keeping any expiry it already
+        /// had; a zero allowance is forgotten entirely
+        pub fn guardian_grants_allowance(&mut self, spirit: super::quest_unfolds::ProtocolSpirit, essence: &str, power: u128) {
+            let key = (spirit, essence.to_string());
+            if power == 0 {
+                self.allowances.remove(&key);
+                self.allowance_expiries.remove(&key);
+            } else {
+                self.allowances.insert(key, power);
+            }
+        }
+
+        /// Sets a spirit's allowance, to lapse once `expires_at` arrives
+        pub fn guardian_grants_allowance_until(
+            &mut self,
+            spirit: super::quest_unfolds::ProtocolSpirit,
+            essence: &str,
+            power: u128,
+            expires_at: u64
+        ) {
+            self.guardian_grants_allowance(spirit.clone(), essence, power);
+            if power > 0 {
+                self.allowance_expiries.insert((spirit, essence.to_string()), expires_at);
+            }
+        }
+
+        /// Whether a spirit's allowance covers what the ritual would draw from
+        /// the guardian right now; rituals drawing nothing need no allowance
+        /// at all
+        pub fn allowance_permits_ritual(
+            &self,
+            spirit: &super::quest_unfolds::ProtocolSpirit,
+            ritual: &super::quest_unfolds::SacredRitual
+        ) -> Result<(), PlotTwist> {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            self.allowance_permits_ritual_at(spirit, ritual, now)
+        }
+
+        /// Whether a spirit's allowance covers what the ritual would draw at
+        /// `now`, in seconds since the epoch. An allowance lapses once its
+        /// expiry arrives, as `sweep_expired` judges it, whether or not it
+        /// has been swept yet.
+        pub fn allowance_permits_ritual_at(
+            &self,
+            spirit: &super::quest_unfolds::ProtocolSpirit,
+            ritual: &super::quest_unfolds::SacredRitual,
+            now: u64
+        ) -> Result<(), PlotTwist> {
+            let Some((essence, power)) = ritual.ritual_draws_upon() else {
+                return Ok(());
+            };
+            if let Some(expires_at) = self.allowance_expiries.get(&(spirit.clone(), essence.to_string())) {
+                if *expires_at <= now {
+                    return Err(PlotTwist::AllowanceWithheld(format!(
+                        "{}'s allowance of {} lapsed at {}", spirit, essence, expires_at
+                    )));
+                }
+            }
+            let allowance = self.allowance_for(spirit, essence);
+            if allowance < power {
+                return Err(PlotTwist::AllowanceWithheld(format!(
+                    "{} may draw {} {} but the ritual needs {}", spirit, allowance, essence, power
+                )));
+            }
+            Ok(())
+        }
+
+        /// Takes what the ritual drew out of the spirit's allowance, once
+        /// `allowance_permits_ritual` has found it covered
+        pub fn allowance_is_consumed(
+            &mut self,
+            spirit: &super::quest_unfolds::ProtocolSpirit,
+            ritual: &super::quest_unfolds::SacredRitual
+        ) {
+            if let Some((essence, power)) = ritual.ritual_draws_upon() {
+                let remaining = self.allowance_for(spirit, essence).saturating_sub(power);
+                self.guardian_grants_allowance(spirit.clone(), essence, remaining);
+            }
+        }
+
+        /// Writes a completed quest into the legend book under the next
+        /// sequence, counting from 1, and hands back the quest as recorded
+        pub fn guardian_records_quest(&mut self, quest: super::quest_unfolds::AssetQuest) -> super::quest_unfolds::AssetQuest {
+            self.quests_recorded += 1;
+            let recorded = super::quest_unfolds::AssetQuest { sequence: self.quests_recorded, ..quest };
+            self.legend_book.push(recorded.clone());
+            recorded
+        }
+
+        /// ## Turning the Pages of the Legend Book
+        /// 
+        /// Up to `page_size` quests recorded after `after_sequence`, oldest
+        /// first. Starting from 0 and then from the last sequence of each
+        /// page walks the whole book without a quest appearing twice.
+        pub fn legend_page(&self, after_sequence: u64, page_size: usize) -> &[super::quest_unfolds::AssetQuest] {
+            let start = self.legend_book.partition_point(|quest| quest.sequence <= after_sequence);
+            let end = start.saturating_add(page_size).min(self.legend_book.len());
+            &self.legend_book[start..end]
+        }
+
+        /// The raw power of every asset summed, whatever its decimals; see
+        /// `total_value_usd` for what the assets are actually worth
         pub fn guardian_reveals_total_dominion(&self) -> u128 {
             self.protected_assets.values().map(|asset| asset.current_power).sum()
         }
+
+        /// ## What the Whole Dominion Is Worth
+        /// 
+        /// Every asset's power brought down to whole units by its precision
+        /// and priced by the oracle, summed in dollars. An asset the oracle
+        /// cannot price adds nothing.
+        pub fn total_value_usd(&self, oracle: &dyn super::quest_unfolds::PriceOracle) -> f64 {
+            self.protected_assets.values()
+                .filter_map(|asset| {
+                    let price = oracle.price_usd(&asset.essence)?;
+                    Some(asset.current_power as f64 / 10f64.powi(asset.precision as i32) * price)
+                })
+                .sum()
+        }
     }
 
+    /// A worthy address is `0x` and forty hex digits. One written in mixed
+    /// case must also carry its EIP-55 checksum: each letter is capitalised
+    /// exactly when the matching nibble of the Keccak-256 hash of the
+    /// lowercase hex is 8 or more. All-lowercase and all-uppercase addresses
+    /// carry no checksum and pass on their digits alone.
     fn address_proves_its_worthiness(address: &str) -> bool {
-        address.len() == 42 && address.starts_with("0x")
+        let Some(digits) = address.strip_prefix("0x") else {
+            return false;
+        };
+        if digits.len() != 40 || !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+            return false;
+        }
+        let has_lower = digits.chars().any(|digit| digit.is_ascii_lowercase());
+        let has_upper = digits.chars().any(|digit| digit.is_ascii_uppercase());
+        if !(has_lower && has_upper) {
+            return true;
+        }
+
+        let checksum = Keccak256::digest(digits.to_ascii_lowercase().as_bytes());
+        digits.chars().enumerate().all(|(index, digit)| {
+            let nibble = (checksum[index / 2] >> if index % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            !digit.is_ascii_alphabetic() || digit.is_ascii_uppercase() == (nibble >= 8)
+        })
     }
 }
 
@@ -112,7 +334,7 @@ pub mod quest_unfolds {
     /// 
     /// Ancient spirits govern the DeFi realm, each with unique powers
     /// and rituals for transforming assets.
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
     pub enum ProtocolSpirit {
         UniswapTheExchanger,
         AaveTheGiver,
@@ -142,7 +364,10 @@ pub mod quest_unfolds {
         AssetTransmutation { 
             offering: super::asset_awakens::DigitalAsset, 
             desired_form: super::asset_awakens::DigitalAsset, 
-            power_amount: u128 
+            power_amount: u128,
+            /// The least of the desired form the guardian will accept; the
+            /// spirit refuses a swap whose output falls below it
+            min_received: u128 
         },
         PowerOffering { 
             asset: super::asset_awakens::DigitalAsset, 
@@ -162,6 +387,303 @@ pub mod quest_unfolds {
         },
     }
 
+    impl SacredRitual {
+        /// The asset and power a spirit must be allowed to draw from the
+        /// guardian for this ritual; borrowing and reclaiming draw nothing.
+        pub fn ritual_draws_upon(&self) -> Option<(&str, u128)> {
+            match self {
+                SacredRitual::AssetTransmutation { offering, power_amount, .. } => Some((&offering.essence, *power_amount)),
+                SacredRitual::PowerOffering { asset, power_amount } |
+                SacredRitual::DebtSettlement { asset, power_amount } => Some((&asset.essence, *power_amount)),
+                SacredRitual::PowerBorrowing { .. } | SacredRitual::PowerReclamation { .. } => None,
+            }
+        }
+
+        /// The asset the ritual moves and how much of it: the offering for a
+        /// transmutation, the named asset for every other ritual.
+        pub fn ritual_moves(&self) -> (&super::asset_awakens::DigitalAsset, u128) {
+            match self {
+                SacredRitual::AssetTransmutation { offering, power_amount, .. } => (offering, *power_amount),
+                SacredRitual::PowerOffering { asset, power_amount } |
+                SacredRitual::PowerBorrowing { asset, power_amount } |
+                SacredRitual::DebtSettlement { asset, power_amount } |
+                SacredRitual::PowerReclamation { asset, power_amount } => (asset, *power_amount),
+            }
+        }
+    }
+
+    /// ## Chapter 4¼: The Oracle Who Knows What Things Are Worth
+    /// 
+    /// The price of one whole unit of an asset in the quote asset, US dollars.
+    pub trait PriceOracle: fmt::Debug + Send + Sync {
+        fn price_usd(&self, essence: &str) -> Option<f64>;
+    }
+
+    /// An oracle reading from a fixed table of prices
+    #[derive(Debug, Clone, Default)]
+    pub struct StaticPriceOracle {
+        pub prices: HashMap<String, f64>,
+    }
+
+    impl StaticPriceOracle {
+        pub fn from_prices(prices: HashMap<String, f64>) -> Self {
+            Self { prices }
+        }
+    }
+
+    impl PriceOracle for StaticPriceOracle {
+        fn price_usd(&self, essence: &str) -> Option<f64> {
+            self.prices.get(essence).copied()
+        }
+    }
+
+    /// The decimals an oracle price carries: a price of one dollar is 10^8
+    pub const ORACLE_PRICE_DECIMALS: u32 = 8;
+
+    /// Every price an oracle knew at one moment, as of the batch that set them
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct OracleObservation {
+        pub prices: HashMap<String, u128>,
+        pub updated_at: u64,
+    }
+
+    impl OracleObservation {
+        /// What the assets are worth in dollars, each priced from this one
+        /// observation; `None` if any of them has no price
+        pub fn valuation_usd<'a>(&self, assets: impl IntoIterator<Item = &'a super::asset_awakens::DigitalAsset>) -> Option<f64> {
+            assets.into_iter()
+                .map(|asset| Some(asset.current_power as f64 / 10f64.powi(asset.precision as i32) * self.price_usd(&asset.essence)?))
+                .sum()
+        }
+    }
+
+    impl PriceOracle for OracleObservation {
+        fn price_usd(&self, essence: &str) -> Option<f64> {
+            self.prices.get(essence).map(|&price| price as f64 / 10f64.powi(ORACLE_PRICE_DECIMALS as i32))
+        }
+    }
+
+    /// ## Chapter 4⅓: An Oracle the Tale Can Move
+    /// 
+    /// A mock oracle whose prices, scaled by `ORACLE_PRICE_DECIMALS`, are
+    /// set as the tale goes on. A batch of prices lands all at once under
+    /// one timestamp, so a valuation read through the oracle prices every
+    /// asset from before the batch or every asset from after it, never a mix.
+    #[derive(Debug, Default)]
+    pub struct MockPriceOracle {
+        observation: std::sync::RwLock<OracleObservation>,
+    }
+
+    impl MockPriceOracle {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn update_price(&self, essence: &str, price: u128, timestamp: u64) {
+            self.update_batch(HashMap::from([(essence.to_string(), price)]), timestamp);
+        }
+
+        /// Sets every price in the batch and stamps them all with `timestamp`;
+        /// assets the batch leaves out keep their price
+        pub fn update_batch(&self, prices: HashMap<String, u128>, timestamp: u64) {
+            let mut observation = self.observation.write().unwrap();
+            observation.prices.extend(prices);
+            observation.updated_at = timestamp;
+        }
+
+        /// The oracle as it stands, whole and consistent
+        pub fn observe(&self) -> OracleObservation {
+            self.observation.read().unwrap().clone()
+        }
+
+        /// What the assets are worth in dollars, with no batch landing midway
+        pub fn valuation_usd<'a>(&self, assets: impl IntoIterator<Item = &'a super::asset_awakens::DigitalAsset>) -> Option<f64> {
+            self.observation.read().unwrap().valuation_usd(assets)
+        }
+    }
+
+    impl PriceOracle for MockPriceOracle {
+        fn price_usd(&self, essence: &str) -> Option<f64> {
+            self.observation.read().unwrap().price_usd(essence)
+        }
+    }
+
+    /// ## The Threshold Below Which No Ritual Is Worth Its Gas
+    /// 
+    /// A spirit holding a trade floor refuses rituals moving less than
+    /// `min_trade_value` dollars, as valued by its oracle.
+    #[derive(Debug, Clone)]
+    pub struct TradeFloor {
+        pub min_trade_value: f64,
+        pub oracle: std::sync::Arc<dyn PriceOracle>,
+    }
+
+    impl TradeFloor {
+        /// Refuses a ritual worth less than the floor. A ritual the oracle
+        /// cannot price is let through rather than guessed at.
+        pub fn ritual_clears_the_floor(&self, ritual: &SacredRitual) -> Result<(), PlotTwist> {
+            let (asset, power) = ritual.ritual_moves();
+            let Some(price) = self.oracle.price_usd(&asset.essence) else {
+                return Ok(());
+            };
+            let trade_value = power as f64 / 10f64.powi(asset.precision as i32) * price;
+            if trade_value < self.min_trade_value {
+                return Err(PlotTwist::PowerInsufficient);
+            }
+            Ok(())
+        }
+    }
+
+    /// ## The Oracle's Watch Over a Pool
+    /// 
+    /// A spirit holding a price guard refuses a transmutation whose effective
+    /// rate strays from the oracle's by more than `max_deviation`, a fraction
+    /// (0.02 is two percent). A pool that far off is more likely manipulated
+    /// than merely slippery.
+    #[derive(Debug, Clone)]
+    pub struct PriceGuard {
+        pub max_deviation: f64,
+        pub oracle: std::sync::Arc<dyn PriceOracle>,
+    }
+
+    impl PriceGuard {
+        /// Compares the whole units received per whole unit offered with the
+        /// rate the oracle's two prices imply. A pair the oracle cannot price
+        /// is let through rather than guessed at.
+        pub fn transmutation_keeps_faith(
+            &self,
+            offering: &super::asset_awakens::DigitalAsset,
+            offering_power: u128,
+            desired_form: &super::asset_awakens::DigitalAsset,
+            received_power: u128
+        ) -> Result<(), PlotTwist> {
+            let (Some(offering_price), Some(desired_price)) = (
+                self.oracle.price_usd(&offering.essence),
+                self.oracle.price_usd(&desired_form.essence),
+            ) else {
+                return Ok(());
+            };
+            if offering_power == 0 || desired_price <= 0.0 {
+                return Ok(());
+            }
+
+            let offered = offering_power as f64 / 10f64.powi(offering.precision as i32);
+            let received = received_power as f64 / 10f64.powi(desired_form.precision as i32);
+            let oracle_rate = offering_price / desired_price;
+            let deviation = (received / offered / oracle_rate - 1.0).abs();
+            if deviation > self.max_deviation {
+                return Err(PlotTwist::PriceDeviationTooHigh(format!(
+                    "{} -> {} trades {:.2}% from the oracle, beyond the {:.2}% tolerated",
+                    offering.essence, desired_form.essence, deviation * 100.0, self.max_deviation * 100.0
+                )));
+            }
+            Ok(())
+        }
+    }
+
+    /// ## Chapter 4½: A Ritual Held for Later
+    /// 
+    /// A ritual the hero has asked a spirit to perform when its moment
+    /// comes. Every order carries the time, in seconds since the epoch,
+    /// after which it is no longer wanted, and the priority fee per unit of
+    /// energy it bids for inclusion when its moment comes alongside others.
+    #[derive(Debug, Clone)]
+    pub struct ConditionalOrder {
+        pub order_id: u64,
+        pub protocol_spirit: ProtocolSpirit,
+        pub sacred_ritual: SacredRitual,
+        pub expires_at: u64,
+        pub trigger: OrderTrigger,
+        pub priority_fee: u64,
+    }
+
+    /// When a held ritual's moment has come, judged by the oracle's prices
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum OrderTrigger {
+        /// Fires at the first poll
+        AtOnce,
+        PriceAtOrBelow { essence: String, price_usd: f64 },
+        PriceAtOrAbove { essence: String, price_usd: f64 },
+    }
+
+    impl OrderTrigger {
+        /// A price trigger the oracle cannot price does not fire
+        pub fn trigger_has_fired(&self, oracle: &dyn PriceOracle) -> bool {
+            match self {
+                OrderTrigger::AtOnce => true,
+                OrderTrigger::PriceAtOrBelow { essence, price_usd } => {
+                    oracle.price_usd(essence).is_some_and(|price| price <= *price_usd)
+                }
+                OrderTrigger::PriceAtOrAbove { essence, price_usd } => {
+                    oracle.price_usd(essence).is_some_and(|price| price >= *price_usd)
+                }
+            }
+        }
+    }
+
+    /// ## Chapter 4¾: The Limits a Route Must Keep
+    /// 
+    /// A route through several pools may buy a little more than a direct
+    /// one but burn far more gas getting there. A route of more than
+    /// `max_hops` swaps, or whose swaps together cost more than
+    /// `max_total_gas`, is pruned. Among those left, any whose output comes
+    /// within `comparable_output_bps` of the best is as good as the best,
+    /// and the one taking the fewest hops is chosen.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RoutingLimits {
+        pub max_hops: usize,
+        pub max_total_gas: u64,
+        pub comparable_output_bps: u32,
+    }
+
+    impl Default for RoutingLimits {
+        fn default() -> Self {
+            Self {
+                max_hops: 3,
+                max_total_gas: 1_000_000,
+                comparable_output_bps: 10, // 0.1%
+            }
+        }
+    }
+
+    /// A way from one asset to another: every essence it passes through,
+    /// what it would deliver, and the energy its swaps would cost together
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CandidateRoute {
+        pub path: Vec<String>,
+        pub output: u128,
+        pub total_gas: u64,
+    }
+
+    impl CandidateRoute {
+        /// How many swaps the route takes
+        pub fn hops(&self) -> usize {
+            self.path.len().saturating_sub(1)
+        }
+    }
+
+    impl RoutingLimits {
+        pub fn route_keeps_the_limits(&self, route: &CandidateRoute) -> bool {
+            route.hops() <= self.max_hops && route.total_gas <= self.max_total_gas
+        }
+
+        /// The route to take among the candidates, or `None` if every one of
+        /// them breaks a limit
+        pub fn route_chosen_among(&self, candidates: impl IntoIterator<Item = CandidateRoute>) -> Option<CandidateRoute> {
+            let permitted: Vec<CandidateRoute> = candidates.into_iter()
+                .filter(|route| self.route_keeps_the_limits(route))
+                .collect();
+            let best_output = permitted.iter().map(|route| route.output).max()?;
+            let shortfall_allowed = best_output / 10_000 * self.comparable_output_bps as u128
+                + best_output % 10_000 * self.comparable_output_bps as u128 / 10_000;
+            let comparable_output = best_output - shortfall_allowed.min(best_output);
+
+            permitted.into_iter()
+                .filter(|route| route.output >= comparable_output)
+                .min_by(|a, b| a.hops().cmp(&b.hops()).then(b.output.cmp(&a.output)))
+        }
+    }
+
     /// ## Chapter 5: The Quest Chronicle
     /// 
     /// Every interaction with the protocol spirits becomes legend,
@@ -169,6 +691,9 @@ pub mod quest_unfolds {
     #[derive(Debug, Clone)]
     pub struct AssetQuest {
         pub quest_id: String,
+        /// Where the quest stands in the legend book; strictly increasing,
+        /// assigned as the quest is recorded
+        pub sequence: u64,
         pub protocol_spirit: ProtocolSpirit,
         pub sacred_ritual: SacredRitual,
         pub energy_limit: u64,    // gas limit
@@ -183,18 +708,93 @@ pub mod quest_unfolds {
         TragicEnding(String),
     }
 
+    /// ## Chapter 5½: The Shadows Watching the Mempool
+    /// 
+    /// Before a ritual is performed in public, the wise guardian asks how
+    /// tempting it looks to the sandwich bots lurking in the mempool.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum SandwichRisk {
+        Low,
+        Elevated,
+        High,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct MevRiskReport {
+        pub sandwich_risk: SandwichRisk,
+        pub price_impact_bps: u128,
+        pub extractable_power: u128,
+        pub warning: Option<String>,
+    }
+
     /// ## Chapter 6: The Protocol Communion Trait
     /// 
     /// All protocol spirits must follow the ancient covenant,
-    /// providing standardized ways to commune with assets.
-    pub trait ProtocolCommunion {
+    /// providing standardized ways to commune with assets. Rituals are
+    /// awaited, so a spirit backed by a real chain can wait on the network;
+    /// the in-memory spirits here simply answer at once.
+    #[async_trait]
+    pub trait ProtocolCommunion: Send + Sync {
         fn spirit_reveals_identity(&self) -> ProtocolSpirit;
+        /// Smart-contract risk, from 0 (battle-tested) to 100 (unaudited and untested)
+        fn risk_rating(&self) -> u8;
         fn spirit_calculates_energy_cost(&self, ritual: &SacredRitual) -> Result<u64, PlotTwist>;
-        fn spirit_performs_sacred_ritual(
-            &self, 
+        async fn spirit_performs_sacred_ritual(
+            &mut self, 
             guardian: &mut super::asset_awakens::WalletGuardian, 
             ritual: SacredRitual
         ) -> Result<AssetQuest, PlotTwist>;
+
+        /// The pool reserves (offering side, desired side) this spirit would
+        /// trade against, in the common precision, for spirits that hold
+        /// liquidity pools.
+        fn spirit_reveals_reserves(
+            &self,
+            _offering: &asset_awakens::DigitalAsset,
+            _desired_form: &asset_awakens::DigitalAsset
+        ) -> Option<(u128, u128)> {
+            None
+        }
+
+        /// The pairs this spirit holds a registered pool for, each as the
+        /// two essences it joins.
+        fn spirit_reveals_pools(&self) -> Vec<(String, String)> {
+            Vec::new()
+        }
+
+        /// What swapping `amount` of one asset into another would yield,
+        /// both in the common precision, for spirits that keep pools;
+        /// nothing is traded.
+        fn spirit_quotes_exchange(
+            &self,
+            _from: &asset_awakens::DigitalAsset,
+            _to: &asset_awakens::DigitalAsset,
+            _amount: u128
+        ) -> Option<u128> {
+            None
+        }
+
+        /// The power this spirit holds on the guardian's behalf, per asset:
+        /// supplies are positive, borrows are negative.
+        fn spirit_reveals_exposure(&self) -> HashMap<String, i128> {
+            HashMap::new()
+        }
+
+        /// How safely the guardian's borrows are collateralized with this
+        /// spirit, for spirits that lend; below 1.0 the position is underwater.
+        fn spirit_reveals_health_factor(&self, _guardian: &asset_awakens::WalletGuardian) -> Option<f64> {
+            None
+        }
+
+        /// Lets `blocks` simulated blocks pass, for spirits whose positions
+        /// grow with time; the rest are left as they were.
+        fn spirit_feels_blocks_pass(&mut self, _blocks: u64) -> Result<(), PlotTwist> {
+            Ok(())
+        }
+
+        /// A faithful copy of the spirit and everything it holds, so rituals
+        /// can be rehearsed without disturbing the real one.
+        fn spirit_casts_reflection(&self) -> Box<dyn ProtocolCommunion>;
     }
 }
 
@@ -217,6 +817,9 @@ pub mod destiny_fulfilled {
         NetworkGossipsFail(String),
         AssetVanishedIntoVoid(String),
         RitualForbiddenBySpirit(String),
+        PowerOverflowsTheVessel(String),
+        PriceDeviationTooHigh(String),
+        AllowanceWithheld(String),
     }
 
     impl fmt::Display for PlotTwist {
@@ -228,6 +831,9 @@ pub mod destiny_fulfilled {
                 PlotTwist::NetworkGossipsFail(msg) => write!(f, "The ethereal networks whisper of failures: {}", msg),
                 PlotTwist::AssetVanishedIntoVoid(asset) => write!(f, "Asset {} mysteriously vanished into the void", asset),
                 PlotTwist::RitualForbiddenBySpirit(msg) => write!(f, "The spirit forbids this ritual: {}", msg),
+                PlotTwist::PowerOverflowsTheVessel(msg) => write!(f, "The power overflowed its vessel: {}", msg),
+                PlotTwist::PriceDeviationTooHigh(msg) => write!(f, "The pool strays too far from the oracle: {}", msg),
+                PlotTwist::AllowanceWithheld(msg) => write!(f, "The guardian has not allowed this draw: {}", msg),
             }
         }
     }
@@ -236,6 +842,72 @@ pub mod destiny_fulfilled {
 
     pub type StoryResult<T> = Result<T, PlotTwist>;
 
+    /// ## Chapter 7½: A Destiny Rehearsed
+    /// 
+    /// Where a whole strategy would leave the guardian, had it been performed:
+    /// the balances, the positions held with spirits, and the first step
+    /// that would have gone wrong.
+    #[derive(Debug, Clone)]
+    pub struct StrategySimResult {
+        pub final_balances: HashMap<String, u128>,
+        pub spirit_positions: HashMap<String, i128>,
+        pub total_borrowed: u128,
+        pub health_factor: Option<f64>,
+        pub failing_step: Option<(usize, PlotTwist)>,
+    }
+
+    /// ## Chapter 7⅗: The Tale Remembers Everything It Did
+    /// 
+    /// Every change the orchestrator makes to its guardian, its spirits and
+    /// its order book, in the order made. Replayed from the beginning onto a
+    /// fresh guardian and the spirits as they first stood, the events rebuild
+    /// the tale exactly as it stood after the last of them.
+    #[derive(Debug, Clone)]
+    pub enum OrchestratorEvent {
+        AssetDiscovered { essence: String, soul_address: String, precision: u8, initial_power: u128 },
+        /// A ritual that succeeded; rituals that failed changed nothing
+        RitualPerformed { spirit_id: super::quest_unfolds::ProtocolSpirit, ritual: super::quest_unfolds::SacredRitual },
+        BlocksPassed(u64),
+        AllowanceApproved { spirit_id: super::quest_unfolds::ProtocolSpirit, essence: String, power: u128, expires_at: u64 },
+        RiskToleranceSet(Option<u8>),
+        /// A spirit took its place in the tale, replacing any of its kind
+        SpiritJoined(super::quest_unfolds::ProtocolSpirit),
+        OrderPlaced(super::quest_unfolds::ConditionalOrder),
+        /// A triggered order left the book and performed; its ritual, if it
+        /// succeeded, was logged just before
+        AuctionWon(AuctionWin),
+        ExpiredSwept { now: u64 },
+    }
+
+    /// An event as the log holds it, under the sequence it was given,
+    /// counting from 1
+    #[derive(Debug, Clone)]
+    pub struct LoggedEvent {
+        pub sequence: u64,
+        pub event: OrchestratorEvent,
+    }
+
+    /// ## Chapter 7¾: Loose Ends Tied
+    /// 
+    /// What a sweep found lingering past its time: the allowances it
+    /// revoked and the conditional orders it cancelled.
+    #[derive(Debug, Clone, Default)]
+    pub struct SweepReport {
+        pub revoked_approvals: Vec<(super::quest_unfolds::ProtocolSpirit, String)>,
+        pub cancelled_orders: Vec<super::quest_unfolds::ConditionalOrder>,
+    }
+
+    /// ## Chapter 7⅞: The Auction for a Place in the Block
+    /// 
+    /// A triggered order that won its turn: what it bid in total (its
+    /// priority fee times the energy its ritual burns) and how the ritual went.
+    #[derive(Debug, Clone)]
+    pub struct AuctionWin {
+        pub order_id: u64,
+        pub effective_bid: u128,
+        pub outcome: StoryResult<super::quest_unfolds::AssetQuest>,
+    }
+
     /// ## Chapter 8: The Resolution Chronicles
     /// 
     /// When plot twists occur, wise heroes know how to navigate
@@ -270,54 +942,397 @@ pub mod supporting_cast {
     /// 
     /// This ancient spirit specializes in the mystical art of transmutation,
     /// converting one asset form into another through sacred mathematical rituals.
+    #[derive(Debug, Clone)]
     pub struct UniswapExchangerSpirit {
         pub sanctum_address: String,
         pub transmutation_fee: u32,
+        pub risk_rating: u8,
+        /// The invariant each pair's pool follows, keyed by the pair's essences
+        /// in alphabetical order; pairs not listed follow the constant product
+        pub pool_curves: HashMap<(String, String), CurveType>,
+        /// Each pair's reserves in the common precision, keyed like
+        /// `pool_curves` and held in the same order; pairs not listed hold
+        /// `MOCK_POOL_RESERVES` of each asset's smallest units until first traded
+        pub pool_reserves: HashMap<(String, String), (u128, u128)>,
+        /// Transmutations worth less than this are refused; `None` accepts any size
+        pub trade_floor: Option<quest_unfolds::TradeFloor>,
+        /// Transmutations priced too far from the oracle are refused; `None`
+        /// trusts the pool whatever it quotes
+        pub price_guard: Option<quest_unfolds::PriceGuard>,
     }
 
+    /// ## The Shape of a Pool's Invariant
+    /// 
+    /// Volatile pairs trade along the constant product `x * y = k`; pairs
+    /// meant to hold a peg trade along the StableSwap invariant, which stays
+    /// nearly flat around balance - the higher the amplification, the flatter.
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub enum CurveType {
+        #[default]
+        ConstantProduct,
+        StableSwap { amplification: u128 },
+    }
+
+    /// How many Newton steps the StableSwap invariant may take to settle
+    const STABLESWAP_MAX_ITERATIONS: usize = 255;
+
+    /// The common precision every pool keeps its reserves in, whatever the
+    /// decimals of the assets it holds
+    pub const COMMON_PRECISION: u8 = 18;
+
+    /// The depth every pool the Uniswap spirit trades against starts with, in
+    /// each asset's own smallest units, so an untraded pool swaps unit for unit
+    pub const MOCK_POOL_RESERVES: u128 = 1_000_000_000;
+
+    /// The share of supplied power a lending spirit counts towards collateral
+    pub const LIQUIDATION_THRESHOLD: f64 = 0.8;
+
+    /// The discount a liquidator buys an underwater guardian's collateral
+    /// at: it receives this much more value than the debt it settles
+    pub const LIQUIDATION_BONUS: f64 = 0.05;
+
+    /// A supply index of exactly one: a share is then worth one unit of power
+    pub const SUPPLY_INDEX_ONE: u128 = 1_000_000_000_000;
+
+    /// Blocks in a year, as Compound counts them
+    pub const BLOCKS_PER_YEAR: u128 = 2_102_400;
+
+    /// Seconds in a year, as Aave counts them when compounding debt
+    pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+    /// Seconds a simulated block lasts, so a year of Compound's blocks is
+    /// a year on Aave's clock too
+    pub const SECONDS_PER_BLOCK: u64 = SECONDS_PER_YEAR / BLOCKS_PER_YEAR as u64;
+
     impl UniswapExchangerSpirit {
         pub fn spirit_manifests_in_realm(sanctum_address: String) -> Self {
             Self {
                 sanctum_address,
                 transmutation_fee: 3000, // 0.3% in basis points
+                risk_rating: 20,
+                pool_curves: HashMap::new(),
+                pool_reserves: HashMap::new(),
+                trade_floor: None,
+                price_guard: None,
+            }
+        }
+
+        pub fn pool_follows_curve(&mut self, first_essence: &str, second_essence: &str, curve: CurveType) {
+            self.pool_curves.insert(pool_key(first_essence, second_essence), curve);
+        }
+
+        pub fn curve_of_pool(&self, first_essence: &str, second_essence: &str) -> CurveType {
+            self.pool_curves.get(&pool_key(first_essence, second_essence)).cloned().unwrap_or_default()
+        }
+
+        /// The pair's reserves as (offering side, desired side), in the common
+        /// precision; an untraded pool holds `MOCK_POOL_RESERVES` of each
+        /// asset's smallest units, raised by the asset's own decimals. Quotes
+        /// and swaps alike read their depth here, so a quote is what trades.
+        pub fn reserves_of_pool(&self, offering: &asset_awakens::DigitalAsset, desired_form: &asset_awakens::DigitalAsset) -> (u128, u128) {
+            let untraded = |asset: &asset_awakens::DigitalAsset| {
+                power_rises_to_common_precision(MOCK_POOL_RESERVES, asset.precision).unwrap_or_default()
+            };
+            self.registered_reserves(&offering.essence, &desired_form.essence)
+                .unwrap_or_else(|| (untraded(offering), untraded(desired_form)))
+        }
+
+        fn registered_reserves(&self, offering_essence: &str, desired_essence: &str) -> Option<(u128, u128)> {
+            let (first, second) = *self.pool_reserves.get(&pool_key(offering_essence, desired_essence))?;
+            Some(if offering_essence <= desired_essence { (first, second) } else { (second, first) })
+        }
+
+        /// Sets the pair's reserves, given as (offering side, desired side)
+        pub fn pool_holds_reserves(&mut self, offering_essence: &str, desired_essence: &str, reserves: (u128, u128)) {
+            let stored = if offering_essence <= desired_essence { reserves } else { (reserves.1, reserves.0) };
+            self.pool_reserves.insert(pool_key(offering_essence, desired_essence), stored);
+        }
+
+        /// ## A Quote Without a Trade
+        /// 
+        /// What swapping `amount` of `from` into `to` would yield against
+        /// the pool as it stands now, both in the common precision; `None`
+        /// if the pool cannot answer. Nothing is traded and the reserves
+        /// stay as they are.
+        pub fn spirit_quotes_price(
+            &self,
+            from: &asset_awakens::DigitalAsset,
+            to: &asset_awakens::DigitalAsset,
+            amount: u128
+        ) -> Option<u128> {
+            self.alchemy_for_pair(&from.essence, &to.essence, amount, self.reserves_of_pool(from, to)).ok()
+        }
+
+        fn alchemy_for_pair(
+            &self,
+            from: &str,
+            to: &str,
+            common_amount: u128,
+            (offering_reserves, desired_reserves): (u128, u128)
+        ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            self.alchemy_along_the_curve(&self.curve_of_pool(from, to), common_amount, offering_reserves, desired_reserves)
+        }
+
+        /// Moves the pair's reserves after a swap: the whole offering joins
+        /// the pool, tribute included, and what the guardian received leaves
+        /// it. Both are raised to the common precision first, so the dust
+        /// the guardian's decimals could not hold remains in the pool.
+        pub fn pool_absorbs_transmutation(
+            &mut self,
+            offering: &asset_awakens::DigitalAsset,
+            offered_power: u128,
+            desired_form: &asset_awakens::DigitalAsset,
+            received_power: u128
+        ) -> Result<(), destiny_fulfilled::PlotTwist> {
+            let (offering_reserves, desired_reserves) = self.reserves_of_pool(offering, desired_form);
+            let new_offering_reserves = power_rises_to_common_precision(offered_power, offering.precision)
+                .and_then(|common_power| offering_reserves.checked_add(common_power))
+                .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(offering.essence.clone()))?;
+            let new_desired_reserves = power_rises_to_common_precision(received_power, desired_form.precision)
+                .and_then(|common_power| desired_reserves.checked_sub(common_power))
+                .ok_or(destiny_fulfilled::PlotTwist::PowerInsufficient)?;
+            self.pool_holds_reserves(&offering.essence, &desired_form.essence, (new_offering_reserves, new_desired_reserves));
+            Ok(())
+        }
+
+        /// The output of a swap along whichever curve the pool follows
+        pub fn alchemy_along_the_curve(
+            &self,
+            curve: &CurveType,
+            offering_amount: u128,
+            offering_reserves: u128,
+            desired_reserves: u128
+        ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            match curve {
+                CurveType::ConstantProduct => {
+                    self.ancient_alchemy_calculates_output(offering_amount, offering_reserves, desired_reserves)
+                }
+                CurveType::StableSwap { amplification } => {
+                    stable_alchemy_calculates_output(*amplification, offering_amount, offering_reserves, desired_reserves)
+                }
             }
         }
 
-        fn ancient_alchemy_calculates_output(
+        /// ## A Quote Across Differing Decimals
+        /// 
+        /// The output of swapping `power_amount` of the offering into the
+        /// desired form, in the desired form's own decimals. The offering is
+        /// raised to the common precision before the curve is walked - exactly,
+        /// for any asset of at most 18 decimals - and only the output is
+        /// rounded, once and down, into the destination's decimals, so the
+        /// dust below its smallest unit stays with the pool.
+        pub fn spirit_quotes_transmutation(
+            &self,
+            offering: &asset_awakens::DigitalAsset,
+            desired_form: &asset_awakens::DigitalAsset,
+            power_amount: u128
+        ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            let common_power = power_rises_to_common_precision(power_amount, offering.precision)
+                .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(offering.essence.clone()))?;
+            let common_output = self.alchemy_for_pair(
+                &offering.essence, &desired_form.essence, common_power, self.reserves_of_pool(offering, desired_form)
+            )?;
+            Ok(power_settles_to_native_precision(common_output, desired_form.precision))
+        }
+
+        /// The sacred AMM formula, refusing to answer rather than let the
+        /// power wrap around when the vessel of `u128` overflows
+        pub fn ancient_alchemy_calculates_output(
             &self, 
             offering_amount: u128, 
             offering_reserves: u128, 
             desired_reserves: u128
-        ) -> u128 {
+        ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            let overflow = || destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(format!(
+                "swapping {} against reserves {}/{}", offering_amount, offering_reserves, desired_reserves
+            ));
+
             // The sacred AMM formula: x * y = k (with fees)
-            let offering_with_tribute = offering_amount * 997; // Fee tribute paid
-            let numerator = offering_with_tribute * desired_reserves;
-            let denominator = (offering_reserves * 1000) + offering_with_tribute;
-            numerator / denominator
+            let offering_with_tribute = offering_amount.checked_mul(997).ok_or_else(overflow)?; // Fee tribute paid
+            let denominator = offering_reserves.checked_mul(1000)
+                .and_then(|scaled_reserves| scaled_reserves.checked_add(offering_with_tribute))
+                .ok_or_else(overflow)?;
+            // Reserves in the common precision are large, so the numerator is kept at full width
+            mul_div(offering_with_tribute, desired_reserves, denominator).ok_or_else(overflow)
         }
     }
 
-    impl quest_unfolds::ProtocolCommunion for UniswapExchangerSpirit {
-        fn spirit_reveals_identity(&self) -> quest_unfolds::ProtocolSpirit {
-            quest_unfolds::ProtocolSpirit::UniswapTheExchanger
+    /// Raises a power from `precision` decimals to the common precision;
+    /// `None` if it no longer fits. An asset finer than the common
+    /// precision loses its excess digits, rounding down.
+    pub fn power_rises_to_common_precision(power: u128, precision: u8) -> Option<u128> {
+        if precision <= COMMON_PRECISION {
+            power.checked_mul(10u128.checked_pow((COMMON_PRECISION - precision) as u32)?)
+        } else {
+            Some(power / 10u128.checked_pow((precision - COMMON_PRECISION) as u32)?)
         }
+    }
 
-        fn spirit_calculates_energy_cost(&self, ritual: &quest_unfolds::SacredRitual) -> Result<u64, destiny_fulfilled::PlotTwist> {
-            match ritual {
-                quest_unfolds::SacredRitual::AssetTransmutation { .. } => Ok(150_000),
-                _ => Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
-                    "Uniswap spirit only performs transmutations".to_string()
-                )),
-            }
+    /// Returns a power from the common precision to `precision` decimals,
+    /// rounding down whatever falls below the asset's smallest unit
+    pub fn power_settles_to_native_precision(power: u128, precision: u8) -> u128 {
+        if precision <= COMMON_PRECISION {
+            power / 10u128.pow((COMMON_PRECISION - precision) as u32)
+        } else {
+            10u128.checked_pow((precision - COMMON_PRECISION) as u32)
+                .and_then(|scale| power.checked_mul(scale))
+                .unwrap_or(u128::MAX)
         }
+    }
 
-        fn spirit_performs_sacred_ritual(
-            &self, 
-            guardian: &mut asset_awakens::WalletGuardian, 
-            ritual: quest_unfolds::SacredRitual
-        ) -> Result<quest_unfolds::AssetQuest, destiny_fulfilled::PlotTwist> {
-            match ritual.clone() {
-                quest_unfolds::SacredRitual::AssetTransmutation { offering, desired_form, power_amount } => {
+    /// `a * b / divisor`, rounded down, with the product held at 256 bits so
+    /// only a quotient too large for `u128` (or a zero divisor) is refused
+    fn mul_div(a: u128, b: u128, divisor: u128) -> Option<u128> {
+        const HALF: u32 = 64;
+        const LOW_MASK: u128 = u64::MAX as u128;
+        if divisor == 0 {
+            return None;
+        }
+
+        // Schoolbook multiplication of 64-bit halves into a high and low word
+        let (a_high, a_low) = (a >> HALF, a & LOW_MASK);
+        let (b_high, b_low) = (b >> HALF, b & LOW_MASK);
+        let (low_low, low_high, high_low) = (a_low * b_low, a_low * b_high, a_high * b_low);
+        let middle = (low_low >> HALF) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+        let low = (low_low & LOW_MASK) | (middle << HALF);
+        let high = a_high * b_high + (low_high >> HALF) + (high_low >> HALF) + (middle >> HALF);
+        if high >= divisor {
+            return None;
+        }
+
+        // Long division, one bit of the low word at a time
+        let mut remainder = high;
+        let mut quotient = 0u128;
+        for bit in (0..128).rev() {
+            let carried = remainder >> 127 == 1;
+            remainder = (remainder << 1) | ((low >> bit) & 1);
+            quotient <<= 1;
+            if carried || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient |= 1;
+            }
+        }
+        Some(quotient)
+    }
+
+    fn pool_key(first_essence: &str, second_essence: &str) -> (String, String) {
+        if first_essence <= second_essence {
+            (first_essence.to_string(), second_essence.to_string())
+        } else {
+            (second_essence.to_string(), first_essence.to_string())
+        }
+    }
+
+    /// The two-asset StableSwap formula: find the invariant `D` of the pool
+    /// as it stands, then the desired reserve that keeps `D` once the
+    /// offering (after the same 0.3% tribute) is added. Both are solved by
+    /// Newton's method in integers, rounding in the pool's favour.
+    fn stable_alchemy_calculates_output(
+        amplification: u128,
+        offering_amount: u128,
+        offering_reserves: u128,
+        desired_reserves: u128
+    ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+        let overflow = || destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(format!(
+            "stable swapping {} against reserves {}/{}", offering_amount, offering_reserves, desired_reserves
+        ));
+        if amplification == 0 || offering_reserves == 0 || desired_reserves == 0 {
+            return Err(overflow());
+        }
+
+        // A * n^n for two assets
+        let amplified = amplification.checked_mul(4).ok_or_else(overflow)?;
+        let offering_with_tribute = offering_amount.checked_mul(997).ok_or_else(overflow)? / 1000;
+
+        let reserve_sum = offering_reserves.checked_add(desired_reserves).ok_or_else(overflow)?;
+        let mut invariant = reserve_sum;
+        // Every product of the invariant with itself is held at 256 bits,
+        // since reserves in the common precision square past `u128`
+        for _ in 0..STABLESWAP_MAX_ITERATIONS {
+            let invariant_product = offering_reserves.checked_mul(2)
+                .and_then(|doubled| mul_div(invariant, invariant, doubled))
+                .ok_or_else(overflow)?;
+            let invariant_product = desired_reserves.checked_mul(2)
+                .and_then(|doubled| mul_div(invariant_product, invariant, doubled))
+                .ok_or_else(overflow)?;
+            let previous = invariant;
+            let sum_terms = amplified.checked_mul(reserve_sum)
+                .and_then(|sum_term| sum_term.checked_add(invariant_product.checked_mul(2)?))
+                .ok_or_else(overflow)?;
+            let denominator = (amplified - 1).checked_mul(invariant)
+                .and_then(|amplified_term| amplified_term.checked_add(invariant_product.checked_mul(3)?))
+                .ok_or_else(overflow)?;
+            invariant = mul_div(sum_terms, invariant, denominator).ok_or_else(overflow)?;
+            if invariant.abs_diff(previous) <= 1 {
+                break;
+            }
+        }
+
+        let new_offering_reserves = offering_reserves.checked_add(offering_with_tribute).ok_or_else(overflow)?;
+        // The curvature D^3 / (4 * A * n^n * x') itself outgrows `u128`, so it
+        // is kept as D^2 / 2x' and divided by the remaining 2 * A * n^n only
+        // alongside each step's denominator
+        let curvature = new_offering_reserves.checked_mul(2)
+            .and_then(|doubled| mul_div(invariant, invariant, doubled))
+            .ok_or_else(overflow)?;
+        let amplified_twice = amplified.checked_mul(2).ok_or_else(overflow)?;
+        let balance_term = new_offering_reserves.checked_add(invariant / amplified).ok_or_else(overflow)?;
+
+        let mut new_desired_reserves = invariant;
+        for _ in 0..STABLESWAP_MAX_ITERATIONS {
+            let previous = new_desired_reserves;
+            let denominator = new_desired_reserves.checked_mul(2)
+                .and_then(|doubled| doubled.checked_add(balance_term))
+                .and_then(|total| total.checked_sub(invariant))
+                .ok_or_else(overflow)?;
+            // (y^2 + c) / denominator, each term rounded down, so a step may
+            // fall one unit short - within the tolerance Newton stops at
+            let squared_term = mul_div(new_desired_reserves, new_desired_reserves, denominator);
+            let curvature_term = denominator.checked_mul(amplified_twice)
+                .and_then(|scaled| mul_div(curvature, invariant, scaled));
+            new_desired_reserves = squared_term.zip(curvature_term)
+                .and_then(|(squared, curved)| squared.checked_add(curved))
+                .ok_or_else(overflow)?;
+            if new_desired_reserves.abs_diff(previous) <= 1 {
+                break;
+            }
+        }
+
+        // One unit is kept back so rounding never favours the trader
+        Ok(desired_reserves.saturating_sub(new_desired_reserves).saturating_sub(1))
+    }
+
+    #[async_trait]
+    impl quest_unfolds::ProtocolCommunion for UniswapExchangerSpirit {
+        fn spirit_reveals_identity(&self) -> quest_unfolds::ProtocolSpirit {
+            quest_unfolds::ProtocolSpirit::UniswapTheExchanger
+        }
+
+        fn risk_rating(&self) -> u8 {
+            self.risk_rating
+        }
+
+        fn spirit_calculates_energy_cost(&self, ritual: &quest_unfolds::SacredRitual) -> Result<u64, destiny_fulfilled::PlotTwist> {
+            match ritual {
+                quest_unfolds::SacredRitual::AssetTransmutation { .. } => Ok(150_000),
+                _ => Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
+                    "Uniswap spirit only performs transmutations".to_string()
+                )),
+            }
+        }
+
+        async fn spirit_performs_sacred_ritual(
+            &mut self, 
+            guardian: &mut asset_awakens::WalletGuardian, 
+            ritual: quest_unfolds::SacredRitual
+        ) -> Result<quest_unfolds::AssetQuest, destiny_fulfilled::PlotTwist> {
+            if let Some(floor) = &self.trade_floor {
+                floor.ritual_clears_the_floor(&ritual)?;
+            }
+            guardian.allowance_permits_ritual(&self.spirit_reveals_identity(), &ritual)?;
+
+            match ritual.clone() {
+                quest_unfolds::SacredRitual::AssetTransmutation { offering, desired_form, power_amount, min_received } => {
                     // The guardian checks if the offering has sufficient power
                     let offering_asset = guardian.guardian_whispers_asset_secrets(&offering.essence)
                         .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(offering.essence.clone()))?;
@@ -326,23 +1341,33 @@ pub mod supporting_cast {
                         return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
                     }
 
-                    // The spirit performs ancient alchemy
-                    let transformed_power = self.ancient_alchemy_calculates_output(
-                        power_amount, 
-                        1_000_000_000, // Mock reserves
-                        1_000_000_000
-                    );
+                    // The spirit performs ancient alchemy along the pool's curve
+                    let transformed_power = self.spirit_quotes_transmutation(&offering, &desired_form, power_amount)?;
+                    if let Some(guard) = &self.price_guard {
+                        guard.transmutation_keeps_faith(&offering, power_amount, &desired_form, transformed_power)?;
+                    }
+                    if transformed_power < min_received {
+                        return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                            "Transmutation would yield {} {}, below the {} the guardian asked for",
+                            transformed_power, desired_form.essence, min_received
+                        )));
+                    }
 
-                    // Assets undergo their transformation
-                    guardian.asset_power_transforms(&offering.essence, offering_asset.current_power - power_amount)?;
-                    
+                    let remaining_offering_power = offering_asset.current_power - power_amount;
                     let current_desired_power = guardian.guardian_whispers_asset_secrets(&desired_form.essence)
                         .map(|a| a.current_power)
                         .unwrap_or(0);
-                    guardian.asset_power_transforms(&desired_form.essence, current_desired_power + transformed_power)?;
+                    let new_desired_power = current_desired_power.checked_add(transformed_power)
+                        .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(desired_form.essence.clone()))?;
+
+                    // Assets undergo their transformation, and the pool with them
+                    self.pool_absorbs_transmutation(&offering, power_amount, &desired_form, transformed_power)?;
+                    guardian.asset_power_transforms(&offering.essence, remaining_offering_power)?;
+                    guardian.asset_power_transforms(&desired_form.essence, new_desired_power)?;
 
                     let quest = quest_unfolds::AssetQuest {
                         quest_id: format!("0x{:x}", mystical_random_generator::generate_quest_id()),
+                        sequence: 0,
                         protocol_spirit: self.spirit_reveals_identity(),
                         sacred_ritual: ritual,
                         energy_limit: 150_000,
@@ -350,23 +1375,62 @@ pub mod supporting_cast {
                         quest_outcome: quest_unfolds::QuestOutcome::LegendComplete,
                     };
 
-                    guardian.legend_book.push(quest.clone());
-                    Ok(quest)
+                    guardian.allowance_is_consumed(&self.spirit_reveals_identity(), &quest.sacred_ritual);
+                    Ok(guardian.guardian_records_quest(quest))
                 }
                 _ => Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
                     "This spirit only accepts transmutation rituals".to_string()
                 )),
             }
         }
+
+        fn spirit_reveals_reserves(
+            &self,
+            offering: &asset_awakens::DigitalAsset,
+            desired_form: &asset_awakens::DigitalAsset
+        ) -> Option<(u128, u128)> {
+            Some(self.reserves_of_pool(offering, desired_form))
+        }
+
+        fn spirit_reveals_pools(&self) -> Vec<(String, String)> {
+            self.pool_reserves.keys().cloned().collect()
+        }
+
+        fn spirit_quotes_exchange(
+            &self,
+            from: &asset_awakens::DigitalAsset,
+            to: &asset_awakens::DigitalAsset,
+            amount: u128
+        ) -> Option<u128> {
+            self.spirit_quotes_price(from, to, amount)
+        }
+
+        fn spirit_casts_reflection(&self) -> Box<dyn quest_unfolds::ProtocolCommunion> {
+            Box::new(self.clone())
+        }
     }
 
     /// ## The Aave Lending Spirit
     /// 
     /// The generous spirit of Aave provides shelter for assets seeking growth
     /// and offers power to those who prove their trustworthiness.
+    #[derive(Debug, Clone)]
     pub struct AaveLendingSpirit {
         pub sanctuary_address: String,
         pub blessed_assets: Vec<String>,
+        pub supplied_power: HashMap<String, u128>,
+        /// What is owed of each asset, principal and accrued interest together
+        pub borrowed_power: HashMap<String, u128>,
+        /// When each asset's loan was first taken, on the spirit's clock
+        pub borrowed_since: HashMap<String, u64>,
+        /// Seconds of interest the spirit has accrued since it was established
+        pub spirit_clock_secs: u64,
+        pub risk_rating: u8,
+        /// Rituals worth less than this are refused; `None` accepts any size
+        pub trade_floor: Option<quest_unfolds::TradeFloor>,
+        /// Prices collateral and debt; `None` counts every smallest unit of
+        /// every asset alike, just as an untraded pool swaps them
+        pub price_oracle: Option<std::sync::Arc<dyn quest_unfolds::PriceOracle>>,
     }
 
     impl AaveLendingSpirit {
@@ -379,7 +1443,145 @@ pub mod supporting_cast {
                     "DAI".to_string(),
                     "WETH".to_string(),
                 ],
+                supplied_power: HashMap::new(),
+                borrowed_power: HashMap::new(),
+                borrowed_since: HashMap::new(),
+                spirit_clock_secs: 0,
+                risk_rating: 35, // lending adds liquidation and oracle risk
+                trade_floor: None,
+                price_oracle: None,
+            }
+        }
+
+        /// ## The Price of Borrowed Time
+        /// 
+        /// Lets `elapsed_secs` pass on the spirit's clock, compounding every
+        /// outstanding debt each second at its asset's blessing rate as a
+        /// yearly rate. Interest is rounded up, in the spirit's favour.
+        pub fn accrue_interest(&mut self, elapsed_secs: u64) -> Result<(), destiny_fulfilled::PlotTwist> {
+            let mut grown_debts = HashMap::new();
+            for (essence, owed) in self.borrowed_power.iter().filter(|(_, owed)| **owed > 0) {
+                let per_second = self.spirit_reveals_blessing_rate(essence)? / 100.0 / SECONDS_PER_YEAR as f64;
+                let growth = (1.0 + per_second).powf(elapsed_secs as f64) - 1.0;
+                let interest = (*owed as f64 * growth).ceil() as u128;
+                let grown = owed.checked_add(interest)
+                    .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(essence.clone()))?;
+                grown_debts.insert(essence.clone(), grown);
+            }
+            self.borrowed_power.extend(grown_debts);
+            self.spirit_clock_secs += elapsed_secs;
+            Ok(())
+        }
+
+        /// How long the guardian's loan of the asset has run on the spirit's
+        /// clock; `None` while nothing of it is owed
+        pub fn loan_age_secs(&self, asset_essence: &str) -> Option<u64> {
+            self.borrowed_since.get(asset_essence).map(|since| self.spirit_clock_secs - since)
+        }
+
+        /// ## How Safely the Guardian Borrows
+        /// 
+        /// The value of the guardian's collateral times the liquidation
+        /// threshold, over the value of its debt. With an oracle each position
+        /// is counted in whole units, by the decimals the guardian knows the
+        /// asset by, at the oracle's price, and an asset the oracle cannot
+        /// price counts one dollar a unit; without one every smallest unit
+        /// counts alike. Below 1.0 the guardian may be liquidated.
+        pub fn health_factor(&self, guardian: &asset_awakens::WalletGuardian) -> f64 {
+            self.health_factor_of_positions(guardian, &self.supplied_power, &self.borrowed_power)
+        }
+
+        fn health_factor_of_positions(
+            &self,
+            guardian: &asset_awakens::WalletGuardian,
+            supplied: &HashMap<String, u128>,
+            borrowed: &HashMap<String, u128>
+        ) -> f64 {
+            let value_of = |essence: &String, power: &u128| *power as f64 * self.unit_value(guardian, essence);
+            let debt_value: f64 = borrowed.iter().map(|(essence, power)| value_of(essence, power)).sum();
+            if debt_value == 0.0 {
+                return f64::INFINITY;
+            }
+            let collateral_value: f64 = supplied.iter().map(|(essence, power)| value_of(essence, power)).sum();
+            collateral_value * LIQUIDATION_THRESHOLD / debt_value
+        }
+
+        /// What one smallest unit of the asset is worth. Priced units are
+        /// counted in dollars at the common precision rather than in whole
+        /// dollars, so positions of whole units compare without rounding.
+        fn unit_value(&self, guardian: &asset_awakens::WalletGuardian, essence: &str) -> f64 {
+            let Some(oracle) = &self.price_oracle else {
+                return 1.0;
+            };
+            let precision = guardian.guardian_whispers_asset_secrets(essence).map_or(COMMON_PRECISION, |asset| asset.precision);
+            10f64.powi(COMMON_PRECISION as i32 - precision as i32) * oracle.price_usd(essence).unwrap_or(1.0)
+        }
+
+        /// ## The Reckoning of an Underwater Loan
+        /// 
+        /// A liquidator settles `repay_amount` of the borrower's `debt_essence`
+        /// debt from its own wallet, and is paid in the borrower's supplied
+        /// `collateral_essence`, worth what it settled plus `LIQUIDATION_BONUS`.
+        /// Should that be more than the borrower supplied, all of it is seized
+        /// and only the share of `repay_amount` it pays for is settled. Only a
+        /// guardian whose health factor has fallen below 1.0 may be liquidated.
+        /// Returns the collateral seized.
+        pub fn liquidate(
+            &mut self,
+            borrower: &asset_awakens::WalletGuardian,
+            liquidator: &mut asset_awakens::WalletGuardian,
+            debt_essence: &str,
+            repay_amount: u128,
+            collateral_essence: &str
+        ) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            let health = self.health_factor(borrower);
+            if health >= 1.0 {
+                return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                    "A guardian at health {:.2} is not underwater", health
+                )));
+            }
+            let outstanding_debt = self.borrowed_power.get(debt_essence).copied().unwrap_or(0);
+            if repay_amount > outstanding_debt {
+                return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                    "Repaying {} {} would settle more than the {} owed", repay_amount, debt_essence, outstanding_debt
+                )));
+            }
+            let liquidator_power = liquidator.guardian_whispers_asset_secrets(debt_essence)
+                .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(debt_essence.to_string()))?
+                .current_power;
+            if liquidator_power < repay_amount {
+                return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
+            }
+
+            let collateral = borrower.guardian_whispers_asset_secrets(collateral_essence)
+                .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(collateral_essence.to_string()))?;
+            let seized_value = repay_amount as f64 * self.unit_value(borrower, debt_essence) * (1.0 + LIQUIDATION_BONUS);
+            let supplied_collateral = self.supplied_power.get(collateral_essence).copied().unwrap_or(0);
+            let seizable = (seized_value / self.unit_value(borrower, collateral_essence)).floor() as u128;
+            let (seized, settled) = if seizable > supplied_collateral {
+                // Rounded up, so the liquidator never buys collateral below the bonus
+                let settled = (repay_amount as f64 * supplied_collateral as f64 / seizable as f64).ceil() as u128;
+                (supplied_collateral, settled.min(repay_amount))
+            } else {
+                (seizable, repay_amount)
+            };
+
+            let liquidator_collateral = liquidator.guardian_whispers_asset_secrets(collateral_essence)
+                .map_or(0, |asset| asset.current_power);
+            liquidator.asset_power_transforms(debt_essence, liquidator_power - settled)?;
+            liquidator.asset_finds_sanctuary(asset_awakens::DigitalAsset {
+                current_power: liquidator_collateral + seized,
+                ..collateral.clone()
+            });
+            self.borrowed_power.insert(debt_essence.to_string(), outstanding_debt - settled);
+            if outstanding_debt == settled {
+                self.borrowed_since.remove(debt_essence);
             }
+            self.supplied_power.insert(collateral_essence.to_string(), supplied_collateral - seized);
+
+            println!("The spirit hands {} {} of an underwater guardian's collateral to its liquidator",
+                seized, collateral_essence);
+            Ok(seized)
         }
 
         pub fn spirit_reveals_blessing_rate(&self, asset_essence: &str) -> Result<f64, destiny_fulfilled::PlotTwist> {
@@ -393,11 +1595,16 @@ pub mod supporting_cast {
         }
     }
 
+    #[async_trait]
     impl quest_unfolds::ProtocolCommunion for AaveLendingSpirit {
         fn spirit_reveals_identity(&self) -> quest_unfolds::ProtocolSpirit {
             quest_unfolds::ProtocolSpirit::AaveTheGiver
         }
 
+        fn risk_rating(&self) -> u8 {
+            self.risk_rating
+        }
+
         fn spirit_calculates_energy_cost(&self, ritual: &quest_unfolds::SacredRitual) -> Result<u64, destiny_fulfilled::PlotTwist> {
             match ritual {
                 quest_unfolds::SacredRitual::PowerOffering { .. } => Ok(200_000),
@@ -410,13 +1617,19 @@ pub mod supporting_cast {
             }
         }
 
-        fn spirit_performs_sacred_ritual(
-            &self, 
+        async fn spirit_performs_sacred_ritual(
+            &mut self, 
             guardian: &mut asset_awakens::WalletGuardian, 
             ritual: quest_unfolds::SacredRitual
         ) -> Result<quest_unfolds::AssetQuest, destiny_fulfilled::PlotTwist> {
+            if let Some(floor) = &self.trade_floor {
+                floor.ritual_clears_the_floor(&ritual)?;
+            }
+            guardian.allowance_permits_ritual(&self.spirit_reveals_identity(), &ritual)?;
+
             let quest = quest_unfolds::AssetQuest {
                 quest_id: format!("0x{:x}", mystical_random_generator::generate_quest_id()),
+                sequence: 0,
                 protocol_spirit: self.spirit_reveals_identity(),
                 sacred_ritual: ritual.clone(),
                 energy_limit: self.spirit_calculates_energy_cost(&ritual)?,
@@ -435,20 +1648,87 @@ pub mod supporting_cast {
                     }
 
                     guardian.asset_power_transforms(&asset.essence, current_power - power_amount)?;
+                    *self.supplied_power.entry(asset.essence.clone()).or_insert(0) += power_amount;
                     
                     println!("The spirit graciously accepts offering of {} {}", 
-                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset }
+                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset.clone() }
                             .power_level_becomes_readable(), 
                         asset.essence);
                 }
                 quest_unfolds::SacredRitual::PowerBorrowing { asset, power_amount } => {
+                    // Only a blessed asset has a rate to accrue its interest by
+                    self.spirit_reveals_blessing_rate(&asset.essence)?;
+                    // The collateral must still carry every loan once this one is granted
+                    let mut borrowed_after = self.borrowed_power.clone();
+                    *borrowed_after.entry(asset.essence.clone()).or_insert(0) += power_amount;
+                    let health_after = self.health_factor_of_positions(guardian, &self.supplied_power, &borrowed_after);
+                    if health_after < 1.0 {
+                        return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                            "Borrowing {} {} would leave the loans undercollateralized at health {:.2}",
+                            power_amount, asset.essence, health_after
+                        )));
+                    }
                     let current_power = guardian.guardian_whispers_asset_secrets(&asset.essence)
                         .map(|a| a.current_power)
                         .unwrap_or(0);
                     guardian.asset_power_transforms(&asset.essence, current_power + power_amount)?;
+                    *self.borrowed_power.entry(asset.essence.clone()).or_insert(0) += power_amount;
+                    self.borrowed_since.entry(asset.essence.clone()).or_insert(self.spirit_clock_secs);
                     
                     println!("The spirit grants borrowed power of {} {}", 
-                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset }
+                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset.clone() }
+                            .power_level_becomes_readable(), 
+                        asset.essence);
+                }
+                quest_unfolds::SacredRitual::DebtSettlement { asset, power_amount } => {
+                    let outstanding_debt = self.borrowed_power.get(&asset.essence).copied().unwrap_or(0);
+                    if power_amount > outstanding_debt {
+                        return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                            "Repaying {} {} would settle more than the {} owed", power_amount, asset.essence, outstanding_debt
+                        )));
+                    }
+                    let current_power = guardian.guardian_whispers_asset_secrets(&asset.essence)
+                        .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(asset.essence.clone()))?
+                        .current_power;
+                    if current_power < power_amount {
+                        return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
+                    }
+
+                    guardian.asset_power_transforms(&asset.essence, current_power - power_amount)?;
+                    self.borrowed_power.insert(asset.essence.clone(), outstanding_debt - power_amount);
+                    if outstanding_debt == power_amount {
+                        self.borrowed_since.remove(&asset.essence);
+                    }
+
+                    println!("The spirit accepts repayment of {} {}", 
+                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset.clone() }
+                            .power_level_becomes_readable(), 
+                        asset.essence);
+                }
+                quest_unfolds::SacredRitual::PowerReclamation { asset, power_amount } => {
+                    let supplied = self.supplied_power.get(&asset.essence).copied().unwrap_or(0);
+                    if supplied < power_amount {
+                        return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
+                    }
+                    // The collateral left behind must still carry every loan
+                    let mut supplied_after = self.supplied_power.clone();
+                    supplied_after.insert(asset.essence.clone(), supplied - power_amount);
+                    let health_after = self.health_factor_of_positions(guardian, &supplied_after, &self.borrowed_power);
+                    if health_after < 1.0 {
+                        return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                            "Reclaiming {} {} would leave the loans undercollateralized at health {:.2}",
+                            power_amount, asset.essence, health_after
+                        )));
+                    }
+                    let current_power = guardian.guardian_whispers_asset_secrets(&asset.essence)
+                        .map(|a| a.current_power)
+                        .unwrap_or(0);
+
+                    guardian.asset_power_transforms(&asset.essence, current_power + power_amount)?;
+                    self.supplied_power.insert(asset.essence.clone(), supplied - power_amount);
+
+                    println!("The spirit returns offered power of {} {}", 
+                        asset_awakens::DigitalAsset { current_power: power_amount, ..asset.clone() }
                             .power_level_becomes_readable(), 
                         asset.essence);
                 }
@@ -457,8 +1737,202 @@ pub mod supporting_cast {
                 )),
             }
 
-            guardian.legend_book.push(quest.clone());
-            Ok(quest)
+            guardian.allowance_is_consumed(&self.spirit_reveals_identity(), &quest.sacred_ritual);
+            Ok(guardian.guardian_records_quest(quest))
+        }
+
+        fn spirit_reveals_exposure(&self) -> HashMap<String, i128> {
+            let mut exposure = HashMap::new();
+            for (essence, power) in &self.supplied_power {
+                *exposure.entry(essence.clone()).or_insert(0) += *power as i128;
+            }
+            for (essence, power) in &self.borrowed_power {
+                *exposure.entry(essence.clone()).or_insert(0) -= *power as i128;
+            }
+            exposure
+        }
+
+        /// The spirit's own `health_factor`, so a rehearsal and a liquidation
+        /// judge the guardian alike
+        fn spirit_reveals_health_factor(&self, guardian: &asset_awakens::WalletGuardian) -> Option<f64> {
+            Some(self.health_factor(guardian))
+        }
+
+        /// Accrues interest on every loan for the seconds the blocks span
+        fn spirit_feels_blocks_pass(&mut self, blocks: u64) -> Result<(), destiny_fulfilled::PlotTwist> {
+            let elapsed_secs = blocks.checked_mul(SECONDS_PER_BLOCK)
+                .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(format!("{} blocks of time", blocks)))?;
+            self.accrue_interest(elapsed_secs)
+        }
+
+        fn spirit_casts_reflection(&self) -> Box<dyn quest_unfolds::ProtocolCommunion> {
+            Box::new(self.clone())
+        }
+    }
+
+    /// ## The Compound Grower Spirit
+    /// 
+    /// The patient spirit of Compound turns supplied power into shares of
+    /// its market. Each market's supply index grows block by block, so a
+    /// share redeems for more power the longer it is held.
+    #[derive(Debug, Clone)]
+    pub struct CompoundGrowerSpirit {
+        pub comptroller_address: String,
+        pub blessed_assets: Vec<String>,
+        /// What one share of each market is worth in power, against `SUPPLY_INDEX_ONE`
+        pub supply_index: HashMap<String, u128>,
+        /// The shares held on the guardian's behalf, per asset
+        pub supplied_shares: HashMap<String, u128>,
+        pub risk_rating: u8,
+    }
+
+    impl CompoundGrowerSpirit {
+        pub fn spirit_plants_its_markets(comptroller_address: String) -> Self {
+            Self {
+                comptroller_address,
+                blessed_assets: vec![
+                    "USDC".to_string(),
+                    "USDT".to_string(),
+                    "DAI".to_string(),
+                    "WETH".to_string(),
+                ],
+                supply_index: HashMap::new(),
+                supplied_shares: HashMap::new(),
+                risk_rating: 30, // long battle-tested, but still a lending market
+            }
+        }
+
+        /// The yearly supply rate of a market, in percent
+        pub fn spirit_reveals_growth_rate(&self, asset_essence: &str) -> Result<u128, destiny_fulfilled::PlotTwist> {
+            match asset_essence {
+                "USDC" | "USDT" | "DAI" => Ok(3),
+                "WETH" => Ok(2),
+                _ => Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
+                    "Asset not blessed by this spirit".to_string()
+                )),
+            }
+        }
+
+        /// A market's supply index, `SUPPLY_INDEX_ONE` until it first grows
+        pub fn index_of(&self, asset_essence: &str) -> u128 {
+            self.supply_index.get(asset_essence).copied().unwrap_or(SUPPLY_INDEX_ONE)
+        }
+
+        /// The power the guardian's shares of a market redeem for today
+        pub fn spirit_reveals_supplied_power(&self, asset_essence: &str) -> u128 {
+            let shares = self.supplied_shares.get(asset_essence).copied().unwrap_or(0);
+            shares.saturating_mul(self.index_of(asset_essence)) / SUPPLY_INDEX_ONE
+        }
+    }
+
+    #[async_trait]
+    impl quest_unfolds::ProtocolCommunion for CompoundGrowerSpirit {
+        fn spirit_reveals_identity(&self) -> quest_unfolds::ProtocolSpirit {
+            quest_unfolds::ProtocolSpirit::CompoundTheGrower
+        }
+
+        fn risk_rating(&self) -> u8 {
+            self.risk_rating
+        }
+
+        fn spirit_calculates_energy_cost(&self, ritual: &quest_unfolds::SacredRitual) -> Result<u64, destiny_fulfilled::PlotTwist> {
+            match ritual {
+                quest_unfolds::SacredRitual::PowerOffering { .. } => Ok(160_000),
+                quest_unfolds::SacredRitual::PowerReclamation { .. } => Ok(190_000),
+                _ => Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
+                    "Compound spirit only accepts supplies and withdrawals".to_string()
+                )),
+            }
+        }
+
+        async fn spirit_performs_sacred_ritual(
+            &mut self, 
+            guardian: &mut asset_awakens::WalletGuardian, 
+            ritual: quest_unfolds::SacredRitual
+        ) -> Result<quest_unfolds::AssetQuest, destiny_fulfilled::PlotTwist> {
+            guardian.allowance_permits_ritual(&self.spirit_reveals_identity(), &ritual)?;
+            let quest = quest_unfolds::AssetQuest {
+                quest_id: format!("0x{:x}", mystical_random_generator::generate_quest_id()),
+                sequence: 0,
+                protocol_spirit: self.spirit_reveals_identity(),
+                sacred_ritual: ritual.clone(),
+                energy_limit: self.spirit_calculates_energy_cost(&ritual)?,
+                energy_price: 20_000_000_000,
+                quest_outcome: quest_unfolds::QuestOutcome::LegendComplete,
+            };
+            let overflow = |essence: &str| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(essence.to_string());
+
+            match ritual {
+                quest_unfolds::SacredRitual::PowerOffering { asset, power_amount } => {
+                    self.spirit_reveals_growth_rate(&asset.essence)?;
+                    let current_power = guardian.guardian_whispers_asset_secrets(&asset.essence)
+                        .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(asset.essence.clone()))?
+                        .current_power;
+                    if current_power < power_amount {
+                        return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
+                    }
+
+                    // The supplier is given whole shares only, so the market never owes more than it holds
+                    let minted_shares = power_amount.checked_mul(SUPPLY_INDEX_ONE)
+                        .ok_or_else(|| overflow(&asset.essence))? / self.index_of(&asset.essence);
+                    let held_shares = self.supplied_shares.get(&asset.essence).copied().unwrap_or(0)
+                        .checked_add(minted_shares)
+                        .ok_or_else(|| overflow(&asset.essence))?;
+                    guardian.asset_power_transforms(&asset.essence, current_power - power_amount)?;
+                    self.supplied_shares.insert(asset.essence.clone(), held_shares);
+                }
+                quest_unfolds::SacredRitual::PowerReclamation { asset, power_amount } => {
+                    // Shares are burned rounding up, so a withdrawal never takes more than it paid for
+                    let index = self.index_of(&asset.essence);
+                    let burned_shares = power_amount.checked_mul(SUPPLY_INDEX_ONE)
+                        .ok_or_else(|| overflow(&asset.essence))?
+                        .div_ceil(index);
+                    let held_shares = self.supplied_shares.get(&asset.essence).copied().unwrap_or(0);
+                    if held_shares < burned_shares {
+                        return Err(destiny_fulfilled::PlotTwist::PowerInsufficient);
+                    }
+
+                    let current_power = guardian.guardian_whispers_asset_secrets(&asset.essence)
+                        .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(asset.essence.clone()))?
+                        .current_power;
+                    let reclaimed_power = current_power.checked_add(power_amount)
+                        .ok_or_else(|| overflow(&asset.essence))?;
+                    guardian.asset_power_transforms(&asset.essence, reclaimed_power)?;
+                    self.supplied_shares.insert(asset.essence.clone(), held_shares - burned_shares);
+                }
+                _ => return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
+                    "Ritual not yet mastered by this spirit".to_string()
+                )),
+            }
+
+            guardian.allowance_is_consumed(&self.spirit_reveals_identity(), &quest.sacred_ritual);
+            Ok(guardian.guardian_records_quest(quest))
+        }
+
+        fn spirit_reveals_exposure(&self) -> HashMap<String, i128> {
+            self.supplied_shares.keys()
+                .map(|essence| (essence.clone(), self.spirit_reveals_supplied_power(essence) as i128))
+                .collect()
+        }
+
+        /// Grows every blessed market's index by its yearly rate spread over
+        /// the blocks of a year, simply rather than compounded, as Compound
+        /// accrues between two touches of a market
+        fn spirit_feels_blocks_pass(&mut self, blocks: u64) -> Result<(), destiny_fulfilled::PlotTwist> {
+            for essence in self.blessed_assets.clone() {
+                let index = self.index_of(&essence);
+                let growth = index.checked_mul(self.spirit_reveals_growth_rate(&essence)?)
+                    .and_then(|grown| grown.checked_mul(blocks as u128))
+                    .map(|grown| grown / (100 * BLOCKS_PER_YEAR))
+                    .and_then(|grown| grown.checked_add(index))
+                    .ok_or_else(|| destiny_fulfilled::PlotTwist::PowerOverflowsTheVessel(essence.clone()))?;
+                self.supply_index.insert(essence, growth);
+            }
+            Ok(())
+        }
+
+        fn spirit_casts_reflection(&self) -> Box<dyn quest_unfolds::ProtocolCommunion> {
+            Box::new(self.clone())
         }
     }
 }
@@ -475,6 +1949,23 @@ pub mod supporting_cast {
 pub struct DeFiStoryOrchestrator {
     pub wallet_guardian: asset_awakens::WalletGuardian,
     pub protocol_spirits: HashMap<quest_unfolds::ProtocolSpirit, Box<dyn quest_unfolds::ProtocolCommunion>>,
+    /// The riskiest spirit the guardian will commune with; `None` trusts them all
+    pub risk_tolerance: Option<u8>,
+    /// Rituals waiting for their moment, oldest first
+    pub conditional_orders: Vec<quest_unfolds::ConditionalOrder>,
+    /// Every order that has won a gas auction, in the order it executed
+    pub auction_history: Vec<destiny_fulfilled::AuctionWin>,
+    /// How long and how costly a route between two assets may be
+    pub routing_limits: quest_unfolds::RoutingLimits,
+    /// Every change made through the orchestrator, oldest first; changes
+    /// made to the public fields directly are not seen by it
+    pub event_log: Vec<destiny_fulfilled::LoggedEvent>,
+    next_order_id: u64,
+    /// The spirits as they stood when the tale began, for replays to start from
+    genesis_spirits: HashMap<quest_unfolds::ProtocolSpirit, Box<dyn quest_unfolds::ProtocolCommunion>>,
+    /// A reflection of each spirit that joined later, as it joined, by the
+    /// sequence of its `SpiritJoined` event
+    joined_spirits: HashMap<u64, Box<dyn quest_unfolds::ProtocolCommunion>>,
 }
 
 impl DeFiStoryOrchestrator {
@@ -497,71 +1988,681 @@ impl DeFiStoryOrchestrator {
             ))
         );
 
+        protocol_spirits.insert(
+            quest_unfolds::ProtocolSpirit::CompoundTheGrower,
+            Box::new(supporting_cast::CompoundGrowerSpirit::spirit_plants_its_markets(
+                "0x3d9819210A31b4961b30EF54bE2aeD79B9c9Cd3B".to_string()
+            ))
+        );
+
+        let genesis_spirits = protocol_spirits.iter()
+            .map(|(spirit_id, spirit)| (spirit_id.clone(), spirit.spirit_casts_reflection()))
+            .collect();
+
         Ok(Self {
             wallet_guardian,
             protocol_spirits,
+            risk_tolerance: None,
+            conditional_orders: Vec::new(),
+            auction_history: Vec::new(),
+            routing_limits: quest_unfolds::RoutingLimits::default(),
+            event_log: Vec::new(),
+            next_order_id: 1,
+            genesis_spirits,
+            joined_spirits: HashMap::new(),
         })
     }
 
-    pub fn new_asset_discovers_its_destiny(
-        &mut self, 
-        essence: String, 
-        soul_address: String, 
-        precision: u8, 
-        initial_power: u128
-    ) {
-        let mut asset = asset_awakens::DigitalAsset::asset_discovers_its_identity(
-            essence, soul_address, precision
-        );
-        asset.current_power = initial_power;
-        self.wallet_guardian.asset_finds_sanctuary(asset);
+    /// Appends an event to the log under the next sequence, returning it
+    fn event_is_logged(&mut self, event: destiny_fulfilled::OrchestratorEvent) -> u64 {
+        let sequence = self.event_log.len() as u64 + 1;
+        self.event_log.push(destiny_fulfilled::LoggedEvent { sequence, event });
+        sequence
     }
 
-    pub fn assets_undergo_sacred_transmutation(
-        &mut self, 
-        offering_essence: &str, 
-        desired_essence: &str, 
-        power_amount: u128
-    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
-        let offering_asset = self.wallet_guardian.guardian_whispers_asset_secrets(offering_essence)
-            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(offering_essence.to_string()))?
-            .clone();
-        
-        let desired_asset = self.wallet_guardian.guardian_whispers_asset_secrets(desired_essence)
-            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(desired_essence.to_string()))?
-            .clone();
+    /// ## Turning Back the Pages
+    /// 
+    /// Undoes every event after `sequence` by rebuilding the tale from its
+    /// beginning: a fresh guardian at the same address and the spirits as
+    /// they first stood, onto which the events up to `sequence` are replayed
+    /// in order. The later events leave the log, and the next one logged
+    /// follows `sequence`. Rolling back to 0 returns to the very beginning.
+    /// 
+    /// The replay runs on a scratch tale, so if any event fails to replay
+    /// the orchestrator and its whole log are left exactly as they were.
+    pub async fn rollback_to(&mut self, sequence: u64) -> destiny_fulfilled::StoryResult<()> {
+        if sequence > self.event_log.len() as u64 {
+            return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                "No event {} has been logged; the log ends at {}", sequence, self.event_log.len()
+            )));
+        }
 
-        let sacred_ritual = quest_unfolds::SacredRitual::AssetTransmutation {
-            offering: offering_asset,
-            desired_form: desired_asset,
-            power_amount,
+        let reflect = |spirits: &HashMap<quest_unfolds::ProtocolSpirit, Box<dyn quest_unfolds::ProtocolCommunion>>| {
+            spirits.iter()
+                .map(|(spirit_id, spirit)| (spirit_id.clone(), spirit.spirit_casts_reflection()))
+                .collect::<HashMap<_, _>>()
+        };
+        let mut scratch = Self {
+            wallet_guardian: asset_awakens::WalletGuardian::guardian_accepts_responsibility(
+                self.wallet_guardian.mystical_address.clone()
+            )?,
+            protocol_spirits: reflect(&self.genesis_spirits),
+            risk_tolerance: None,
+            conditional_orders: Vec::new(),
+            auction_history: Vec::new(),
+            routing_limits: self.routing_limits.clone(),
+            event_log: Vec::new(),
+            next_order_id: 1,
+            genesis_spirits: reflect(&self.genesis_spirits),
+            joined_spirits: self.joined_spirits.iter()
+                .filter(|(&joined_at, _)| joined_at <= sequence)
+                .map(|(&joined_at, spirit)| (joined_at, spirit.spirit_casts_reflection()))
+                .collect(),
         };
 
-        let protocol_spirit = self.protocol_spirits.get(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)
-            .ok_or(destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(
-                "Uniswap spirit unavailable".to_string()
-            ))?;
-
-        protocol_spirit.spirit_performs_sacred_ritual(&mut self.wallet_guardian, sacred_ritual)
+        let replayed = &self.event_log[..sequence as usize];
+        for logged in replayed {
+            scratch.event_replays(logged).await?;
+        }
+        scratch.event_log = replayed.to_vec();
+        *self = scratch;
+        Ok(())
     }
 
-    pub fn asset_seeks_sanctuary_with_lending_spirit(
-        &mut self, 
-        asset_essence: &str, 
-        power_amount: u128
-    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
-        let asset = self.wallet_guardian.guardian_whispers_asset_secrets(asset_essence)
-            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(asset_essence.to_string()))?
-            .clone();
+    /// Applies a logged event again, without logging it anew
+    async fn event_replays(&mut self, logged: &destiny_fulfilled::LoggedEvent) -> destiny_fulfilled::StoryResult<()> {
+        use destiny_fulfilled::OrchestratorEvent;
 
-        let sacred_ritual = quest_unfolds::SacredRitual::PowerOffering { asset, power_amount };
+        match &logged.event {
+            OrchestratorEvent::AssetDiscovered { essence, soul_address, precision, initial_power } => {
+                self.asset_takes_its_place(essence.clone(), soul_address.clone(), *precision, *initial_power);
+            }
+            OrchestratorEvent::RitualPerformed { spirit_id, ritual } => {
+                let protocol_spirit = self.protocol_spirits.get_mut(spirit_id)
+                    .ok_or_else(|| destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(format!("{} unavailable", spirit_id)))?;
+                Self::ritual_is_approved_and_performed(protocol_spirit.as_mut(), &mut self.wallet_guardian, ritual.clone()).await?;
+            }
+            OrchestratorEvent::BlocksPassed(blocks) => self.spirits_feel_blocks_pass(*blocks)?,
+            OrchestratorEvent::AllowanceApproved { spirit_id, essence, power, expires_at } => {
+                self.wallet_guardian.guardian_grants_allowance_until(spirit_id.clone(), essence, *power, *expires_at);
+            }
+            OrchestratorEvent::RiskToleranceSet(tolerance) => self.risk_tolerance = *tolerance,
+            OrchestratorEvent::SpiritJoined(spirit_id) => {
+                let spirit = self.joined_spirits.get(&logged.sequence)
+                    .map(|spirit| spirit.spirit_casts_reflection())
+                    .ok_or_else(|| destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(format!("{} unavailable", spirit_id)))?;
+                self.protocol_spirits.insert(spirit_id.clone(), spirit);
+            }
+            OrchestratorEvent::OrderPlaced(order) => {
+                self.next_order_id = self.next_order_id.max(order.order_id + 1);
+                self.conditional_orders.push(order.clone());
+            }
+            OrchestratorEvent::AuctionWon(win) => {
+                self.conditional_orders.retain(|order| order.order_id != win.order_id);
+                self.auction_history.push(win.clone());
+            }
+            OrchestratorEvent::ExpiredSwept { now } => {
+                self.expired_are_swept(*now);
+            }
+        }
+        Ok(())
+    }
+
+    /// Gives a spirit its place in the tale, replacing any spirit of its
+    /// kind; unlike setting `protocol_spirits` directly, the spirit joins
+    /// the event log and so survives a rollback past the moment it joined
+    pub fn spirit_joins_the_tale(&mut self, spirit: Box<dyn quest_unfolds::ProtocolCommunion>) {
+        let spirit_id = spirit.spirit_reveals_identity();
+        let joined_at = self.event_is_logged(destiny_fulfilled::OrchestratorEvent::SpiritJoined(spirit_id.clone()));
+        self.joined_spirits.insert(joined_at, spirit.spirit_casts_reflection());
+        self.protocol_spirits.insert(spirit_id, spirit);
+    }
+
+    /// Lets `blocks` simulated blocks pass for every spirit, so positions
+    /// that grow with time do so
+    pub fn blocks_pass(&mut self, blocks: u64) -> destiny_fulfilled::StoryResult<()> {
+        self.spirits_feel_blocks_pass(blocks)?;
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::BlocksPassed(blocks));
+        Ok(())
+    }
+
+    /// The blocks pass for reflections of the spirits, which take the
+    /// spirits' places only once every one has advanced, so a spirit that
+    /// cannot leaves all of them as they were
+    fn spirits_feel_blocks_pass(&mut self, blocks: u64) -> destiny_fulfilled::StoryResult<()> {
+        let mut advanced = HashMap::with_capacity(self.protocol_spirits.len());
+        for (spirit_id, protocol_spirit) in &self.protocol_spirits {
+            let mut reflection = protocol_spirit.spirit_casts_reflection();
+            reflection.spirit_feels_blocks_pass(blocks)?;
+            advanced.insert(spirit_id.clone(), reflection);
+        }
+        self.protocol_spirits = advanced;
+        Ok(())
+    }
+
+    /// Refuses a spirit whose smart-contract risk exceeds the guardian's tolerance
+    fn spirit_is_trusted(&self, spirit_id: &quest_unfolds::ProtocolSpirit) -> destiny_fulfilled::StoryResult<()> {
+        let (Some(tolerance), Some(protocol_spirit)) = (self.risk_tolerance, self.protocol_spirits.get(spirit_id)) else {
+            return Ok(());
+        };
+
+        if protocol_spirit.risk_rating() > tolerance {
+            return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(format!(
+                "{} carries risk {} above the guardian's tolerance of {}",
+                spirit_id, protocol_spirit.risk_rating(), tolerance
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sets the riskiest spirit the orchestrator will perform rituals with
+    pub fn set_risk_tolerance(&mut self, tolerance: Option<u8>) {
+        self.risk_tolerance = tolerance;
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::RiskToleranceSet(tolerance));
+    }
+
+    /// Lets a spirit draw up to `power` of an asset until `expires_at`
+    pub fn approve_until(
+        &mut self,
+        spirit_id: quest_unfolds::ProtocolSpirit,
+        asset_essence: &str,
+        power: u128,
+        expires_at: u64
+    ) {
+        self.wallet_guardian.guardian_grants_allowance_until(spirit_id.clone(), asset_essence, power, expires_at);
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::AllowanceApproved {
+            spirit_id,
+            essence: asset_essence.to_string(),
+            power,
+            expires_at,
+        });
+    }
+
+    /// Holds a ritual for later, to be cancelled if still waiting at `expires_at`.
+    /// It fires at the next poll and bids nothing for priority.
+    pub fn place_conditional_order(
+        &mut self,
+        spirit_id: quest_unfolds::ProtocolSpirit,
+        ritual: quest_unfolds::SacredRitual,
+        expires_at: u64
+    ) -> u64 {
+        self.place_triggered_order(spirit_id, ritual, quest_unfolds::OrderTrigger::AtOnce, 0, expires_at)
+    }
+
+    /// Holds a ritual until `trigger` fires, bidding `priority_fee` per unit
+    /// of energy against whatever else fires at the same poll
+    pub fn place_triggered_order(
+        &mut self,
+        spirit_id: quest_unfolds::ProtocolSpirit,
+        ritual: quest_unfolds::SacredRitual,
+        trigger: quest_unfolds::OrderTrigger,
+        priority_fee: u64,
+        expires_at: u64
+    ) -> u64 {
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+        let order = quest_unfolds::ConditionalOrder {
+            order_id,
+            protocol_spirit: spirit_id,
+            sacred_ritual: ritual,
+            expires_at,
+            trigger,
+            priority_fee,
+        };
+        self.conditional_orders.push(order.clone());
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::OrderPlaced(order));
+        order_id
+    }
+
+    /// ## The Scramble When Many Moments Come at Once
+    /// 
+    /// Every unexpired order whose trigger has fired by `now` competes in a
+    /// gas auction: the highest effective bid executes first, ties going to
+    /// the order placed earlier. Each executed order leaves the book, failed
+    /// or not, and its win is recorded in `auction_history`. Orders still
+    /// waiting, and expired ones left for `sweep_expired`, stay put.
+    pub async fn poll_conditional_orders(
+        &mut self,
+        oracle: &dyn quest_unfolds::PriceOracle,
+        now: u64
+    ) -> Vec<destiny_fulfilled::AuctionWin> {
+        let (mut triggered, still_waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.conditional_orders)
+            .into_iter()
+            .partition(|order| order.expires_at > now && order.trigger.trigger_has_fired(oracle));
+        self.conditional_orders = still_waiting;
+
+        let effective_bid = |orchestrator: &Self, order: &quest_unfolds::ConditionalOrder| {
+            let energy = orchestrator.protocol_spirits.get(&order.protocol_spirit)
+                .and_then(|spirit| spirit.spirit_calculates_energy_cost(&order.sacred_ritual).ok())
+                .unwrap_or(0);
+            order.priority_fee as u128 * energy as u128
+        };
+        let mut bids: Vec<(u128, quest_unfolds::ConditionalOrder)> = triggered.drain(..)
+            .map(|order| (effective_bid(self, &order), order))
+            .collect();
+        bids.sort_by(|(a_bid, a), (b_bid, b)| b_bid.cmp(a_bid).then(a.order_id.cmp(&b.order_id)));
+
+        let mut wins = Vec::new();
+        for (effective_bid, order) in bids {
+            let outcome = self.approve_and_execute(order.protocol_spirit, order.sacred_ritual).await;
+            let win = destiny_fulfilled::AuctionWin { order_id: order.order_id, effective_bid, outcome };
+            self.event_is_logged(destiny_fulfilled::OrchestratorEvent::AuctionWon(win.clone()));
+            wins.push(win);
+        }
+        self.auction_history.extend(wins.iter().cloned());
+        wins
+    }
+
+    /// ## Nothing Outstays Its Welcome
+    /// 
+    /// Revokes every allowance and cancels every conditional order whose
+    /// expiry has arrived by `now`, reporting what was cleaned up.
+    pub fn sweep_expired(&mut self, now: u64) -> destiny_fulfilled::SweepReport {
+        let report = self.expired_are_swept(now);
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::ExpiredSwept { now });
+        report
+    }
+
+    fn expired_are_swept(&mut self, now: u64) -> destiny_fulfilled::SweepReport {
+        let mut revoked_approvals: Vec<(quest_unfolds::ProtocolSpirit, String)> = self.wallet_guardian.allowance_expiries.iter()
+            .filter(|(_, &expires_at)| expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        revoked_approvals.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.to_string().cmp(&b.0.to_string())));
+        for (spirit_id, essence) in &revoked_approvals {
+            self.wallet_guardian.guardian_grants_allowance(spirit_id.clone(), essence, 0);
+        }
+
+        let (cancelled_orders, still_waiting) = std::mem::take(&mut self.conditional_orders)
+            .into_iter()
+            .partition(|order| order.expires_at <= now);
+        self.conditional_orders = still_waiting;
+
+        destiny_fulfilled::SweepReport { revoked_approvals, cancelled_orders }
+    }
+
+    pub fn new_asset_discovers_its_destiny(
+        &mut self, 
+        essence: String, 
+        soul_address: String, 
+        precision: u8, 
+        initial_power: u128
+    ) {
+        self.asset_takes_its_place(essence.clone(), soul_address.clone(), precision, initial_power);
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::AssetDiscovered {
+            essence, soul_address, precision, initial_power,
+        });
+    }
+
+    fn asset_takes_its_place(&mut self, essence: String, soul_address: String, precision: u8, initial_power: u128) {
+        let mut asset = asset_awakens::DigitalAsset::asset_discovers_its_identity(
+            essence, soul_address, precision
+        );
+        asset.current_power = initial_power;
+        self.wallet_guardian.asset_finds_sanctuary(asset);
+    }
+
+    /// Transmutes at whatever rate the pool offers; a guardian wary of
+    /// slippage builds the ritual with a `min_received` and performs it
+    /// through `approve_and_execute` instead.
+    pub async fn assets_undergo_sacred_transmutation(
+        &mut self, 
+        offering_essence: &str, 
+        desired_essence: &str, 
+        power_amount: u128
+    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
+        let offering_asset = self.wallet_guardian.guardian_whispers_asset_secrets(offering_essence)
+            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(offering_essence.to_string()))?
+            .clone();
+        
+        let desired_asset = self.wallet_guardian.guardian_whispers_asset_secrets(desired_essence)
+            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(desired_essence.to_string()))?
+            .clone();
+
+        let sacred_ritual = quest_unfolds::SacredRitual::AssetTransmutation {
+            offering: offering_asset,
+            desired_form: desired_asset,
+            power_amount,
+            min_received: 0,
+        };
+
+        self.spirit_is_trusted(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)?;
+        let protocol_spirit = self.protocol_spirits.get_mut(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)
+            .ok_or(destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(
+                "Uniswap spirit unavailable".to_string()
+            ))?;
+
+        let quest = Self::ritual_is_approved_and_performed(
+            protocol_spirit.as_mut(), &mut self.wallet_guardian, sacred_ritual.clone(),
+        ).await?;
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::RitualPerformed {
+            spirit_id: quest_unfolds::ProtocolSpirit::UniswapTheExchanger,
+            ritual: sacred_ritual,
+        });
+        Ok(quest)
+    }
+
+    /// ## Finding the Way Between Two Assets
+    /// 
+    /// Searches the Uniswap spirit's registered pools for a way from `from`
+    /// to `to`: the direct pool, or any single asset both sides share a pool
+    /// with. Each way is quoted for `amount` of `from`, and the one chosen
+    /// within `routing_limits` is returned as the essences it passes through;
+    /// `None` if no way delivers anything.
+    pub fn find_best_route(&self, from: &str, to: &str, amount: u128) -> Option<Vec<String>> {
+        let offering = self.wallet_guardian.guardian_whispers_asset_secrets(from)?;
+        let common_amount = supporting_cast::power_rises_to_common_precision(amount, offering.precision)?;
+        let spirit = self.protocol_spirits.get(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)?;
+        let hop_energy = spirit.spirit_calculates_energy_cost(&quest_unfolds::SacredRitual::AssetTransmutation {
+            offering: offering.clone(),
+            desired_form: offering.clone(),
+            power_amount: amount,
+            min_received: 0,
+        }).ok()?;
+
+        let pools = spirit.spirit_reveals_pools();
+        let joined = |a: &str, b: &str| pools.iter().any(|(x, y)| (x == a && y == b) || (x == b && y == a));
+        let mut paths = vec![vec![from.to_string(), to.to_string()]];
+        let shared: std::collections::BTreeSet<&String> = pools.iter()
+            .flat_map(|(x, y)| [x, y])
+            .filter(|via| via.as_str() != from && via.as_str() != to)
+            .collect();
+        paths.extend(shared.into_iter().map(|via| vec![from.to_string(), via.clone(), to.to_string()]));
+
+        // An asset the guardian has never held is taken at the common precision
+        let asset_named = |essence: &str| self.wallet_guardian.guardian_whispers_asset_secrets(essence).cloned()
+            .unwrap_or_else(|| asset_awakens::DigitalAsset::asset_discovers_its_identity(
+                essence.to_string(), String::new(), supporting_cast::COMMON_PRECISION
+            ));
+        let candidates = paths.into_iter()
+            .filter(|path| path.windows(2).all(|hop| joined(&hop[0], &hop[1])))
+            .filter_map(|path| {
+                let output = path.windows(2).try_fold(common_amount, |power, hop| {
+                    spirit.spirit_quotes_exchange(&asset_named(&hop[0]), &asset_named(&hop[1]), power)
+                })?;
+                let total_gas = hop_energy.saturating_mul(path.len() as u64 - 1);
+                (output > 0).then_some(quest_unfolds::CandidateRoute { path, output, total_gas })
+            });
+
+        self.routing_limits.route_chosen_among(candidates).map(|route| route.path)
+    }
+
+    /// ## Walking a Route Hop by Hop
+    /// 
+    /// Transmutes `amount` of the route's first essence along each hop in
+    /// turn, every hop offering all the previous one received. If any leg
+    /// fails, the guardian and the Uniswap spirit are restored to where they
+    /// stood before the first, so a route is walked whole or not at all.
+    pub async fn execute_route(
+        &mut self,
+        path: &[String],
+        amount: u128
+    ) -> destiny_fulfilled::StoryResult<Vec<quest_unfolds::AssetQuest>> {
+        if path.len() < 2 {
+            return Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(
+                "A route needs at least two assets".to_string()
+            ));
+        }
+
+        let guardian_before = self.wallet_guardian.clone();
+        let events_before = self.event_log.len();
+        let spirit_before = self.protocol_spirits.get(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)
+            .map(|spirit| spirit.spirit_casts_reflection());
+
+        let mut quests = Vec::new();
+        let mut power = amount;
+        for hop in path.windows(2) {
+            match self.route_hop_unfolds(&hop[0], &hop[1], power).await {
+                Ok((quest, received)) => {
+                    quests.push(quest);
+                    power = received;
+                }
+                Err(plot_twist) => {
+                    self.wallet_guardian = guardian_before;
+                    self.event_log.truncate(events_before);
+                    if let Some(spirit) = spirit_before {
+                        self.protocol_spirits.insert(quest_unfolds::ProtocolSpirit::UniswapTheExchanger, spirit);
+                    }
+                    return Err(plot_twist);
+                }
+            }
+        }
+        Ok(quests)
+    }
+
+    /// One hop of a route, with the power of `to` it brought the guardian
+    async fn route_hop_unfolds(
+        &mut self,
+        from: &str,
+        to: &str,
+        power_amount: u128
+    ) -> destiny_fulfilled::StoryResult<(quest_unfolds::AssetQuest, u128)> {
+        let power_of = |orchestrator: &Self, essence: &str| orchestrator.wallet_guardian
+            .guardian_whispers_asset_secrets(essence)
+            .map(|asset| asset.current_power)
+            .unwrap_or(0);
+        let before = power_of(self, to);
+        let quest = self.assets_undergo_sacred_transmutation(from, to, power_amount).await?;
+        Ok((quest, power_of(self, to).saturating_sub(before)))
+    }
+
+    pub async fn asset_seeks_sanctuary_with_lending_spirit(
+        &mut self, 
+        asset_essence: &str, 
+        power_amount: u128
+    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
+        let asset = self.wallet_guardian.guardian_whispers_asset_secrets(asset_essence)
+            .ok_or(destiny_fulfilled::PlotTwist::AssetVanishedIntoVoid(asset_essence.to_string()))?
+            .clone();
+
+        let sacred_ritual = quest_unfolds::SacredRitual::PowerOffering { asset, power_amount };
 
-        let protocol_spirit = self.protocol_spirits.get(&quest_unfolds::ProtocolSpirit::AaveTheGiver)
+        self.spirit_is_trusted(&quest_unfolds::ProtocolSpirit::AaveTheGiver)?;
+        let protocol_spirit = self.protocol_spirits.get_mut(&quest_unfolds::ProtocolSpirit::AaveTheGiver)
             .ok_or(destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(
                 "Aave spirit unavailable".to_string()
             ))?;
 
-        protocol_spirit.spirit_performs_sacred_ritual(&mut self.wallet_guardian, sacred_ritual)
+        let quest = Self::ritual_is_approved_and_performed(
+            protocol_spirit.as_mut(), &mut self.wallet_guardian, sacred_ritual.clone(),
+        ).await?;
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::RitualPerformed {
+            spirit_id: quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            ritual: sacred_ritual,
+        });
+        Ok(quest)
+    }
+
+    /// ## Permission and Performance in One Breath
+    /// 
+    /// Grants the spirit exactly the allowance the ritual needs and performs
+    /// it; the spirit consumes that allowance on success, and the approval is
+    /// rolled back if the ritual fails, so no permission is left dangling.
+    pub async fn approve_and_execute(
+        &mut self,
+        spirit_id: quest_unfolds::ProtocolSpirit,
+        ritual: quest_unfolds::SacredRitual
+    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
+        self.spirit_is_trusted(&spirit_id)?;
+        let protocol_spirit = self.protocol_spirits.get_mut(&spirit_id)
+            .ok_or_else(|| destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(
+                format!("{} unavailable", spirit_id)
+            ))?;
+
+        let quest = Self::ritual_is_approved_and_performed(
+            protocol_spirit.as_mut(), &mut self.wallet_guardian, ritual.clone(),
+        ).await?;
+        self.event_is_logged(destiny_fulfilled::OrchestratorEvent::RitualPerformed { spirit_id, ritual });
+        Ok(quest)
+    }
+
+    /// Performs a ritual under an approval of exactly what it draws, put in
+    /// place of whatever allowance the spirit already held and replaced by
+    /// it again afterwards, whether the ritual succeeded or failed. Every
+    /// ritual the orchestrator performs on the guardian's behalf is
+    /// approved this way, so standing allowances are neither spent nor
+    /// needed by them.
+    async fn ritual_is_approved_and_performed(
+        protocol_spirit: &mut dyn quest_unfolds::ProtocolCommunion,
+        guardian: &mut asset_awakens::WalletGuardian,
+        ritual: quest_unfolds::SacredRitual
+    ) -> destiny_fulfilled::StoryResult<quest_unfolds::AssetQuest> {
+        let Some((essence, power)) = ritual.ritual_draws_upon().map(|(essence, power)| (essence.to_string(), power)) else {
+            return protocol_spirit.spirit_performs_sacred_ritual(guardian, ritual).await;
+        };
+
+        let key = (protocol_spirit.spirit_reveals_identity(), essence);
+        let standing = guardian.allowances.remove(&key);
+        let standing_expiry = guardian.allowance_expiries.remove(&key);
+        guardian.guardian_grants_allowance(key.0.clone(), &key.1, power);
+
+        let outcome = protocol_spirit.spirit_performs_sacred_ritual(guardian, ritual).await;
+
+        guardian.guardian_grants_allowance(key.0.clone(), &key.1, 0);
+        if let Some(standing) = standing {
+            guardian.allowances.insert(key.clone(), standing);
+        }
+        if let Some(standing_expiry) = standing_expiry {
+            guardian.allowance_expiries.insert(key, standing_expiry);
+        }
+        outcome
+    }
+
+    /// ## The Guardian Peers Into the Mempool
+    /// 
+    /// Judges how sandwichable a planned ritual is, following the MEV
+    /// guardian's threat model: a bot profits from the slippage it can force,
+    /// so the larger a swap is relative to the pool, the juicier the target.
+    pub fn assess_mev_risk(&self, ritual: &quest_unfolds::SacredRitual) -> quest_unfolds::MevRiskReport {
+        let quest_unfolds::SacredRitual::AssetTransmutation { offering, desired_form, power_amount, .. } = ritual else {
+            return quest_unfolds::MevRiskReport {
+                sandwich_risk: quest_unfolds::SandwichRisk::Low,
+                price_impact_bps: 0,
+                extractable_power: 0,
+                warning: None,
+            };
+        };
+
+        let reserves = self.protocol_spirits.get(&quest_unfolds::ProtocolSpirit::UniswapTheExchanger)
+            .and_then(|spirit| spirit.spirit_reveals_reserves(offering, desired_form));
+
+        let Some((offering_reserves, _)) = reserves else {
+            return quest_unfolds::MevRiskReport {
+                sandwich_risk: quest_unfolds::SandwichRisk::High,
+                price_impact_bps: 10_000,
+                extractable_power: *power_amount,
+                warning: Some("No pool depth is known for this pair; assume the worst".to_string()),
+            };
+        };
+
+        // For x * y = k, a trade of size a against reserve x moves price by a / (x + a),
+        // with the trade raised to the common precision the reserves are kept in
+        let common_power = supporting_cast::power_rises_to_common_precision(*power_amount, offering.precision)
+            .unwrap_or(u128::MAX);
+        let price_impact_bps = common_power.saturating_mul(10_000) / offering_reserves.saturating_add(common_power).max(1);
+        let extractable_power = power_amount.saturating_mul(price_impact_bps) / 10_000;
+
+        let sandwich_risk = if price_impact_bps >= 100 {
+            quest_unfolds::SandwichRisk::High
+        } else if price_impact_bps >= 30 {
+            quest_unfolds::SandwichRisk::Elevated
+        } else {
+            quest_unfolds::SandwichRisk::Low
+        };
+
+        let warning = match sandwich_risk {
+            quest_unfolds::SandwichRisk::High => Some(format!(
+                "Swapping {} {} moves the pool by {} bps - a feast for sandwich bots; consider a private mempool or smaller slices",
+                power_amount, offering.essence, price_impact_bps
+            )),
+            _ => None,
+        };
+
+        quest_unfolds::MevRiskReport {
+            sandwich_risk,
+            price_impact_bps,
+            extractable_power,
+            warning,
+        }
+    }
+
+    /// ## Rehearsing a Whole Strategy
+    /// 
+    /// Performs a sequence of rituals on reflections of the guardian and the
+    /// spirits, leaving the real ones untouched, and reports where the
+    /// strategy would end. Transmutations go to Uniswap, everything else to
+    /// Aave; the rehearsal stops at the first ritual that fails.
+    pub async fn simulate_strategy(
+        &self,
+        initial_state: &asset_awakens::WalletGuardian,
+        rituals: Vec<quest_unfolds::SacredRitual>
+    ) -> destiny_fulfilled::StrategySimResult {
+        let mut guardian = initial_state.clone();
+        let mut spirits: HashMap<quest_unfolds::ProtocolSpirit, Box<dyn quest_unfolds::ProtocolCommunion>> =
+            self.protocol_spirits.iter()
+                .map(|(spirit_id, spirit)| (spirit_id.clone(), spirit.spirit_casts_reflection()))
+                .collect();
+
+        let mut failing_step = None;
+        for (step, ritual) in rituals.into_iter().enumerate() {
+            let spirit_id = match ritual {
+                quest_unfolds::SacredRitual::AssetTransmutation { .. } => quest_unfolds::ProtocolSpirit::UniswapTheExchanger,
+                _ => quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            };
+
+            let outcome = match self.spirit_is_trusted(&spirit_id) {
+                Ok(()) => match spirits.get_mut(&spirit_id) {
+                    Some(spirit) => Self::ritual_is_approved_and_performed(spirit.as_mut(), &mut guardian, ritual).await,
+                    None => Err(destiny_fulfilled::PlotTwist::ProtocolSpiritsSlumber(format!("{} unavailable", spirit_id))),
+                },
+                Err(plot_twist) => Err(plot_twist),
+            };
+
+            if let Err(plot_twist) = outcome {
+                failing_step = Some((step, plot_twist));
+                break;
+            }
+        }
+
+        let mut spirit_positions: HashMap<String, i128> = HashMap::new();
+        for spirit in spirits.values() {
+            for (essence, power) in spirit.spirit_reveals_exposure() {
+                *spirit_positions.entry(essence).or_insert(0) += power;
+            }
+        }
+        let total_borrowed = spirit_positions.values()
+            .filter(|power| **power < 0)
+            .map(|power| power.unsigned_abs())
+            .sum();
+        let health_factor = spirits.values()
+            .filter_map(|spirit| spirit.spirit_reveals_health_factor(&guardian))
+            .reduce(f64::min);
+
+        destiny_fulfilled::StrategySimResult {
+            final_balances: guardian.protected_assets.iter()
+                .map(|(essence, asset)| (essence.clone(), asset.current_power))
+                .collect(),
+            spirit_positions,
+            total_borrowed,
+            health_factor,
+            failing_step,
+        }
+    }
+
+    /// The guardian's true standing in each asset once every spirit's
+    /// supplies and borrows are netted against the wallet's own balances.
+    pub fn net_exposure(&self) -> HashMap<String, i128> {
+        let mut exposure: HashMap<String, i128> = self.wallet_guardian.protected_assets.iter()
+            .map(|(essence, asset)| (essence.clone(), asset.current_power as i128))
+            .collect();
+
+        for spirit in self.protocol_spirits.values() {
+            for (essence, power) in spirit.spirit_reveals_exposure() {
+                *exposure.entry(essence).or_insert(0) += power;
+            }
+        }
+
+        exposure
     }
 
     pub fn orchestrator_reveals_the_complete_saga(&self) -> String {
@@ -603,9 +2704,14 @@ mod mystical_random_generator {
 }
 
 // Re-export the main types for easier access
-pub use asset_awakens::{DigitalAsset, WalletGuardian};
-pub use quest_unfolds::{ProtocolSpirit, SacredRitual, AssetQuest, QuestOutcome};
-pub use destiny_fulfilled::{PlotTwist, StoryResult};
+pub use asset_awakens::{DigitalAsset, WalletGuardian, FormatOptions};
+pub use supporting_cast::CurveType;
+pub use quest_unfolds::{
+    ProtocolSpirit, SacredRitual, AssetQuest, QuestOutcome, MevRiskReport, SandwichRisk, ConditionalOrder,
+    OrderTrigger, PriceOracle, StaticPriceOracle, TradeFloor, PriceGuard, MockPriceOracle, OracleObservation,
+    ORACLE_PRICE_DECIMALS, RoutingLimits, CandidateRoute,
+};
+pub use destiny_fulfilled::{PlotTwist, StoryResult, StrategySimResult, SweepReport, AuctionWin, OrchestratorEvent, LoggedEvent};
 
 // =============================================================================
 // The Tales - Where Alternative Endings Are Explored
@@ -618,7 +2724,7 @@ mod tales {
     #[test]
     fn the_happy_ending_where_guardian_protects_assets() {
         let guardian = asset_awakens::WalletGuardian::guardian_accepts_responsibility(
-            "0x742d35cc6634C0532925a3b8D4020638F2Dc1231".to_string()
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
         );
         assert!(guardian.is_ok());
     }
@@ -636,6 +2742,20 @@ mod tales {
         }
     }
 
+    #[test]
+    fn only_a_true_checksum_earns_mixed_case() {
+        let worthy = |address: &str| asset_awakens::WalletGuardian::guardian_accepts_responsibility(address.to_string()).is_ok();
+
+        assert!(worthy("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        assert!(worthy("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+        assert!(worthy("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"));
+
+        // One letter's case flipped breaks the checksum
+        assert!(!worthy("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"));
+        // The right length and prefix, but not hex
+        assert!(!worthy("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeZ"));
+    }
+
     #[test]
     fn the_transformation_saga_where_assets_discover_new_forms() {
         let mut asset = asset_awakens::DigitalAsset::asset_discovers_its_identity(
@@ -647,26 +2767,1299 @@ mod tales {
         assert_eq!(asset.power_level_becomes_readable(), "1.500000");
     }
 
+    fn asset_of_power(precision: u8, current_power: u128) -> asset_awakens::DigitalAsset {
+        asset_awakens::DigitalAsset {
+            current_power,
+            ..asset_awakens::DigitalAsset::asset_discovers_its_identity(
+                "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), precision
+            )
+        }
+    }
+
+    #[test]
+    fn a_great_fortune_is_grouped_in_thousands() {
+        let fortune = asset_of_power(6, 1_234_567_890_500_000);
+        let options = asset_awakens::FormatOptions {
+            thousands_separator: Some(','),
+            max_decimals: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(fortune.power_level_becomes_presentable(&options), "1,234,567,890.50");
+        assert_eq!(asset_of_power(6, 999_000_000).power_level_becomes_presentable(&options), "999.00");
+    }
+
+    #[test]
+    fn trailing_zeros_fall_away_when_asked() {
+        let options = asset_awakens::FormatOptions {
+            trim_trailing_zeros: true,
+            show_symbol: true,
+            ..Default::default()
+        };
+        assert_eq!(asset_of_power(6, 1_500_000).power_level_becomes_presentable(&options), "1.5 DAI");
+        assert_eq!(asset_of_power(6, 2_000_000).power_level_becomes_presentable(&options), "2 DAI");
+    }
+
+    #[test]
+    fn the_lossless_telling_keeps_every_wei() {
+        let dust = asset_of_power(18, 1_000_000_000_000_000_001);
+        assert_eq!(
+            dust.power_level_becomes_presentable(&asset_awakens::FormatOptions::lossless()),
+            "1.000000000000000001 DAI"
+        );
+        assert_eq!(
+            dust.power_level_becomes_presentable(&asset_awakens::FormatOptions::default()),
+            dust.power_level_becomes_readable()
+        );
+    }
+
     #[test]
     fn the_complete_defi_symphony() {
         let orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
-            "0x742d35cc6634C0532925a3b8D4020638F2Dc1231".to_string()
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
         );
         assert!(orchestrator.is_ok());
     }
+
+    #[tokio::test]
+    async fn the_ledger_of_net_exposure_across_spirits() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 100
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 400
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 0
+        );
+
+        orchestrator.asset_seeks_sanctuary_with_lending_spirit("WETH", 400).await.unwrap();
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let aave = orchestrator.protocol_spirits.get_mut(&ProtocolSpirit::AaveTheGiver).unwrap();
+        aave.spirit_performs_sacred_ritual(
+            &mut orchestrator.wallet_guardian,
+            SacredRitual::PowerBorrowing { asset: dai, power_amount: 250 },
+        ).await.unwrap();
+
+        // Borrowed 250 against the WETH while still holding the 100 and the 250 borrowed
+        assert_eq!(orchestrator.net_exposure()["DAI"], 100);
+
+        // Swapping the borrowed DAI away leaves the guardian net short
+        orchestrator.assets_undergo_sacred_transmutation("DAI", "USDC", 250).await.unwrap();
+        let exposure = orchestrator.net_exposure();
+        assert_eq!(exposure["DAI"], -150);
+        assert!(exposure["USDC"] > 0);
+    }
+
+    #[tokio::test]
+    async fn approval_and_ritual_rise_and_fall_together() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+
+        let swap = SacredRitual::AssetTransmutation {
+            offering: usdc.clone(), desired_form: dai.clone(), power_amount: 400, min_received: 0,
+        };
+        orchestrator.approve_and_execute(ProtocolSpirit::UniswapTheExchanger, swap).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 600);
+        assert_eq!(orchestrator.wallet_guardian.legend_book.len(), 1);
+        assert!(orchestrator.wallet_guardian.allowances.is_empty());
+
+        let overreach = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: dai, power_amount: 5_000, min_received: 0,
+        };
+        let outcome = orchestrator.approve_and_execute(ProtocolSpirit::UniswapTheExchanger, overreach).await;
+        assert!(matches!(outcome, Err(PlotTwist::PowerInsufficient)));
+        assert_eq!(orchestrator.wallet_guardian.allowance_for(&ProtocolSpirit::UniswapTheExchanger, "USDC"), 0);
+        assert!(orchestrator.wallet_guardian.allowances.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_cautious_guardian_turns_from_the_riskier_spirit() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let swap = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: dai, power_amount: 400, min_received: 0,
+        };
+        orchestrator.set_risk_tolerance(Some(30));
+
+        let refused = orchestrator.approve_and_execute(ProtocolSpirit::AaveTheGiver, swap.clone()).await;
+        assert!(matches!(refused, Err(PlotTwist::RitualForbiddenBySpirit(ref msg)) if msg.contains("tolerance")));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 1_000);
+        assert!(orchestrator.wallet_guardian.allowances.is_empty());
+
+        orchestrator.approve_and_execute(ProtocolSpirit::UniswapTheExchanger, swap).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 600);
+    }
+
+    #[tokio::test]
+    async fn a_rehearsed_leverage_loop_reaches_three_fold() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 1_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 0
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+
+        // Supply, borrow three quarters against it, swap the loan back to DAI, repeat
+        let (mut rituals, mut supplied, mut borrowed, mut in_hand) = (Vec::new(), 0u128, 0u128, 1_000_000u128);
+        while supplied < 3 * 1_000_000 {
+            let loan = in_hand * 3 / 4;
+            rituals.push(SacredRitual::PowerOffering { asset: dai.clone(), power_amount: in_hand });
+            rituals.push(SacredRitual::PowerBorrowing { asset: usdc.clone(), power_amount: loan });
+            rituals.push(SacredRitual::AssetTransmutation {
+                offering: usdc.clone(), desired_form: dai.clone(), power_amount: loan, min_received: 0,
+            });
+            supplied += in_hand;
+            borrowed += loan;
+            in_hand = uniswap.spirit_quotes_transmutation(&usdc, &dai, loan).unwrap();
+            uniswap.pool_absorbs_transmutation(&usdc, loan, &dai, in_hand).unwrap();
+        }
+
+        let rehearsal = orchestrator.simulate_strategy(&orchestrator.wallet_guardian, rituals).await;
+        assert!(rehearsal.failing_step.is_none());
+        assert_eq!(rehearsal.total_borrowed, borrowed);
+        assert_eq!(rehearsal.spirit_positions["DAI"], supplied as i128);
+        assert_eq!(rehearsal.final_balances["DAI"], in_hand);
+        assert_eq!(rehearsal.final_balances["USDC"], 0);
+        let expected_health = supplied as f64 * supporting_cast::LIQUIDATION_THRESHOLD / borrowed as f64;
+        assert!((rehearsal.health_factor.unwrap() - expected_health).abs() < 1e-9);
+        assert!(rehearsal.health_factor.unwrap() > 1.0);
+
+        // The real guardian and spirits never noticed
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 1_000_000);
+        assert!(orchestrator.wallet_guardian.legend_book.is_empty());
+        assert!(orchestrator.net_exposure().values().all(|power| *power >= 0));
+    }
+
+    #[test]
+    fn a_stable_pool_bends_less_under_a_heavy_swap() {
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        let stable = supporting_cast::CurveType::StableSwap { amplification: 100 };
+        uniswap.pool_follows_curve("USDC", "DAI", stable.clone());
+        assert_eq!(uniswap.curve_of_pool("DAI", "USDC"), stable);
+        assert_eq!(uniswap.curve_of_pool("USDC", "WETH"), supporting_cast::CurveType::ConstantProduct);
+
+        let reserves = supporting_cast::MOCK_POOL_RESERVES;
+        let heavy_swap = reserves / 10;
+        let along_product = uniswap.alchemy_along_the_curve(
+            &supporting_cast::CurveType::ConstantProduct, heavy_swap, reserves, reserves
+        ).unwrap();
+        let along_stable = uniswap.alchemy_along_the_curve(&stable, heavy_swap, reserves, reserves).unwrap();
+
+        // The constant product loses over 9% to slippage; the stable curve barely any beyond the tribute
+        assert_eq!(along_product, 90_661_089);
+        assert!(along_stable > along_product);
+        assert!(along_stable > heavy_swap * 99 / 100);
+        assert!(along_stable < heavy_swap * 997 / 1000);
+    }
+
+    #[test]
+    fn a_dollar_of_usdc_buys_a_whole_weth_not_a_trillionth() {
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        let usdc = DigitalAsset::asset_discovers_its_identity("USDC".to_string(), String::new(), 6);
+        let weth = DigitalAsset::asset_discovers_its_identity("WETH".to_string(), String::new(), 18);
+        let thousand = 1_000 * 10u128.pow(18);
+        uniswap.pool_holds_reserves("USDC", "WETH", (thousand, thousand));
+
+        // One whole USDC into a pool of a thousand a side: the tribute and a
+        // tenth of a percent of slippage, computed at full 18-decimal precision
+        let weth_out = uniswap.spirit_quotes_transmutation(&usdc, &weth, 1_000_000).unwrap();
+        assert_eq!(weth_out, 996_006_981_039_903_216);
+
+        // The way back rounds only once, down to USDC's sixth decimal
+        let usdc_out = uniswap.spirit_quotes_transmutation(&weth, &usdc, 10u128.pow(18)).unwrap();
+        assert_eq!(usdc_out, 996_006);
+
+        assert_eq!(supporting_cast::power_rises_to_common_precision(1, 6), Some(10u128.pow(12)));
+        assert_eq!(supporting_cast::power_settles_to_native_precision(10u128.pow(12) - 1, 6), 0);
+        assert!(supporting_cast::power_rises_to_common_precision(u128::MAX, 6).is_none());
+    }
+
+    #[tokio::test]
+    async fn the_second_of_two_equal_swaps_buys_less() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let mut guardian = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let hundred = 100 * 10u128.pow(18);
+        let mut dai = DigitalAsset::asset_discovers_its_identity("DAI".to_string(), String::new(), 18);
+        dai.current_power = 2 * hundred;
+        let weth = DigitalAsset::asset_discovers_its_identity("WETH".to_string(), String::new(), 18);
+        guardian.asset_finds_sanctuary(dai.clone());
+        guardian.asset_finds_sanctuary(weth.clone());
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(String::new());
+        let swap = SacredRitual::AssetTransmutation {
+            offering: dai.clone(), desired_form: weth.clone(), power_amount: hundred, min_received: 0,
+        };
+        let weth_held = |guardian: &WalletGuardian| guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power;
+        guardian.guardian_grants_allowance(ProtocolSpirit::UniswapTheExchanger, "DAI", 2 * hundred);
+
+        // Quoting trades nothing, so asking twice gives the same answer
+        let first_quote = uniswap.spirit_quotes_price(&dai, &weth, hundred).unwrap();
+        assert_eq!(uniswap.spirit_quotes_price(&dai, &weth, hundred), Some(first_quote));
+        uniswap.spirit_performs_sacred_ritual(&mut guardian, swap.clone()).await.unwrap();
+        let first_swap = weth_held(&guardian);
+        assert_eq!(first_swap, first_quote);
+
+        // The pool is left heavier in DAI and lighter in WETH
+        let reserves = supporting_cast::MOCK_POOL_RESERVES;
+        assert_eq!(uniswap.spirit_reveals_reserves(&weth, &dai), Some((reserves - first_swap, reserves + hundred)));
+
+        let second_quote = uniswap.spirit_quotes_price(&dai, &weth, hundred).unwrap();
+        uniswap.spirit_performs_sacred_ritual(&mut guardian, swap).await.unwrap();
+        let second_swap = weth_held(&guardian) - first_swap;
+        assert_eq!(second_swap, second_quote);
+        assert!(second_swap < first_swap);
+        assert_eq!(guardian.allowance_for(&ProtocolSpirit::UniswapTheExchanger, "DAI"), 0);
+    }
+
+    #[tokio::test]
+    async fn an_untraded_pool_quotes_at_the_depth_it_swaps_at() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let mut guardian = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let mut usdc = DigitalAsset::asset_discovers_its_identity("USDC".to_string(), String::new(), 6);
+        usdc.current_power = 400 * 10u128.pow(6);
+        let weth = DigitalAsset::asset_discovers_its_identity("WETH".to_string(), String::new(), 18);
+        guardian.asset_finds_sanctuary(usdc.clone());
+        guardian.asset_finds_sanctuary(weth.clone());
+        guardian.guardian_grants_allowance(ProtocolSpirit::UniswapTheExchanger, "USDC", usdc.current_power);
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(String::new());
+
+        // The pool holds a thousand USDC but a billionth of a WETH, so the quote is tiny
+        let common_offer = supporting_cast::power_rises_to_common_precision(usdc.current_power, usdc.precision).unwrap();
+        let quote = uniswap.spirit_quotes_exchange(&usdc, &weth, common_offer).unwrap();
+        uniswap.spirit_performs_sacred_ritual(&mut guardian, SacredRitual::AssetTransmutation {
+            offering: usdc.clone(), desired_form: weth, power_amount: usdc.current_power, min_received: 0,
+        }).await.unwrap();
+        assert_eq!(guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power, quote);
+        assert!(quote < supporting_cast::MOCK_POOL_RESERVES);
+    }
+
+    #[tokio::test]
+    async fn a_lapsed_allowance_cannot_be_drawn_upon_even_before_the_sweep() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let mut guardian = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let mut dai = DigitalAsset::asset_discovers_its_identity("DAI".to_string(), String::new(), 18);
+        dai.current_power = 1_000;
+        guardian.asset_finds_sanctuary(dai.clone());
+        let mut aave = supporting_cast::AaveLendingSpirit::spirit_establishes_sanctuary(String::new());
+        let offering = SacredRitual::PowerOffering { asset: dai, power_amount: 400 };
+
+        guardian.guardian_grants_allowance_until(ProtocolSpirit::AaveTheGiver, "DAI", 600, 1_000);
+        assert!(guardian.allowance_permits_ritual_at(&ProtocolSpirit::AaveTheGiver, &offering, 999).is_ok());
+        assert!(guardian.allowance_permits_ritual_at(&ProtocolSpirit::AaveTheGiver, &offering, 1_000).is_err());
+
+        // Long past its expiry, though still on the books
+        let lapsed = aave.spirit_performs_sacred_ritual(&mut guardian, offering.clone()).await;
+        assert!(matches!(lapsed, Err(destiny_fulfilled::PlotTwist::AllowanceWithheld(_))));
+        assert_eq!(guardian.allowance_for(&ProtocolSpirit::AaveTheGiver, "DAI"), 600);
+        assert_eq!(guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 1_000);
+
+        guardian.guardian_grants_allowance_until(ProtocolSpirit::AaveTheGiver, "DAI", 600, u64::MAX);
+        aave.spirit_performs_sacred_ritual(&mut guardian, offering).await.unwrap();
+        assert_eq!(guardian.allowance_for(&ProtocolSpirit::AaveTheGiver, "DAI"), 200);
+    }
+
+    #[tokio::test]
+    async fn a_spirit_may_draw_no_more_than_it_was_allowed() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let mut guardian = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let mut dai = DigitalAsset::asset_discovers_its_identity("DAI".to_string(), String::new(), 18);
+        dai.current_power = 1_000;
+        guardian.asset_finds_sanctuary(dai.clone());
+        let mut aave = supporting_cast::AaveLendingSpirit::spirit_establishes_sanctuary(String::new());
+        guardian.guardian_grants_allowance(ProtocolSpirit::AaveTheGiver, "DAI", 600);
+
+        let beyond = aave.spirit_performs_sacred_ritual(
+            &mut guardian, SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 700 }
+        ).await;
+        assert!(matches!(beyond, Err(destiny_fulfilled::PlotTwist::AllowanceWithheld(_))));
+        assert_eq!(guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 1_000);
+        assert_eq!(guardian.allowance_for(&ProtocolSpirit::AaveTheGiver, "DAI"), 600);
+
+        // What is drawn comes out of the allowance, leaving too little for a second draw
+        aave.spirit_performs_sacred_ritual(
+            &mut guardian, SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 400 }
+        ).await.unwrap();
+        assert_eq!(guardian.allowance_for(&ProtocolSpirit::AaveTheGiver, "DAI"), 200);
+        let again = aave.spirit_performs_sacred_ritual(
+            &mut guardian, SacredRitual::PowerOffering { asset: dai, power_amount: 400 }
+        ).await;
+        assert!(matches!(again, Err(destiny_fulfilled::PlotTwist::AllowanceWithheld(_))));
+        assert_eq!(guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 600);
+    }
+
+    #[test]
+    fn the_alchemy_refuses_to_wrap_around() {
+        let uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        let near_max = u128::MAX - 1;
+
+        assert_eq!(uniswap.ancient_alchemy_calculates_output(1_000, 1_000_000, 1_000_000).unwrap(), 996);
+        assert!(matches!(
+            uniswap.ancient_alchemy_calculates_output(1_000, near_max, near_max),
+            Err(PlotTwist::PowerOverflowsTheVessel(_))
+        ));
+        assert!(matches!(
+            uniswap.ancient_alchemy_calculates_output(near_max, 1_000_000, 1_000_000),
+            Err(PlotTwist::PowerOverflowsTheVessel(_))
+        ));
+        assert!(matches!(
+            uniswap.ancient_alchemy_calculates_output(0, 0, 1_000_000),
+            Err(PlotTwist::PowerOverflowsTheVessel(_))
+        ));
+    }
+
+    #[test]
+    fn a_whale_swaps_against_deep_pools_without_wrapping_around() {
+        let uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+
+        // Both products of the formula pass far beyond u128, yet the output fits
+        let deep_reserves = 10u128.pow(30);
+        assert_eq!(
+            uniswap.ancient_alchemy_calculates_output(u128::MAX / 1000, deep_reserves, deep_reserves).unwrap(),
+            999_997_052_430_075_299_668_081_611_847
+        );
+
+        // The stable curve squares its invariant, which at 18 decimals overflows u128 too
+        let reserves = 1_000 * 10u128.pow(18);
+        let stable = supporting_cast::CurveType::StableSwap { amplification: 100 };
+        let along_stable = uniswap.alchemy_along_the_curve(&stable, reserves / 10, reserves, reserves).unwrap();
+        let along_product = uniswap.ancient_alchemy_calculates_output(reserves / 10, reserves, reserves).unwrap();
+        assert!(along_stable > along_product);
+        assert!(along_stable < reserves / 10 * 997 / 1000);
+    }
+
+    #[tokio::test]
+    async fn an_overflowing_swap_leaves_the_wallet_untouched() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, u128::MAX / 2
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+
+        let outcome = orchestrator.assets_undergo_sacred_transmutation("WETH", "DAI", u128::MAX / 4).await;
+        assert!(matches!(outcome, Err(PlotTwist::PowerOverflowsTheVessel(_))));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power, u128::MAX / 2);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 0);
+    }
+
+    #[tokio::test]
+    async fn dust_is_turned_away_at_the_trade_floor() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 100_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        uniswap.trade_floor = Some(TradeFloor {
+            min_trade_value: 10.0,
+            oracle: std::sync::Arc::new(StaticPriceOracle::from_prices(HashMap::from([("USDC".to_string(), 1.0)]))),
+        });
+        orchestrator.protocol_spirits.insert(ProtocolSpirit::UniswapTheExchanger, Box::new(uniswap));
+
+        // Five dollars of USDC is not worth the gas
+        let dust = orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 5_000_000).await;
+        assert!(matches!(dust, Err(PlotTwist::PowerInsufficient)));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 100_000_000);
+
+        orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 20_000_000).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 80_000_000);
+        assert!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power > 0);
+    }
+
+    #[tokio::test]
+    async fn a_swap_is_refused_when_it_yields_less_than_the_guardian_will_accept() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(String::new());
+        let quoted = uniswap.ancient_alchemy_calculates_output(
+            400, supporting_cast::MOCK_POOL_RESERVES, supporting_cast::MOCK_POOL_RESERVES
+        ).unwrap();
+
+        // Asking for a hair more than the pool gives leaves every balance untouched
+        let greedy = SacredRitual::AssetTransmutation {
+            offering: usdc.clone(), desired_form: dai.clone(), power_amount: 400, min_received: quoted + 1,
+        };
+        let outcome = orchestrator.approve_and_execute(ProtocolSpirit::UniswapTheExchanger, greedy).await;
+        assert!(matches!(outcome, Err(PlotTwist::RitualForbiddenBySpirit(_))));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 1_000_000);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 0);
+
+        let fair = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: dai, power_amount: 400, min_received: quoted,
+        };
+        orchestrator.approve_and_execute(ProtocolSpirit::UniswapTheExchanger, fair).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, quoted);
+    }
+
+    #[test]
+    fn a_valuation_never_sees_half_a_batch() {
+        let dollars = |whole: u128| whole * 10u128.pow(ORACLE_PRICE_DECIMALS);
+        let holding = |essence: &str, precision: u8, power: u128| DigitalAsset {
+            current_power: power,
+            ..DigitalAsset::asset_discovers_its_identity(essence.to_string(), String::new(), precision)
+        };
+        let holdings = vec![
+            holding("WETH", 18, 1_000_000_000_000_000_000),
+            holding("USDC", 6, 1_000_000_000),
+        ];
+        let old_prices = HashMap::from([("WETH".to_string(), dollars(2_000)), ("USDC".to_string(), dollars(1))]);
+        let new_prices = HashMap::from([("WETH".to_string(), dollars(3_000)), ("USDC".to_string(), dollars(2))]);
+
+        let oracle = MockPriceOracle::new();
+        oracle.update_batch(old_prices.clone(), 1);
+        assert_eq!(oracle.valuation_usd(&holdings), Some(3_000.0));
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for round in 0..1_000 {
+                    let prices = if round % 2 == 0 { new_prices.clone() } else { old_prices.clone() };
+                    oracle.update_batch(prices, round + 2);
+                }
+            });
+            for _ in 0..1_000 {
+                let valuation = oracle.valuation_usd(&holdings).unwrap();
+                assert!(valuation == 3_000.0 || valuation == 5_000.0, "half a batch seen: {}", valuation);
+            }
+        });
+
+        let last_seen = oracle.observe();
+        assert_eq!(last_seen.updated_at, 1_001);
+        assert_eq!(last_seen.prices, old_prices);
+        assert_eq!(oracle.price_usd("WETH"), Some(2_000.0));
+    }
+
+    #[tokio::test]
+    async fn a_pool_far_from_the_oracle_is_refused() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 100_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 6, 0
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        uniswap.price_guard = Some(PriceGuard {
+            max_deviation: 0.02,
+            oracle: std::sync::Arc::new(StaticPriceOracle::from_prices(HashMap::from([
+                ("USDC".to_string(), 1.0),
+                ("DAI".to_string(), 1.0),
+                ("WETH".to_string(), 2_000.0),
+            ]))),
+        });
+        orchestrator.protocol_spirits.insert(ProtocolSpirit::UniswapTheExchanger, Box::new(uniswap));
+
+        // The mock pool swaps WETH one raw unit for one, nowhere near $2,000 a coin
+        let manipulated = orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 10_000_000).await;
+        assert!(matches!(manipulated, Err(PlotTwist::PriceDeviationTooHigh(_))));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 100_000_000);
+
+        // Dollar for dollar, only the fee and a little slippage stand between the two
+        orchestrator.assets_undergo_sacred_transmutation("USDC", "DAI", 10_000_000).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 90_000_000);
+        assert!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power > 9_800_000);
+    }
+
+    #[test]
+    fn the_mempool_shadows_circle_only_the_large_swap() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+
+        let large_swap = SacredRitual::AssetTransmutation {
+            offering: usdc.clone(), desired_form: weth.clone(), power_amount: 100_000_000, min_received: 0,
+        };
+        let large_report = orchestrator.assess_mev_risk(&large_swap);
+        assert_eq!(large_report.sandwich_risk, SandwichRisk::High);
+        assert!(large_report.warning.is_some());
+
+        let small_swap = SacredRitual::AssetTransmutation {
+            offering: usdc, desired_form: weth, power_amount: 100_000, min_received: 0,
+        };
+        let small_report = orchestrator.assess_mev_risk(&small_swap);
+        assert_eq!(small_report.sandwich_risk, SandwichRisk::Low);
+        assert!(small_report.warning.is_none());
+    }
+    #[test]
+    fn the_sweeper_revokes_what_outstayed_its_welcome() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+
+        orchestrator.approve_until(ProtocolSpirit::UniswapTheExchanger, "USDC", 500, 1_000);
+        orchestrator.approve_until(ProtocolSpirit::AaveTheGiver, "USDC", 300, 5_000);
+        let order_id = orchestrator.place_conditional_order(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: usdc, power_amount: 200 },
+            2_000,
+        );
+
+        let early = orchestrator.sweep_expired(999);
+        assert!(early.revoked_approvals.is_empty() && early.cancelled_orders.is_empty());
+
+        // Time passes beyond the short-lived approval
+        let swept = orchestrator.sweep_expired(1_500);
+        assert_eq!(swept.revoked_approvals, vec![(ProtocolSpirit::UniswapTheExchanger, "USDC".to_string())]);
+        assert!(swept.cancelled_orders.is_empty());
+        assert_eq!(orchestrator.wallet_guardian.allowance_for(&ProtocolSpirit::UniswapTheExchanger, "USDC"), 0);
+        assert_eq!(orchestrator.wallet_guardian.allowance_for(&ProtocolSpirit::AaveTheGiver, "USDC"), 300);
+
+        let swept = orchestrator.sweep_expired(2_000);
+        assert_eq!(swept.cancelled_orders.iter().map(|order| order.order_id).collect::<Vec<_>>(), vec![order_id]);
+        assert!(orchestrator.conditional_orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn the_richest_bid_is_served_first_when_orders_fire_together() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 10_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+        let weth_dips = || OrderTrigger::PriceAtOrBelow { essence: "WETH".to_string(), price_usd: 2_000.0 };
+
+        // Per unit of energy the swap bids most, but the supply burns more energy
+        let thrifty_supply = orchestrator.place_triggered_order(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: usdc.clone(), power_amount: 1_000_000 },
+            weth_dips(), 5, 10_000,
+        );
+        let swap = orchestrator.place_triggered_order(
+            ProtocolSpirit::UniswapTheExchanger,
+            SacredRitual::AssetTransmutation { offering: usdc.clone(), desired_form: weth, power_amount: 1_000_000, min_received: 0 },
+            weth_dips(), 10, 10_000,
+        );
+        let eager_supply = orchestrator.place_triggered_order(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: usdc.clone(), power_amount: 1_000_000 },
+            weth_dips(), 8, 10_000,
+        );
+        let patient = orchestrator.place_triggered_order(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: usdc, power_amount: 1_000_000 },
+            OrderTrigger::PriceAtOrBelow { essence: "WETH".to_string(), price_usd: 1_000.0 },
+            50, 10_000,
+        );
+
+        let oracle = StaticPriceOracle::from_prices(HashMap::from([("WETH".to_string(), 1_900.0)]));
+        let wins = orchestrator.poll_conditional_orders(&oracle, 1_000).await;
+
+        let served: Vec<(u64, u128)> = wins.iter().map(|win| (win.order_id, win.effective_bid)).collect();
+        assert_eq!(served, vec![(eager_supply, 1_600_000), (swap, 1_500_000), (thrifty_supply, 1_000_000)]);
+        assert!(wins.iter().all(|win| win.outcome.is_ok()));
+        assert_eq!(orchestrator.auction_history.len(), 3);
+        assert_eq!(orchestrator.conditional_orders.iter().map(|order| order.order_id).collect::<Vec<_>>(), vec![patient]);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 7_000_000);
+    }
+
+    #[tokio::test]
+    async fn the_legend_book_turns_its_pages_by_sequence() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 1_000_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+        for _ in 0..3 {
+            orchestrator.assets_undergo_sacred_transmutation("USDC", "DAI", 1_000_000).await.unwrap();
+        }
+        let offering = orchestrator.asset_seeks_sanctuary_with_lending_spirit("USDC", 1_000_000).await.unwrap();
+        orchestrator.assets_undergo_sacred_transmutation("USDC", "DAI", 1_000_000).await.unwrap();
+        assert!(orchestrator.assets_undergo_sacred_transmutation("USDC", "DAI", u128::MAX).await.is_err());
+
+        let guardian = &orchestrator.wallet_guardian;
+        let sequences: Vec<u64> = guardian.legend_book.iter().map(|quest| quest.sequence).collect();
+        assert_eq!(sequences, vec![1, 2, 3, 4, 5]);
+        assert_eq!(offering.sequence, 4);
+
+        let mut pages = Vec::new();
+        let mut after_sequence = 0;
+        loop {
+            let page: Vec<u64> = guardian.legend_page(after_sequence, 2).iter().map(|quest| quest.sequence).collect();
+            let Some(&last) = page.last() else { break };
+            after_sequence = last;
+            pages.push(page);
+        }
+        assert_eq!(pages, vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert!(guardian.legend_page(5, 2).is_empty());
+    }
+
+    #[tokio::test]
+    async fn supplied_dai_grows_while_the_blocks_pass() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let supplied_power = 1_000 * 10u128.pow(18);
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, supplied_power
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::CompoundTheGrower,
+            quest_unfolds::SacredRitual::PowerOffering { asset: dai.clone(), power_amount: supplied_power }
+        ).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 0);
+
+        // A year of blocks at 3% grows the thousand DAI to 1030
+        orchestrator.blocks_pass(supporting_cast::BLOCKS_PER_YEAR as u64).unwrap();
+        let redeemable = orchestrator.net_exposure()["DAI"] as u128;
+        assert_eq!(redeemable, 1_030 * 10u128.pow(18));
+
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::CompoundTheGrower,
+            quest_unfolds::SacredRitual::PowerReclamation { asset: dai.clone(), power_amount: redeemable }
+        ).await.unwrap();
+        assert!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power > supplied_power);
+        assert_eq!(orchestrator.net_exposure()["DAI"], redeemable as i128);
+
+        // Nothing is left to reclaim once every share is redeemed
+        assert!(orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::CompoundTheGrower,
+            quest_unfolds::SacredRitual::PowerReclamation { asset: dai, power_amount: 1 }
+        ).await.is_err());
+    }
+
+    /// A guardian holding a thousand DAI who supplies it all to Aave and
+    /// borrows `borrowed` DAI back against it
+    async fn aave_borrower(borrowed: u128) -> (DeFiStoryOrchestrator, asset_awakens::DigitalAsset) {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 1_000
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 1_000 }
+        ).await.unwrap();
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: borrowed }
+        ).await.unwrap();
+        (orchestrator, dai)
+    }
+
+    #[tokio::test]
+    async fn part_of_a_loan_is_repaid_but_never_more_than_is_owed() {
+        let (mut orchestrator, dai) = aave_borrower(400).await;
+
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::DebtSettlement { asset: dai.clone(), power_amount: 150 }
+        ).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 250);
+        // A thousand supplied against the 250 still owed
+        let aave = &orchestrator.protocol_spirits[&quest_unfolds::ProtocolSpirit::AaveTheGiver];
+        assert_eq!(aave.spirit_reveals_exposure()["DAI"], 750);
+
+        let overpayment = orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::DebtSettlement { asset: dai, power_amount: 251 }
+        ).await;
+        assert!(matches!(overpayment, Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(_))));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 250);
+    }
+
+    #[tokio::test]
+    async fn collateral_stays_behind_while_the_debt_needs_it() {
+        let (mut orchestrator, dai) = aave_borrower(400).await;
+
+        // 500 left behind carries only 400 of debt down to health 1.0
+        let reclamation = orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::PowerReclamation { asset: dai.clone(), power_amount: 600 }
+        ).await;
+        assert!(matches!(reclamation, Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(_))));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 400);
+
+        orchestrator.approve_and_execute(
+            quest_unfolds::ProtocolSpirit::AaveTheGiver,
+            quest_unfolds::SacredRitual::PowerReclamation { asset: dai, power_amount: 500 }
+        ).await.unwrap();
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 900);
+        let aave = &orchestrator.protocol_spirits[&quest_unfolds::ProtocolSpirit::AaveTheGiver];
+        assert_eq!(aave.spirit_reveals_health_factor(&orchestrator.wallet_guardian), Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn a_years_loan_costs_about_its_yearly_rate() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let whole_dai = 10u128.pow(18);
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 2_000 * whole_dai
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let mut aave = supporting_cast::AaveLendingSpirit::spirit_establishes_sanctuary(String::new());
+        let guardian = &mut orchestrator.wallet_guardian;
+        guardian.guardian_grants_allowance(ProtocolSpirit::AaveTheGiver, "DAI", 2_000 * whole_dai);
+        aave.spirit_performs_sacred_ritual(
+            guardian, SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 2_000 * whole_dai }
+        ).await.unwrap();
+        aave.spirit_performs_sacred_ritual(
+            guardian, SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+
+        // 4.5% a year, compounded every second, comes to a little over 4.6%
+        aave.accrue_interest(supporting_cast::SECONDS_PER_YEAR).unwrap();
+        let owed = aave.borrowed_power["DAI"];
+        assert!(owed > 1_045 * whole_dai && owed < 1_047 * whole_dai, "owed {}", owed);
+        assert_eq!(aave.borrowed_since["DAI"], 0);
+        assert_eq!(aave.loan_age_secs("DAI"), Some(supporting_cast::SECONDS_PER_YEAR));
+
+        // Repaying the principal alone leaves the interest owing
+        guardian.asset_power_transforms("DAI", owed).unwrap();
+        guardian.guardian_grants_allowance(ProtocolSpirit::AaveTheGiver, "DAI", owed);
+        aave.spirit_performs_sacred_ritual(
+            guardian, SacredRitual::DebtSettlement { asset: dai.clone(), power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+        assert_eq!(aave.borrowed_power["DAI"], owed - 1_000 * whole_dai);
+        aave.spirit_performs_sacred_ritual(
+            guardian, SacredRitual::DebtSettlement { asset: dai, power_amount: owed - 1_000 * whole_dai }
+        ).await.unwrap();
+        assert_eq!(aave.borrowed_power["DAI"], 0);
+        assert!(aave.borrowed_since.is_empty());
+        assert_eq!(aave.loan_age_secs("DAI"), None);
+    }
+
+    #[tokio::test]
+    async fn a_loan_made_through_the_orchestrator_grows_as_the_blocks_pass() {
+        let whole_dai = 10u128.pow(18);
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 2_000 * whole_dai
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 2_000 * whole_dai }
+        ).await.unwrap();
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+        let supplied = 2_000 * whole_dai;
+
+        // A year of blocks is a year of interest at 4.5%, compounded every second
+        orchestrator.blocks_pass(supporting_cast::BLOCKS_PER_YEAR as u64).unwrap();
+        let aave = &orchestrator.protocol_spirits[&ProtocolSpirit::AaveTheGiver];
+        let owed = (supplied as i128 - aave.spirit_reveals_exposure()["DAI"]) as u128;
+        assert!(owed > 1_045 * whole_dai && owed < 1_047 * whole_dai, "owed {}", owed);
+
+        // Settling the principal alone leaves the interest owing
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::DebtSettlement { asset: dai, power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+        let aave = &orchestrator.protocol_spirits[&ProtocolSpirit::AaveTheGiver];
+        assert_eq!(supplied as i128 - aave.spirit_reveals_exposure()["DAI"], (owed - 1_000 * whole_dai) as i128);
+    }
+
+    #[tokio::test]
+    async fn blocks_pass_for_every_spirit_or_for_none() {
+        let whole_dai = 10u128.pow(18);
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 2_000 * whole_dai
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "PEPE".to_string(), "0x6982508145454Ce325dDbE47a25d4ec3d2311933".to_string(), 18, 0
+        );
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let pepe = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("PEPE").unwrap().clone();
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerOffering { asset: dai.clone(), power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::CompoundTheGrower,
+            SacredRitual::PowerOffering { asset: dai, power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+
+        // Aave has no rate for an asset it never blessed, so will not lend it
+        let unblessed = orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver,
+            SacredRitual::PowerBorrowing { asset: pepe, power_amount: 1 }
+        ).await;
+        assert!(matches!(unblessed, Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(_))));
+        orchestrator.blocks_pass(1).unwrap();
+
+        // An Aave spirit owing what it cannot accrue holds every spirit back
+        orchestrator.protocol_spirits.insert(ProtocolSpirit::AaveTheGiver, Box::new(supporting_cast::AaveLendingSpirit {
+            borrowed_power: HashMap::from([("PEPE".to_string(), 1)]),
+            ..supporting_cast::AaveLendingSpirit::spirit_establishes_sanctuary(String::new())
+        }));
+        let exposure_before = orchestrator.net_exposure();
+        let events_before = orchestrator.event_log.len();
+        assert!(orchestrator.blocks_pass(supporting_cast::BLOCKS_PER_YEAR as u64).is_err());
+        assert_eq!(orchestrator.net_exposure(), exposure_before);
+        assert_eq!(orchestrator.event_log.len(), events_before);
+    }
+
+    #[test]
+    fn a_long_route_too_costly_to_walk_loses_to_a_short_one() {
+        let route = |path: &[&str], output: u128| CandidateRoute {
+            path: path.iter().map(|essence| essence.to_string()).collect(),
+            output,
+            total_gas: 150_000 * (path.len() as u64 - 1),
+        };
+        let winding = route(&["USDC", "USDT", "WETH", "WBTC", "DAI"], 1_004_000);
+        let direct = route(&["USDC", "WETH", "DAI"], 1_000_000);
+        let limits = RoutingLimits { max_hops: 4, max_total_gas: 500_000, ..RoutingLimits::default() };
+
+        // The four hops buy a little more but cost 600,000 gas
+        assert!(!limits.route_keeps_the_limits(&winding));
+        assert_eq!(limits.route_chosen_among([winding.clone(), direct.clone()]), Some(direct.clone()));
+
+        // With gas to spare the better output wins, unless it is only as good
+        let generous = RoutingLimits { max_total_gas: 1_000_000, ..limits.clone() };
+        assert_eq!(generous.route_chosen_among([winding.clone(), direct.clone()]), Some(winding.clone()));
+        let forgiving = RoutingLimits { comparable_output_bps: 50, ..generous };
+        assert_eq!(forgiving.route_chosen_among([winding.clone(), direct.clone()]), Some(direct));
+
+        let too_strict = RoutingLimits { max_hops: 1, ..limits };
+        assert_eq!(too_strict.route_chosen_among([winding]), None);
+    }
+
+    /// An Aave spirit priced by a movable oracle, with ether at $2000, and
+    /// a guardian who has supplied it one WETH
+    async fn guardian_supplying_one_weth() -> (supporting_cast::AaveLendingSpirit, WalletGuardian, std::sync::Arc<MockPriceOracle>) {
+        use quest_unfolds::ProtocolCommunion;
+
+        let oracle = std::sync::Arc::new(MockPriceOracle::new());
+        oracle.update_batch(HashMap::from([
+            ("WETH".to_string(), 2_000 * 10u128.pow(ORACLE_PRICE_DECIMALS)),
+            ("DAI".to_string(), 10u128.pow(ORACLE_PRICE_DECIMALS)),
+        ]), 1);
+        let mut aave = supporting_cast::AaveLendingSpirit {
+            price_oracle: Some(oracle.clone()),
+            ..supporting_cast::AaveLendingSpirit::spirit_establishes_sanctuary(String::new())
+        };
+        let mut borrower = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        let mut weth = DigitalAsset::asset_discovers_its_identity("WETH".to_string(), String::new(), 18);
+        weth.current_power = 10u128.pow(18);
+        borrower.asset_finds_sanctuary(weth.clone());
+        borrower.asset_finds_sanctuary(DigitalAsset::asset_discovers_its_identity("DAI".to_string(), String::new(), 18));
+        borrower.guardian_grants_allowance(ProtocolSpirit::AaveTheGiver, "WETH", 10u128.pow(18));
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerOffering { asset: weth, power_amount: 10u128.pow(18) }
+        ).await.unwrap();
+        (aave, borrower, oracle)
+    }
+
+    #[tokio::test]
+    async fn a_borrow_the_collateral_cannot_carry_is_refused() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let (mut aave, mut borrower, _) = guardian_supplying_one_weth().await;
+        let dai = borrower.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let whole_dai = 10u128.pow(18);
+
+        // $2000 of WETH at a threshold of 0.8 carries $1600 of debt
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_500 * whole_dai }
+        ).await.unwrap();
+        assert!((aave.health_factor(&borrower) - 1_600.0 / 1_500.0).abs() < 1e-9);
+
+        let greedy = aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai, power_amount: 200 * whole_dai }
+        ).await;
+        assert!(matches!(greedy, Err(destiny_fulfilled::PlotTwist::RitualForbiddenBySpirit(_))));
+        assert_eq!(aave.borrowed_power["DAI"], 1_500 * whole_dai);
+        assert_eq!(borrower.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 1_500 * whole_dai);
+    }
+
+    #[tokio::test]
+    async fn an_underwater_guardian_loses_collateral_at_a_discount() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let (mut aave, mut borrower, oracle) = guardian_supplying_one_weth().await;
+        let dai = borrower.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let whole_dai = 10u128.pow(18);
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_500 * whole_dai }
+        ).await.unwrap();
+        let mut liquidator = WalletGuardian::guardian_accepts_responsibility(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+        ).unwrap();
+        liquidator.asset_finds_sanctuary(DigitalAsset { current_power: 500 * whole_dai, ..dai });
+
+        // A healthy guardian keeps its collateral
+        assert!(aave.liquidate(&borrower, &mut liquidator, "DAI", 500 * whole_dai, "WETH").is_err());
+
+        // Ether falls to $1500: $1200 of collateral now carries $1500 of debt
+        oracle.update_price("WETH", 1_500 * 10u128.pow(ORACLE_PRICE_DECIMALS), 2);
+        assert!(aave.health_factor(&borrower) < 1.0);
+        let seized = aave.liquidate(&borrower, &mut liquidator, "DAI", 500 * whole_dai, "WETH").unwrap();
+
+        // $500 of debt settled buys $525 of ether: 0.35 WETH
+        assert!((seized as f64 - 0.35e18).abs() < 1e4);
+        assert_eq!(liquidator.guardian_whispers_asset_secrets("WETH").unwrap().current_power, seized);
+        assert_eq!(liquidator.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 0);
+        assert_eq!(aave.borrowed_power["DAI"], 1_000 * whole_dai);
+        assert_eq!(aave.supplied_power["WETH"], 10u128.pow(18) - seized);
+    }
+
+    #[tokio::test]
+    async fn a_liquidator_pays_only_for_the_collateral_left_to_seize() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let (mut aave, mut borrower, oracle) = guardian_supplying_one_weth().await;
+        let dai = borrower.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let whole_dai = 10u128.pow(18);
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_500 * whole_dai }
+        ).await.unwrap();
+        let mut liquidator = WalletGuardian::guardian_accepts_responsibility(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+        ).unwrap();
+        liquidator.asset_finds_sanctuary(DigitalAsset { current_power: 1_500 * whole_dai, ..dai });
+
+        // Ether crashes to $1050: the one WETH covers $1000 of debt plus its bonus
+        oracle.update_price("WETH", 1_050 * 10u128.pow(ORACLE_PRICE_DECIMALS), 2);
+        let seized = aave.liquidate(&borrower, &mut liquidator, "DAI", 1_500 * whole_dai, "WETH").unwrap();
+
+        assert_eq!(seized, 10u128.pow(18));
+        assert_eq!(aave.supplied_power["WETH"], 0);
+        let settled = 1_500 * whole_dai - liquidator.guardian_whispers_asset_secrets("DAI").unwrap().current_power;
+        assert!((settled as f64 - 1_000e18).abs() < 1e6);
+        assert_eq!(aave.borrowed_power["DAI"], 1_500 * whole_dai - settled);
+    }
+
+    #[tokio::test]
+    async fn a_loan_liquidated_in_full_is_forgotten() {
+        use quest_unfolds::ProtocolCommunion;
+
+        let (mut aave, mut borrower, oracle) = guardian_supplying_one_weth().await;
+        let dai = borrower.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        let whole_dai = 10u128.pow(18);
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai.clone(), power_amount: 1_000 * whole_dai }
+        ).await.unwrap();
+        let mut liquidator = WalletGuardian::guardian_accepts_responsibility(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+        ).unwrap();
+        liquidator.asset_finds_sanctuary(DigitalAsset { current_power: 1_000 * whole_dai, ..dai.clone() });
+
+        // Ether falls to $1200: the whole debt is settled for 0.875 WETH
+        oracle.update_price("WETH", 1_200 * 10u128.pow(ORACLE_PRICE_DECIMALS), 2);
+        aave.accrue_interest(60).unwrap();
+        let owed = aave.borrowed_power["DAI"];
+        liquidator.asset_power_transforms("DAI", owed).unwrap();
+        aave.liquidate(&borrower, &mut liquidator, "DAI", owed, "WETH").unwrap();
+        assert_eq!(aave.borrowed_power["DAI"], 0);
+        assert_eq!(aave.loan_age_secs("DAI"), None);
+
+        // A later loan's age starts when it is taken
+        aave.accrue_interest(600).unwrap();
+        borrower.guardian_grants_allowance(ProtocolSpirit::AaveTheGiver, "DAI", 10 * whole_dai);
+        aave.spirit_performs_sacred_ritual(
+            &mut borrower, SacredRitual::PowerBorrowing { asset: dai, power_amount: 10 * whole_dai }
+        ).await.unwrap();
+        assert_eq!(aave.loan_age_secs("DAI"), Some(0));
+    }
+
+    #[tokio::test]
+    async fn a_transmutation_is_awaited_like_any_call_to_the_chain() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 100_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+
+        // A spirit's ritual is a future that may cross threads, as a network call would
+        fn crosses_threads<T: Send>(_: &T) {}
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+        let mut reflection = orchestrator.protocol_spirits[&ProtocolSpirit::UniswapTheExchanger].spirit_casts_reflection();
+        let mut rehearsal_guardian = orchestrator.wallet_guardian.clone();
+        rehearsal_guardian.guardian_grants_allowance(ProtocolSpirit::UniswapTheExchanger, "USDC", 10_000_000);
+        let rehearsal = reflection.spirit_performs_sacred_ritual(
+            &mut rehearsal_guardian,
+            SacredRitual::AssetTransmutation { offering: usdc, desired_form: weth, power_amount: 10_000_000, min_received: 0 },
+        );
+        crosses_threads(&rehearsal);
+        assert!(matches!(rehearsal.await.unwrap().quest_outcome, QuestOutcome::LegendComplete));
+
+        let quest = orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 10_000_000).await.unwrap();
+        assert!(matches!(quest.quest_outcome, QuestOutcome::LegendComplete));
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 90_000_000);
+        assert!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power > 0);
+    }
+
+    #[tokio::test]
+    async fn without_a_direct_pool_the_swap_finds_its_way_through_weth() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 5_000_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "WETH".to_string(), "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), 18, 0
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 0
+        );
+
+        // Ether trades at $2000 against both dollars, but no pool joins them directly
+        let whole = 10u128.pow(18);
+        let mut uniswap = supporting_cast::UniswapExchangerSpirit::spirit_manifests_in_realm(
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string()
+        );
+        uniswap.pool_holds_reserves("USDC", "WETH", (2_000_000 * whole, 1_000 * whole));
+        uniswap.pool_holds_reserves("WETH", "DAI", (1_000 * whole, 2_000_000 * whole));
+        orchestrator.protocol_spirits.insert(ProtocolSpirit::UniswapTheExchanger, Box::new(uniswap));
+
+        let route = orchestrator.find_best_route("USDC", "DAI", 1_000_000_000).unwrap();
+        assert_eq!(route, ["USDC", "WETH", "DAI"]);
+        assert_eq!(orchestrator.find_best_route("USDC", "LINK", 1_000_000_000), None);
+
+        // A route whose last leg leads nowhere is walked not at all
+        let usdc = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().clone();
+        let weth = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().clone();
+        let reserves_before = orchestrator.protocol_spirits[&ProtocolSpirit::UniswapTheExchanger]
+            .spirit_reveals_reserves(&usdc, &weth);
+        let astray = ["USDC".to_string(), "WETH".to_string(), "LINK".to_string()];
+        assert!(orchestrator.execute_route(&astray, 1_000_000_000).await.is_err());
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 5_000_000_000);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power, 0);
+        assert_eq!(
+            orchestrator.protocol_spirits[&ProtocolSpirit::UniswapTheExchanger].spirit_reveals_reserves(&usdc, &weth),
+            reserves_before
+        );
+
+        // A thousand dollars through two pools, less two tributes of 0.3%
+        let quests = orchestrator.execute_route(&route, 1_000_000_000).await.unwrap();
+        assert_eq!(quests.len(), 2);
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power;
+        assert!(dai > 990 * whole && dai < 1_000 * whole);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("USDC").unwrap().current_power, 4_000_000_000);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("WETH").unwrap().current_power, 0);
+    }
+
+    #[test]
+    fn a_mixed_wallet_is_worth_its_assets_at_their_prices() {
+        let mut guardian = WalletGuardian::guardian_accepts_responsibility(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        for (essence, precision, power) in [
+            ("USDC", 6, 1_500_000_000),             // 1,500 USDC
+            ("WETH", 18, 2 * 10u128.pow(18)),       // 2 WETH
+            ("DAI", 18, 250 * 10u128.pow(18)),      // 250 DAI
+            ("SHIB", 18, 1_000_000 * 10u128.pow(18)),
+        ] {
+            let mut asset = DigitalAsset::asset_discovers_its_identity(essence.to_string(), String::new(), precision);
+            asset.current_power = power;
+            guardian.asset_finds_sanctuary(asset);
+        }
+        let oracle = StaticPriceOracle::from_prices(HashMap::from([
+            ("USDC".to_string(), 1.0),
+            ("WETH".to_string(), 2_000.0),
+            ("DAI".to_string(), 1.0),
+        ]));
+
+        // The unpriced SHIB counts for nothing, however much raw power it holds
+        assert!((guardian.total_value_usd(&oracle) - 5_750.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn a_mistaken_batch_is_undone_by_replaying_the_log_to_before_it() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "USDC".to_string(), "0xA0b86a33E6f0C0059eA39c3a9Ae31bF66Bb4d2AE".to_string(), 6, 100_000_000
+        );
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 1_000
+        );
+        orchestrator.asset_seeks_sanctuary_with_lending_spirit("DAI", 400).await.unwrap();
+        let before_the_batch = orchestrator.event_log.last().unwrap().sequence;
+        assert_eq!(before_the_batch, 3);
+        let power_of = |orchestrator: &DeFiStoryOrchestrator, essence: &str| {
+            orchestrator.wallet_guardian.guardian_whispers_asset_secrets(essence).unwrap().current_power
+        };
+
+        // The batch: a swap, a loan against the supply and an allowance
+        orchestrator.assets_undergo_sacred_transmutation("USDC", "DAI", 10_000_000).await.unwrap();
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        orchestrator.approve_and_execute(
+            ProtocolSpirit::AaveTheGiver, SacredRitual::PowerBorrowing { asset: dai, power_amount: 100 }
+        ).await.unwrap();
+        orchestrator.approve_until(ProtocolSpirit::UniswapTheExchanger, "USDC", 5_000_000, u64::MAX);
+        assert_eq!(orchestrator.event_log.len(), 6);
+        assert_eq!(power_of(&orchestrator, "USDC"), 90_000_000);
+
+        assert!(orchestrator.rollback_to(7).await.is_err());
+        orchestrator.rollback_to(before_the_batch).await.unwrap();
+        assert_eq!(power_of(&orchestrator, "USDC"), 100_000_000);
+        assert_eq!(power_of(&orchestrator, "DAI"), 600);
+        assert_eq!(orchestrator.wallet_guardian.allowance_for(&ProtocolSpirit::UniswapTheExchanger, "USDC"), 0);
+        let aave = &orchestrator.protocol_spirits[&ProtocolSpirit::AaveTheGiver];
+        assert_eq!(aave.spirit_reveals_exposure(), HashMap::from([("DAI".to_string(), 400)]));
+        assert_eq!(orchestrator.wallet_guardian.legend_book.len(), 1);
+
+        // The tale carries on from where it was rolled back to
+        orchestrator.blocks_pass(1).unwrap();
+        assert_eq!(orchestrator.event_log.last().unwrap().sequence, before_the_batch + 1);
+    }
+
+    #[tokio::test]
+    async fn a_replay_that_fails_leaves_the_tale_and_its_log_untouched() {
+        let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
+            "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
+        ).unwrap();
+        orchestrator.new_asset_discovers_its_destiny(
+            "DAI".to_string(), "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(), 18, 1_000
+        );
+        orchestrator.asset_seeks_sanctuary_with_lending_spirit("DAI", 400).await.unwrap();
+
+        // A log tampered with a loan no collateral could ever have carried
+        let dai = orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().clone();
+        orchestrator.event_log.push(destiny_fulfilled::LoggedEvent {
+            sequence: 3,
+            event: OrchestratorEvent::RitualPerformed {
+                spirit_id: ProtocolSpirit::AaveTheGiver,
+                ritual: SacredRitual::PowerBorrowing { asset: dai, power_amount: 1_000_000 },
+            },
+        });
+        orchestrator.blocks_pass(1).unwrap();
+
+        assert!(orchestrator.rollback_to(4).await.is_err());
+        assert_eq!(orchestrator.event_log.len(), 4);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 600);
+        assert_eq!(orchestrator.wallet_guardian.legend_book.len(), 1);
+
+        // Rolling back to before the tampering still replays cleanly
+        orchestrator.rollback_to(2).await.unwrap();
+        assert_eq!(orchestrator.event_log.len(), 2);
+        assert_eq!(orchestrator.wallet_guardian.guardian_whispers_asset_secrets("DAI").unwrap().current_power, 600);
+    }
 }
 
 /// ## The Grand Finale: Where the Story Comes to Life
 /// 
 /// This is where our tale unfolds in the real world, demonstrating
 /// the epic journey of assets through the DeFi cosmos.
-fn main() -> destiny_fulfilled::StoryResult<()> {
+#[tokio::main]
+async fn main() -> destiny_fulfilled::StoryResult<()> {
     println!("🌟 The DeFi Chronicles: An Epic Tale of Digital Assets 🌟");
     println!("{:=<70}", "");
 
     // The orchestrator awakens to begin the grand tale
     let mut orchestrator = DeFiStoryOrchestrator::orchestrator_begins_the_great_tale(
-        "0x742d35cc6634C0532925a3b8D4020638F2Dc1231".to_string()
+        "0x742d35cC6634C0532925A3B8D4020638F2Dc1231".to_string()
     )?;
 
     // Assets discover their identities and find sanctuary
@@ -696,14 +4089,14 @@ fn main() -> destiny_fulfilled::StoryResult<()> {
 
     // The sacred transmutation ritual begins
     println!("\n📖 Chapter 2: The Great Transmutation (100 USDC → WETH)");
-    match orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 100_000_000) {
+    match orchestrator.assets_undergo_sacred_transmutation("USDC", "WETH", 100_000_000).await {
         Ok(quest) => println!("✨ Transmutation successful! Quest recorded: {}", quest.quest_id),
         Err(plot_twist) => println!("💥 Plot twist encountered: {}", plot_twist),
     }
 
     // Assets seek sanctuary with the lending spirit
     println!("\n📖 Chapter 3: The Sanctuary Seeking (200 DAI to Aave Spirit)");
-    match orchestrator.asset_seeks_sanctuary_with_lending_spirit("DAI", 200_000_000_000_000_000_000) {
+    match orchestrator.asset_seeks_sanctuary_with_lending_spirit("DAI", 200_000_000_000_000_000_000).await {
         Ok(quest) => println!("🏛️ Sanctuary granted! Quest recorded: {}", quest.quest_id),
         Err(plot_twist) => println!("💥 Plot twist encountered: {}", plot_twist),
     }